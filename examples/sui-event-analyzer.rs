@@ -40,7 +40,7 @@ async fn process_stream(sui_event_source: SuiEventSource) {
             .aggregate(HashMap::new(), |mut counts, events| {
                 for event in events {
                     tracing::debug!("Processing event: {:?}", event);
-                    *counts.entry(event.event_type).or_insert(0) += 1;
+                    *counts.entry(event.event_type.to_string()).or_insert(0) += 1;
                 }
                 counts
             })
@@ -0,0 +1,255 @@
+//! End-to-end pipeline against a local Sui network
+//!
+//! Start localnet with `docker compose -f examples/docker-compose.localnet.yml up`,
+//! then run `cargo run --example sui-localnet-pipeline`. The example sends SUI
+//! transfers and stakes SUI with a validator from the first funded address of the
+//! generated wallet, and checks that the transaction, object and event sources
+//! each observe activity of that address. Staking calls into the system package,
+//! whose `StakingRequestEvent` gives the event source Move events to read.
+//!
+//! The wallet config generated in the container points at a keystore inside it,
+//! so the example writes `client.host.yaml` next to it with host-side paths. Set
+//! `SUI_CONFIG` to use a host wallet config instead.
+
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiEventSource, SuiObjectSource, SuiTransactionSource};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use sui_sdk::SUI_LOCAL_NETWORK_URL;
+use sui_sdk::rpc_types::{EventFilter, SuiTransactionBlockResponseQuery, TransactionFilter};
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::wallet_context::WalletContext;
+use tokio::time::sleep;
+
+/// Number of transfers sent by the generator
+const TRANSFERS: usize = 5;
+/// Amount sent per transfer (MIST)
+const AMOUNT: u64 = 1_000;
+/// Amount staked, the minimum stake of 1 SUI (MIST)
+const STAKE: u64 = 1_000_000_000;
+/// Gas budget per transaction (MIST)
+const GAS_BUDGET: u64 = 10_000_000;
+/// Wallet config generated by the localnet container
+const GENERATED_CONFIG: &str = "examples/localnet/sui_config/client.yaml";
+/// Config directory as mounted inside the localnet container
+const CONTAINER_CONFIG_DIR: &str = "/root/.sui/sui_config";
+/// Time each source is given to observe the generated activity
+const OBSERVE_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[tokio::main]
+async fn main() {
+    // Initialize logging
+    tracing_subscriber::fmt().init();
+
+    let config_path = match std::env::var("SUI_CONFIG") {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => host_config(Path::new(GENERATED_CONFIG)).expect("Failed to write host config"),
+    };
+    let mut wallet = WalletContext::new(&config_path).expect("Failed to load wallet config");
+    let sender = wallet
+        .active_address()
+        .expect("Wallet has no active address");
+    tracing::info!("Generating traffic from {}", sender);
+
+    // Sources observing the sender's activity
+    let query =
+        SuiTransactionBlockResponseQuery::new(Some(TransactionFilter::FromAddress(sender)), None);
+    let mut transactions = SuiTransactionSource::new(SUI_LOCAL_NETWORK_URL.to_string(), 500, 50)
+        .with_query(query)
+        .with_effects_only(true);
+    let mut objects = SuiObjectSource::new(
+        SUI_LOCAL_NETWORK_URL.to_string(),
+        500,
+        sender.to_string(),
+        50,
+    );
+    let mut events = SuiEventSource::new(SUI_LOCAL_NETWORK_URL.to_string(), 500, 50)
+        .with_query(EventFilter::Sender(sender));
+
+    transactions
+        .init()
+        .await
+        .expect("Failed to initialize transaction source");
+    objects
+        .init()
+        .await
+        .expect("Failed to initialize object source");
+    events
+        .init()
+        .await
+        .expect("Failed to initialize event source");
+
+    // Write path: send transfers and a stake, remembering their digests
+    let mut generated = HashSet::new();
+    for _ in 0..TRANSFERS {
+        let digest = transfer(&wallet, sender, SuiAddress::random_for_testing_only())
+            .await
+            .expect("Transfer failed");
+        tracing::info!("Sent transfer {}", digest);
+        generated.insert(digest);
+    }
+    let digest = stake(&wallet, sender).await.expect("Stake failed");
+    tracing::info!("Sent stake {}", digest);
+    generated.insert(digest);
+
+    // Read path: poll until every generated transaction has been observed
+    let mut observed = HashSet::new();
+    let deadline = Instant::now() + OBSERVE_TIMEOUT;
+    while observed.len() < generated.len() && Instant::now() < deadline {
+        if let Ok(Some(batch)) = transactions.next().await {
+            observed.extend(
+                batch
+                    .data
+                    .into_iter()
+                    .map(|transaction| transaction.transaction_digest)
+                    .filter(|digest| generated.contains(digest)),
+            );
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    println!(
+        "Transaction source observed {}/{} generated transactions",
+        observed.len(),
+        generated.len()
+    );
+
+    // Gas coins of the sender change version with every transaction
+    let mut sender_objects = 0;
+    let deadline = Instant::now() + OBSERVE_TIMEOUT;
+    while sender_objects == 0 && Instant::now() < deadline {
+        if let Ok(Some(batch)) = objects.next().await {
+            sender_objects = batch.data.len();
+        }
+    }
+    println!("Object source observed {} sender objects", sender_objects);
+
+    // Transfers emit no Move events, the stake emits a StakingRequestEvent
+    let mut sender_events = 0;
+    let deadline = Instant::now() + OBSERVE_TIMEOUT;
+    while sender_events == 0 && Instant::now() < deadline {
+        if let Ok(Some(batch)) = events.next().await {
+            sender_events = batch
+                .data
+                .iter()
+                .filter(|event| generated.contains(&event.id.tx_digest.to_string()))
+                .count();
+        }
+    }
+    println!("Event source observed {} sender events", sender_events);
+
+    let mut failed = false;
+    if observed.len() < generated.len() {
+        eprintln!("Transaction source missed generated transactions");
+        failed = true;
+    }
+    if sender_objects == 0 {
+        eprintln!("Object source observed no sender objects");
+        failed = true;
+    }
+    if sender_events == 0 {
+        eprintln!("Event source observed no sender events");
+        failed = true;
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Writes a copy of the container's wallet config with host-side paths, returning its path
+///
+/// The copy points the keystore at the mounted config directory and the RPC
+/// endpoint at the port published on the host.
+fn host_config(generated: &Path) -> Result<PathBuf, String> {
+    let config = std::fs::read_to_string(generated)
+        .map_err(|e| format!("Failed to read {}: {}", generated.display(), e))?;
+    let directory = generated
+        .parent()
+        .unwrap_or(Path::new("."))
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", generated.display(), e))?;
+    let config = config
+        .replace(CONTAINER_CONFIG_DIR, &directory.to_string_lossy())
+        .replace("0.0.0.0", "127.0.0.1");
+
+    let path = directory.join("client.host.yaml");
+    std::fs::write(&path, config)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Sends `AMOUNT` MIST to `recipient`, paying with the sender's first coin
+async fn transfer(
+    wallet: &WalletContext,
+    sender: SuiAddress,
+    recipient: SuiAddress,
+) -> Result<String, String> {
+    let client = wallet.get_client().await.map_err(|e| e.to_string())?;
+    let coins = client
+        .coin_read_api()
+        .get_coins(sender, None, None, Some(1))
+        .await
+        .map_err(|e| e.to_string())?;
+    let coin = coins
+        .data
+        .first()
+        .ok_or_else(|| format!("Address {} has no SUI coins", sender))?;
+
+    let data = client
+        .transaction_builder()
+        .pay_sui(
+            sender,
+            vec![coin.coin_object_id],
+            vec![recipient],
+            vec![AMOUNT],
+            GAS_BUDGET,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let transaction = wallet.sign_transaction(&data).await;
+    let response = wallet.execute_transaction_must_succeed(transaction).await;
+
+    Ok(response.digest.to_string())
+}
+
+/// Stakes `STAKE` MIST with the first active validator, emitting a Move event
+async fn stake(wallet: &WalletContext, sender: SuiAddress) -> Result<String, String> {
+    let client = wallet.get_client().await.map_err(|e| e.to_string())?;
+    let system_state = client
+        .governance_api()
+        .get_latest_sui_system_state()
+        .await
+        .map_err(|e| e.to_string())?;
+    let validator = system_state
+        .active_validators
+        .first()
+        .map(|validator| validator.sui_address)
+        .ok_or_else(|| "Localnet has no active validator".to_string())?;
+    let coins = client
+        .coin_read_api()
+        .get_coins(sender, None, None, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    let coin = coins
+        .data
+        .iter()
+        .find(|coin| coin.balance > STAKE + GAS_BUDGET)
+        .ok_or_else(|| format!("Address {} has no coin to stake", sender))?;
+
+    let data = client
+        .transaction_builder()
+        .request_add_stake(
+            sender,
+            vec![coin.coin_object_id],
+            Some(STAKE),
+            validator,
+            None,
+            GAS_BUDGET,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let transaction = wallet.sign_transaction(&data).await;
+    let response = wallet.execute_transaction_must_succeed(transaction).await;
+
+    Ok(response.digest.to_string())
+}
@@ -0,0 +1,173 @@
+use fluxus_source_sui::{CursorStore, FileCursorStore};
+use std::str::FromStr;
+use sui_sdk::rpc_types::{CheckpointId, SuiTransactionBlockResponseOptions};
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+
+const USAGE: &str = "Usage: sui-source-ctl [--rpc <url>] <command>
+
+Commands:
+  digest-to-checkpoint <digest>     Print the checkpoint containing a transaction
+  checkpoint-to-digests <sequence>  Print the transaction digests of a checkpoint
+  checkpoint-at <timestamp_ms>      Print the first checkpoint at or after a timestamp
+  dump <dir>                        Print the cursors persisted by a FileCursorStore
+  seed <dir> <name> <timestamp_ms>  Save a transaction cursor resuming at a timestamp";
+
+#[tokio::main]
+async fn main() {
+    // Initialize logging
+    tracing_subscriber::fmt().init();
+
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    // Optional RPC endpoint override, defaults to Mainnet
+    let rpc_url = match args.iter().position(|arg| arg == "--rpc") {
+        Some(index) if index + 1 < args.len() => {
+            let url = args.remove(index + 1);
+            args.remove(index);
+            url
+        }
+        _ => SUI_MAINNET_URL.to_string(),
+    };
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    // Reading cursor files needs no node
+    if let ["dump", dir] = args.as_slice() {
+        if let Err(e) = dump(dir) {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let client = SuiClientBuilder::default()
+        .build(rpc_url.as_str())
+        .await
+        .expect("Failed to initialize Sui client");
+
+    let result = match args.as_slice() {
+        ["digest-to-checkpoint", digest] => digest_to_checkpoint(&client, digest).await,
+        ["checkpoint-to-digests", sequence] => checkpoint_to_digests(&client, sequence).await,
+        ["checkpoint-at", timestamp_ms] => checkpoint_at(&client, timestamp_ms).await,
+        ["seed", dir, name, timestamp_ms] => seed(&client, dir, name, timestamp_ms).await,
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn digest_to_checkpoint(client: &SuiClient, digest: &str) -> Result<(), String> {
+    let digest = TransactionDigest::from_str(digest).map_err(|e| e.to_string())?;
+    let transaction = client
+        .read_api()
+        .get_transaction_with_options(digest, SuiTransactionBlockResponseOptions::new())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match transaction.checkpoint {
+        Some(checkpoint) => println!("{}", checkpoint),
+        None => println!("Transaction {} is not checkpointed yet", digest),
+    }
+    Ok(())
+}
+
+async fn checkpoint_to_digests(client: &SuiClient, sequence: &str) -> Result<(), String> {
+    let sequence = sequence.parse::<u64>().map_err(|e| e.to_string())?;
+    let checkpoint = client
+        .read_api()
+        .get_checkpoint(CheckpointId::SequenceNumber(sequence))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    for digest in checkpoint.transactions {
+        println!("{}", digest);
+    }
+    Ok(())
+}
+
+async fn checkpoint_at(client: &SuiClient, timestamp_ms: &str) -> Result<(), String> {
+    let timestamp_ms = timestamp_ms.parse::<u64>().map_err(|e| e.to_string())?;
+    println!("{}", first_checkpoint_at(client, timestamp_ms).await?);
+    Ok(())
+}
+
+async fn first_checkpoint_at(client: &SuiClient, timestamp_ms: u64) -> Result<u64, String> {
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Binary search for the first checkpoint whose timestamp is not earlier than the target
+    let (mut low, mut high) = (0u64, latest);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let checkpoint = client
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(middle))
+            .await
+            .map_err(|e| e.to_string())?;
+        if checkpoint.timestamp_ms < timestamp_ms {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    Ok(low)
+}
+
+fn dump(dir: &str) -> Result<(), String> {
+    let store = FileCursorStore::new(dir);
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir, e))?;
+
+    // Cursor files are named after the source, with path separators replaced
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            name.strip_suffix(".cursor").map(str::to_string)
+        })
+        .collect();
+    names.sort();
+
+    for name in names {
+        if let Some(cursor) = store.load(&name).map_err(|e| e.to_string())? {
+            println!("{}\t{}", name, cursor.trim());
+        }
+    }
+    Ok(())
+}
+
+async fn seed(client: &SuiClient, dir: &str, name: &str, timestamp_ms: &str) -> Result<(), String> {
+    let timestamp_ms = timestamp_ms.parse::<u64>().map_err(|e| e.to_string())?;
+    let sequence = first_checkpoint_at(client, timestamp_ms).await?;
+
+    // Transaction sources resume after the saved digest, so save the last
+    // transaction of the preceding checkpoint to read the found one in full
+    let previous = sequence
+        .checked_sub(1)
+        .ok_or_else(|| "Checkpoint 0 has no preceding transaction to resume after".to_string())?;
+    let checkpoint = client
+        .read_api()
+        .get_checkpoint(CheckpointId::SequenceNumber(previous))
+        .await
+        .map_err(|e| e.to_string())?;
+    let digest = checkpoint
+        .transactions
+        .last()
+        .ok_or_else(|| format!("Checkpoint {} has no transactions", previous))?;
+
+    FileCursorStore::new(dir)
+        .save(name, &digest.to_string())
+        .map_err(|e| e.to_string())?;
+    println!("{}\t{} (resumes at checkpoint {})", name, digest, sequence);
+    Ok(())
+}
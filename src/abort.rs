@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Move function that aborted a transaction
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AbortLocation {
+    /// Package ID, `0x` prefixed
+    pub package: String,
+    /// Module name
+    pub module: String,
+    /// Index of the function within the module
+    pub function_index: u16,
+    /// Instruction offset within the function
+    pub instruction: u16,
+    /// Function name, when reported by the node
+    pub function_name: Option<String>,
+}
+
+/// Move abort decoded from a failed execution status
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MoveAbort {
+    /// Location of the abort
+    pub location: AbortLocation,
+    /// Abort code
+    pub code: u64,
+    /// Index of the failing command, when reported by the node
+    pub command: Option<usize>,
+}
+
+impl MoveAbort {
+    /// Decodes the error of a failed execution status, None if it is not a Move abort
+    ///
+    /// Nodes report aborts as
+    /// `MoveAbort(MoveLocation { module: ModuleId { address: .., name: Identifier("..") },
+    /// function: .., instruction: .., function_name: Some("..") }, <code>) in command <index>`.
+    pub fn parse(error: &str) -> Option<Self> {
+        let body = error.strip_prefix("MoveAbort(")?;
+
+        let address = field(body, "address: ", |c| c == ',' || c == ' ')?;
+        let package = if address.starts_with("0x") {
+            address.to_string()
+        } else {
+            format!("0x{}", address)
+        };
+        let module = field(body, "name: Identifier(\"", |c| c == '"')?.to_string();
+        let function_index = field(body, "function: ", |c| !c.is_ascii_digit())?
+            .parse()
+            .ok()?;
+        let instruction = field(body, "instruction: ", |c| !c.is_ascii_digit())?
+            .parse()
+            .ok()?;
+        let function_name = field(body, "function_name: Some(\"", |c| c == '"').map(str::to_string);
+
+        // The abort code follows the closing brace of the location
+        let (_, rest) = body.rsplit_once("}, ")?;
+        let code = rest.split(')').next()?.trim().parse().ok()?;
+        let command = error
+            .rsplit_once(" in command ")
+            .and_then(|(_, index)| index.trim().parse().ok());
+
+        Some(Self {
+            location: AbortLocation {
+                package,
+                module,
+                function_index,
+                instruction,
+                function_name,
+            },
+            code,
+            command,
+        })
+    }
+}
+
+impl fmt::Display for MoveAbort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = &self.location;
+        match &location.function_name {
+            Some(name) => write!(f, "{}::{}::{}", location.package, location.module, name)?,
+            None => write!(
+                f,
+                "{}::{}::#{}",
+                location.package, location.module, location.function_index
+            )?,
+        }
+        write!(f, " abort {}", self.code)
+    }
+}
+
+/// Returns the text following `prefix` up to the first character matching `end`
+fn field<'a>(text: &'a str, prefix: &str, end: impl Fn(char) -> bool) -> Option<&'a str> {
+    let start = text.find(prefix)? + prefix.len();
+    let rest = &text[start..];
+    let value = &rest[..rest.find(end).unwrap_or(rest.len())];
+    (!value.is_empty()).then_some(value)
+}
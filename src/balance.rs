@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::{BalanceChange as SuiBalanceChange, SuiTransactionBlockResponse};
+
+/// Change of one owner's balance of one coin type caused by a transaction
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BalanceChange {
+    /// Owner address, or the owner description for non-address owners
+    pub owner: String,
+    /// Coin type with every address in long form, e.g.
+    /// `0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI`
+    pub coin_type: String,
+    /// Signed change in the coin's smallest unit, negative when spent
+    ///
+    /// Nodes report amounts as decimal strings that can exceed `u64`, so they are
+    /// kept as `i128` rather than truncated.
+    pub amount: i128,
+    /// Amount scaled by the coin's decimals, e.g. `-1.5` for SUI
+    ///
+    /// None unless the source scales amounts, or the coin type has no metadata.
+    pub decimal_amount: Option<String>,
+}
+
+impl From<&SuiBalanceChange> for BalanceChange {
+    fn from(change: &SuiBalanceChange) -> Self {
+        BalanceChange {
+            owner: change
+                .owner
+                .get_owner_address()
+                .map(|address| address.to_string())
+                .unwrap_or_else(|_| change.owner.to_string()),
+            coin_type: change.coin_type.to_canonical_string(true),
+            amount: change.amount,
+            decimal_amount: None,
+        }
+    }
+}
+
+/// Converts the balance changes of a transaction, empty if the node returned none
+pub(crate) fn balance_changes(transaction: &SuiTransactionBlockResponse) -> Vec<BalanceChange> {
+    transaction
+        .balance_changes
+        .iter()
+        .flatten()
+        .map(BalanceChange::from)
+        .collect()
+}
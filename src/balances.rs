@@ -0,0 +1,273 @@
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use futures::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceUpdate {
+    /// Wallet address
+    pub address: String,
+    /// Coin type, e.g. `0x2::sui::SUI`
+    pub coin_type: String,
+    /// Balance at the previous poll, zero for coin types not held before
+    pub old_balance: u128,
+    /// Balance at this poll, zero once the last coin of the type is gone
+    pub new_balance: u128,
+    /// Signed change from the old to the new balance
+    pub delta: i128,
+    /// Timestamp of the poll (milliseconds)
+    pub timestamp: u64,
+}
+
+/// Sui blockchain data source emitting balance changes of watched addresses
+///
+/// Every poll fetches the balances of all addresses and emits one update per
+/// address and coin type whose balance moved since the previous poll. The first
+/// poll only records the starting balances, unless initial balances are enabled.
+pub struct SuiBalanceSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Addresses to watch
+    addresses: Vec<String>,
+    /// Addresses parsed at init
+    owners: Vec<SuiAddress>,
+    /// Coin type to watch, every coin type held when None
+    coin_type: Option<String>,
+    /// Whether the first poll emits the starting balances as changes from zero
+    emit_initial: bool,
+    /// Balances of the previous poll by address and coin type, None before the first poll
+    balances: Option<HashMap<(String, String), u128>>,
+}
+
+impl SuiBalanceSource {
+    /// Creates a new SuiBalanceSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `addresses` - Addresses whose balances are watched
+    pub fn new(rpc_url: String, interval_ms: u64, addresses: Vec<String>) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            addresses,
+            owners: Vec::new(),
+            coin_type: None,
+            emit_initial: false,
+            balances: None,
+        }
+    }
+
+    /// Creates a new SuiBalanceSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64, addresses: Vec<String>) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms, addresses)
+    }
+
+    /// Watches a single coin type, fetched with `suix_getBalance` instead of every balance
+    pub fn with_coin_type(mut self, coin_type: impl Into<String>) -> Self {
+        self.coin_type = Some(coin_type.into());
+        self
+    }
+
+    /// Emits the balances found by the first poll as changes from zero
+    pub fn with_initial_balances(mut self, emit_initial: bool) -> Self {
+        self.emit_initial = emit_initial;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Fetches the balances of one address by coin type
+    async fn fetch_balances(
+        client: &SuiClient,
+        owner: SuiAddress,
+        coin_type: Option<&str>,
+    ) -> StreamResult<Vec<(String, u128)>> {
+        if let Some(coin_type) = coin_type {
+            let balance = rpc::call(
+                "SuiBalanceSource",
+                "suix_getBalance",
+                &format!("fetch {} balance of {}", coin_type, owner),
+                client
+                    .coin_read_api()
+                    .get_balance(owner, Some(coin_type.to_string())),
+            )
+            .await?;
+            return Ok(vec![(coin_type.to_string(), balance.total_balance)]);
+        }
+
+        let balances = rpc::call(
+            "SuiBalanceSource",
+            "suix_getAllBalances",
+            &format!("fetch balances of {}", owner),
+            client.coin_read_api().get_all_balances(owner),
+        )
+        .await?;
+        Ok(balances
+            .into_iter()
+            .map(|balance| (balance.coin_type, balance.total_balance))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Source<Vec<BalanceUpdate>> for SuiBalanceSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        self.owners = self
+            .addresses
+            .iter()
+            .map(|address| {
+                SuiAddress::from_str(address).map_err(|e| {
+                    tracing::error!("Invalid watched address: {}", e);
+                    StreamError::Runtime(format!("Invalid watched address {}: {}", address, e))
+                })
+            })
+            .collect::<StreamResult<_>>()?;
+
+        // Initialize Sui client
+        let client = rpc::call(
+            "SuiBalanceSource",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(self.rpc_url.as_str()),
+        )
+        .await?;
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiBalanceSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<BalanceUpdate>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiBalanceSource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiBalanceSource client not available".to_string())
+        })?;
+
+        // Return None if no addresses are watched
+        if self.owners.is_empty() {
+            tracing::info!("No addresses to watch balances of");
+            return Ok(None);
+        }
+
+        // Fetch the balances of all addresses concurrently
+        let fetched = try_join_all(
+            self.owners
+                .iter()
+                .map(|owner| Self::fetch_balances(client, *owner, self.coin_type.as_deref())),
+        )
+        .await?;
+        let current: HashMap<(String, String), u128> = self
+            .addresses
+            .iter()
+            .zip(fetched)
+            .flat_map(|(address, balances)| {
+                balances
+                    .into_iter()
+                    .map(move |(coin_type, balance)| ((address.clone(), coin_type), balance))
+            })
+            .collect();
+
+        let previous = match self.balances.replace(current.clone()) {
+            Some(previous) => previous,
+            None if self.emit_initial => HashMap::new(),
+            None => {
+                tracing::info!(
+                    "Recorded starting balances of {} addresses",
+                    self.owners.len()
+                );
+                return Ok(None);
+            }
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let mut updates: Vec<BalanceUpdate> = current
+            .iter()
+            .map(|(key, new_balance)| (key, previous.get(key).copied().unwrap_or(0), *new_balance))
+            // Coin types no longer listed were spent down to zero
+            .chain(
+                previous
+                    .iter()
+                    .filter(|(key, _)| !current.contains_key(*key))
+                    .map(|(key, old_balance)| (key, *old_balance, 0)),
+            )
+            .filter(|(_, old_balance, new_balance)| old_balance != new_balance)
+            .map(
+                |((address, coin_type), old_balance, new_balance)| BalanceUpdate {
+                    address: address.clone(),
+                    coin_type: coin_type.clone(),
+                    old_balance,
+                    new_balance,
+                    delta: new_balance as i128 - old_balance as i128,
+                    timestamp,
+                },
+            )
+            .collect();
+
+        // Return None if no balance moved
+        if updates.is_empty() {
+            tracing::info!("No balance changes found");
+            return Ok(None);
+        }
+        updates.sort_by(|a, b| {
+            (a.address.as_str(), a.coin_type.as_str())
+                .cmp(&(b.address.as_str(), b.coin_type.as_str()))
+        });
+        for update in &updates {
+            tracing::debug!(
+                "Balance of {} in {} changed by {}",
+                update.address,
+                update.coin_type,
+                update.delta
+            );
+        }
+
+        Ok(Some(Record::new(updates)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiBalanceSource closed");
+        Ok(())
+    }
+}
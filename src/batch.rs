@@ -0,0 +1,133 @@
+use fluxus::utils::models::{StreamError, StreamResult};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+
+/// Computes a deterministic SHA-256 over a batch, hex encoded
+///
+/// Every item is serialized to JSON with object keys sorted, so maps hash the same
+/// whatever their iteration order, and the encoded items are sorted before hashing,
+/// so the digest does not depend on the order within the batch either. Two
+/// deliveries of the same items, e.g. a duplicate or a replay, have equal digests.
+pub fn batch_digest<T: Serialize>(items: &[T]) -> StreamResult<String> {
+    let mut encoded = items
+        .iter()
+        .map(|item| {
+            let value = serde_json::to_value(item)
+                .map_err(|e| StreamError::Runtime(format!("Failed to encode batch item: {}", e)))?;
+            let mut canonical = String::new();
+            write_canonical(&value, &mut canonical);
+            Ok(canonical)
+        })
+        .collect::<StreamResult<Vec<String>>>()?;
+    encoded.sort_unstable();
+
+    let mut hasher = Sha256::new();
+    for item in &encoded {
+        // Length prefixes keep item boundaries unambiguous
+        hasher.update((item.len() as u64).to_be_bytes());
+        hasher.update(item.as_bytes());
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Writes a JSON value with the keys of every object sorted
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(fields) => {
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_unstable_by_key(|(key, _)| *key);
+            out.push('{');
+            for (index, (key, field)) in fields.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(field, out);
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// Splits emitted batches exceeding a byte budget, holding back the remainder
+pub(crate) struct BatchSplitter<T> {
+    /// Budget of the JSON-encoded items of one batch, unlimited when None
+    max_bytes: Option<usize>,
+    /// Batches split off and not yet emitted
+    pending: VecDeque<Vec<T>>,
+}
+
+impl<T> Default for BatchSplitter<T> {
+    fn default() -> Self {
+        Self {
+            max_bytes: None,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<T: Serialize> BatchSplitter<T> {
+    pub(crate) fn set_max_bytes(&mut self, max_bytes: usize) {
+        self.max_bytes = Some(max_bytes);
+    }
+
+    /// Returns the next batch held back by a previous split
+    pub(crate) fn next_pending(&mut self) -> Option<Vec<T>> {
+        self.pending.pop_front()
+    }
+
+    /// Returns whether batches are held back
+    pub(crate) fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Returns the first batch within the budget, holding back the rest
+    ///
+    /// Items are kept in order and every batch holds at least one item, so an item
+    /// larger than the budget is emitted alone.
+    pub(crate) fn split(&mut self, items: Vec<T>) -> Vec<T> {
+        let Some(max_bytes) = self.max_bytes else {
+            return items;
+        };
+
+        let mut batches = Vec::new();
+        let mut batch = Vec::new();
+        let mut batch_bytes = 0;
+        for item in items {
+            let bytes = serde_json::to_vec(&item).map_or(0, |encoded| encoded.len());
+            if !batch.is_empty() && batch_bytes + bytes > max_bytes {
+                batches.push(std::mem::take(&mut batch));
+                batch_bytes = 0;
+            }
+            batch_bytes += bytes;
+            batch.push(item);
+        }
+        batches.push(batch);
+        if batches.len() > 1 {
+            tracing::debug!(
+                "Split batch over {} bytes into {}",
+                max_bytes,
+                batches.len()
+            );
+        }
+
+        let mut batches = batches.into_iter();
+        let first = batches.next().unwrap_or_default();
+        self.pending.extend(batches);
+        first
+    }
+}
@@ -0,0 +1,269 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use fluxus::utils::models::{StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Minimum time between writes of a persisted filter
+const SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Bloom filter bits and the number of items inserted
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Generation {
+    /// Bit array, base64 encoded when persisted
+    #[serde(with = "base64_bytes")]
+    bits: Vec<u8>,
+    /// Items inserted
+    items: usize,
+}
+
+impl Generation {
+    fn new(bits: usize) -> Self {
+        Self {
+            bits: vec![0; bits.div_ceil(8)],
+            items: 0,
+        }
+    }
+
+    fn get(&self, bit: usize) -> bool {
+        self.bits[bit / 8] & (1 << (bit % 8)) != 0
+    }
+
+    fn set(&mut self, bit: usize) {
+        self.bits[bit / 8] |= 1 << (bit % 8);
+    }
+}
+
+/// Compact set of recently emitted digests, persisted across restarts
+///
+/// A Bloom filter sized for `capacity` digests at the configured false-positive
+/// rate. Once `capacity` digests are inserted the filter rotates: the full
+/// generation is kept for lookups and a new one receives inserts, so the last
+/// `capacity` to `2 * capacity` digests are remembered. A false positive makes a
+/// source drop a digest it never emitted, at the configured rate.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DigestFilter {
+    /// Digests remembered per generation
+    capacity: usize,
+    /// Target false-positive rate
+    false_positive_rate: f64,
+    /// Bits per generation
+    bits: usize,
+    /// Bit positions per digest
+    hashes: u32,
+    /// Generation receiving inserts
+    current: Generation,
+    /// Previous generation, kept for lookups
+    previous: Option<Generation>,
+}
+
+impl DigestFilter {
+    /// Creates an empty filter
+    ///
+    /// # Parameters
+    /// * `capacity` - Number of digests remembered before the filter rotates
+    /// * `false_positive_rate` - Probability that an unseen digest is reported as seen
+    pub fn new(capacity: usize, false_positive_rate: f64) -> Self {
+        let capacity = capacity.max(1);
+        let rate = false_positive_rate.clamp(f64::MIN_POSITIVE, 0.5);
+        let ln2 = std::f64::consts::LN_2;
+        let bits = (-(capacity as f64) * rate.ln() / (ln2 * ln2))
+            .ceil()
+            .max(8.0) as usize;
+        let hashes = ((bits as f64 / capacity as f64) * ln2).round().max(1.0) as u32;
+        Self {
+            capacity,
+            false_positive_rate: rate,
+            bits,
+            hashes,
+            current: Generation::new(bits),
+            previous: None,
+        }
+    }
+
+    /// Returns whether the digest was probably inserted
+    pub fn contains(&self, digest: &str) -> bool {
+        let positions: Vec<usize> = self.positions(digest).collect();
+        let found = |generation: &Generation| positions.iter().all(|&bit| generation.get(bit));
+        found(&self.current) || self.previous.as_ref().is_some_and(found)
+    }
+
+    /// Inserts a digest, rotating the filter once the current generation is full
+    pub fn insert(&mut self, digest: &str) {
+        if self.current.items >= self.capacity {
+            let full = std::mem::replace(&mut self.current, Generation::new(self.bits));
+            self.previous = Some(full);
+        }
+        let positions: Vec<usize> = self.positions(digest).collect();
+        for bit in positions {
+            self.current.set(bit);
+        }
+        self.current.items += 1;
+    }
+
+    /// Returns whether two filters are sized alike, so one can replace the other
+    pub(crate) fn same_sizing(&self, other: &Self) -> bool {
+        self.capacity == other.capacity && self.bits == other.bits && self.hashes == other.hashes
+    }
+
+    /// Writes the filter to a file, replacing it atomically
+    pub fn save(&self, path: &Path) -> StreamResult<()> {
+        let encoded = serde_json::to_vec(self)
+            .map_err(|e| StreamError::Runtime(format!("Failed to encode digest filter: {}", e)))?;
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, encoded)
+            .and_then(|_| fs::rename(&temporary, path))
+            .map_err(|e| {
+                StreamError::Runtime(format!(
+                    "Failed to write digest filter {}: {}",
+                    path.display(),
+                    e
+                ))
+            })
+    }
+
+    /// Reads a filter written by `save`, None if the file does not exist
+    pub fn load(path: &Path) -> StreamResult<Option<Self>> {
+        let encoded = match fs::read(path) {
+            Ok(encoded) => encoded,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(StreamError::Runtime(format!(
+                    "Failed to read digest filter {}: {}",
+                    path.display(),
+                    e
+                )));
+            }
+        };
+        let filter: Self = serde_json::from_slice(&encoded).map_err(|e| {
+            StreamError::Runtime(format!(
+                "Failed to decode digest filter {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let width = filter.bits.div_ceil(8);
+        let sized = |generation: &Generation| generation.bits.len() == width;
+        if filter.bits == 0
+            || filter.hashes == 0
+            || !sized(&filter.current)
+            || filter
+                .previous
+                .as_ref()
+                .is_some_and(|previous| !sized(previous))
+        {
+            return Err(StreamError::Runtime(format!(
+                "Digest filter {} is corrupt",
+                path.display()
+            )));
+        }
+        Ok(Some(filter))
+    }
+
+    /// Bit positions of a digest, by double hashing its SHA-256
+    fn positions(&self, digest: &str) -> impl Iterator<Item = usize> {
+        let hash = Sha256::digest(digest.as_bytes());
+        let h1 = u64::from_le_bytes(hash[0..8].try_into().unwrap_or_default());
+        let h2 = u64::from_le_bytes(hash[8..16].try_into().unwrap_or_default()) | 1;
+        let bits = self.bits as u64;
+        (0..self.hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % bits) as usize)
+    }
+}
+
+/// Digest filter persisted to a file by a source
+///
+/// Writes run on the blocking pool, at most once per `SAVE_INTERVAL` while
+/// digests are inserted and once more when the source closes, so a crash loses
+/// at most the digests of the last interval.
+pub(crate) struct PersistedFilter {
+    /// File the filter persists to
+    path: PathBuf,
+    /// Filter of the emitted digests
+    filter: DigestFilter,
+    /// Time of the last write
+    saved_at: Option<Instant>,
+    /// Whether digests were inserted since the last write
+    dirty: bool,
+}
+
+impl PersistedFilter {
+    pub(crate) fn new(path: PathBuf, filter: DigestFilter) -> Self {
+        Self {
+            path,
+            filter,
+            saved_at: None,
+            dirty: false,
+        }
+    }
+
+    pub(crate) fn contains(&self, digest: &str) -> bool {
+        self.filter.contains(digest)
+    }
+
+    pub(crate) fn insert(&mut self, digest: &str) {
+        self.filter.insert(digest);
+        self.dirty = true;
+    }
+
+    /// Replaces the filter with the one persisted before a restart, if sized alike
+    pub(crate) async fn restore(&mut self) {
+        let path = self.path.clone();
+        let loaded = tokio::task::spawn_blocking(move || DigestFilter::load(&path))
+            .await
+            .unwrap_or_else(|e| Err(StreamError::Runtime(e.to_string())));
+        match loaded {
+            Ok(Some(loaded)) if loaded.same_sizing(&self.filter) => self.filter = loaded,
+            Ok(Some(_)) => tracing::warn!(
+                "Digest filter {} was sized differently, starting empty",
+                self.path.display()
+            ),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("{}, starting empty", e),
+        }
+    }
+
+    /// Writes the filter if digests were inserted and the save interval elapsed
+    pub(crate) async fn save_due(&mut self) -> StreamResult<()> {
+        if self
+            .saved_at
+            .is_some_and(|saved_at| saved_at.elapsed() < SAVE_INTERVAL)
+        {
+            return Ok(());
+        }
+        self.flush().await
+    }
+
+    /// Writes the filter if digests were inserted since the last write
+    pub(crate) async fn flush(&mut self) -> StreamResult<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let (path, filter) = (self.path.clone(), self.filter.clone());
+        tokio::task::spawn_blocking(move || filter.save(&path))
+            .await
+            .unwrap_or_else(|e| Err(StreamError::Runtime(e.to_string())))?;
+        self.saved_at = Some(Instant::now());
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+/// Serializes bytes as a base64 string
+mod base64_bytes {
+    use super::BASE64;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64.encode(bytes))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        BASE64.decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// Maximum number of entries kept per cache before expired entries are purged
+const MAX_ENTRIES: usize = 10_000;
+
+/// Map whose entries expire a fixed time after insertion
+pub(crate) struct TtlCache<K, V> {
+    /// Cached values and their insertion times
+    entries: HashMap<K, (Instant, V)>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the value if it was inserted less than `ttl` ago
+    pub(crate) fn get(&self, key: &K, ttl: Duration) -> Option<V> {
+        self.entries
+            .get(key)
+            .filter(|(inserted, _)| inserted.elapsed() < ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Inserts a value, purging expired entries when the cache is full
+    pub(crate) fn insert(&mut self, key: K, value: V, ttl: Duration) {
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries
+                .retain(|_, (inserted, _)| inserted.elapsed() < ttl);
+        }
+        // Still full with live entries, start over rather than grow unbounded
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.clear();
+        }
+        self.entries.insert(key, (Instant::now(), value));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -0,0 +1,128 @@
+use crate::transaction::SuiTransactionSource;
+use crate::window::TumblingWindow;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use sui_sdk::rpc_types::{SuiCommand, SuiTransactionBlockDataAPI, SuiTransactionBlockKind};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallCount {
+    /// Package ID
+    pub package: String,
+    /// Module name
+    pub module: String,
+    /// Function name
+    pub function: String,
+    /// Number of Move calls within the window
+    pub count: u64,
+    /// Window start, local time (milliseconds)
+    pub window_start: u64,
+    /// Window end, local time (milliseconds)
+    pub window_end: u64,
+}
+
+/// Source emitting per-function Move call counts over tumbling windows
+///
+/// Calls are counted from the commands of programmable transactions polled by the
+/// inner source, so it must fetch transaction input (not effects-only). Restrict the
+/// inner source with a `TransactionFilter::MoveFunction` query to count the calls of
+/// one package. Windows follow local time; one record holding every function called
+/// within the window is emitted when it closes, and polls within the window yield None.
+pub struct SuiCallFrequencySource {
+    /// Underlying transaction source
+    inner: SuiTransactionSource,
+    /// Counting window
+    window: TumblingWindow,
+    /// Calls counted in the current window
+    counts: HashMap<(String, String, String), u64>,
+}
+
+impl SuiCallFrequencySource {
+    /// Creates a new SuiCallFrequencySource
+    ///
+    /// # Parameters
+    /// * `inner` - Transaction source providing the calls
+    /// * `window` - Length of the counting window
+    pub fn new(inner: SuiTransactionSource, window: Duration) -> Self {
+        Self {
+            inner,
+            window: TumblingWindow::new(window),
+            counts: HashMap::new(),
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl Source<Vec<CallCount>> for SuiCallFrequencySource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<CallCount>>>> {
+        self.window.open();
+
+        if let Some(record) = self.inner.next().await? {
+            for transaction in record.data {
+                if !self.window.first_seen(&transaction.transaction_digest) {
+                    continue;
+                }
+                let Some(metadata) = &transaction.metadata else {
+                    continue;
+                };
+                let SuiTransactionBlockKind::ProgrammableTransaction(programmable) =
+                    metadata.transaction()
+                else {
+                    continue;
+                };
+                for command in &programmable.commands {
+                    if let SuiCommand::MoveCall(call) = command {
+                        *self
+                            .counts
+                            .entry((
+                                call.package.to_string(),
+                                call.module.clone(),
+                                call.function.clone(),
+                            ))
+                            .or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        // Keep counting until the window closes
+        let Some((window_start, window_end)) = self.window.close() else {
+            return Ok(None);
+        };
+
+        let mut counts: Vec<CallCount> = self
+            .counts
+            .drain()
+            .map(|((package, module, function), count)| CallCount {
+                package,
+                module,
+                function,
+                count,
+                window_start,
+                window_end,
+            })
+            .collect();
+        if counts.is_empty() {
+            tracing::info!("No Move calls within the window");
+            return Ok(None);
+        }
+        counts.sort_by(|a, b| b.count.cmp(&a.count));
+
+        Ok(Some(Record::new(counts)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
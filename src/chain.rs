@@ -0,0 +1,63 @@
+use crate::error::SuiSourceError;
+use crate::rpc;
+use fluxus::utils::models::StreamResult;
+use serde::{Deserialize, Serialize};
+use sui_sdk::{SUI_DEVNET_URL, SUI_LOCAL_NETWORK_URL, SUI_MAINNET_URL, SUI_TESTNET_URL, SuiClient};
+
+/// Network a record was read from, stamped on every emitted record
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChainMetadata {
+    /// Configured network name, e.g. `mainnet` or `testnet`
+    pub network: String,
+    /// Chain identifier reported by the node (digest of the genesis checkpoint)
+    pub chain_identifier: String,
+}
+
+impl ChainMetadata {
+    /// Fetches the chain identifier of the connected node
+    pub(crate) async fn fetch(
+        source: &str,
+        client: &SuiClient,
+        network: String,
+    ) -> StreamResult<Self> {
+        let chain_identifier = rpc::call(
+            source,
+            "sui_getChainIdentifier",
+            "fetch chain identifier",
+            client.read_api().get_chain_identifier(),
+        )
+        .await?;
+        Ok(Self {
+            network,
+            chain_identifier,
+        })
+    }
+
+    /// Returns the reset error when the chain differs from the expected one
+    ///
+    /// The chain identifier is the digest of the genesis checkpoint, so a wiped
+    /// network such as devnet comes back with a new one.
+    pub(crate) fn reset_from(&self, expected: &str) -> Option<SuiSourceError> {
+        (self.chain_identifier != expected).then(|| SuiSourceError::NetworkReset {
+            expected: expected.to_string(),
+            actual: self.chain_identifier.clone(),
+        })
+    }
+}
+
+/// Derives a network name from the well-known RPC endpoints, `custom` otherwise
+pub(crate) fn network_name(rpc_url: &str) -> String {
+    let rpc_url = rpc_url.trim_end_matches('/');
+    let name = if rpc_url == SUI_MAINNET_URL.trim_end_matches('/') {
+        "mainnet"
+    } else if rpc_url == SUI_TESTNET_URL.trim_end_matches('/') {
+        "testnet"
+    } else if rpc_url == SUI_DEVNET_URL.trim_end_matches('/') {
+        "devnet"
+    } else if rpc_url == SUI_LOCAL_NETWORK_URL.trim_end_matches('/') {
+        "localnet"
+    } else {
+        "custom"
+    };
+    name.to_string()
+}
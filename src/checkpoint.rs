@@ -0,0 +1,236 @@
+use crate::connection::SuiConnection;
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+use sui_sdk::rpc_types::Checkpoint;
+use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_sdk::types::sui_serde::BigInt;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+/// Maximum number of checkpoints the node returns per page
+const CHECKPOINT_PAGE_LIMIT: usize = 100;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainCheckpoint {
+    /// Checkpoint sequence number
+    pub sequence_number: u64,
+    /// Checkpoint digest
+    pub digest: String,
+    /// Digest of the previous checkpoint, None for genesis
+    pub previous_digest: Option<String>,
+    /// Epoch of the checkpoint
+    pub epoch: u64,
+    /// Timestamp (milliseconds)
+    pub timestamp: u64,
+    /// Digests of the transactions of the checkpoint, in execution order
+    pub transactions: Vec<String>,
+    /// Number of transactions on the network up to and including this checkpoint
+    pub network_total_transactions: u64,
+}
+
+impl From<Checkpoint> for ChainCheckpoint {
+    fn from(checkpoint: Checkpoint) -> Self {
+        ChainCheckpoint {
+            sequence_number: checkpoint.sequence_number,
+            digest: checkpoint.digest.to_string(),
+            previous_digest: checkpoint.previous_digest.map(|digest| digest.to_string()),
+            epoch: checkpoint.epoch,
+            timestamp: checkpoint.timestamp_ms,
+            transactions: checkpoint
+                .transactions
+                .iter()
+                .map(|digest| digest.to_string())
+                .collect(),
+            network_total_transactions: checkpoint.network_total_transactions,
+        }
+    }
+}
+
+/// Sui blockchain data source streaming whole checkpoints in sequence order
+///
+/// Emits one record per checkpoint, the natural unit for indexers. Reads start
+/// after the head at init unless a start checkpoint is set, and page through
+/// `sui_getCheckpoints` ascending; a page of checkpoints is fetched at once and
+/// handed out one per call to `next` before the source polls again.
+pub struct SuiCheckpointSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Shared connection, a client is built in `init` when None
+    connection: Option<SuiConnection>,
+    /// First checkpoint to read, the one after the head at init when None
+    start: Option<CheckpointSequenceNumber>,
+    /// Last checkpoint read
+    cursor: Option<CheckpointSequenceNumber>,
+    /// Checkpoints fetched and not emitted yet
+    pending: VecDeque<ChainCheckpoint>,
+}
+
+impl SuiCheckpointSource {
+    /// Creates a new SuiCheckpointSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            connection: None,
+            start: None,
+            cursor: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Creates a new SuiCheckpointSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms)
+    }
+
+    /// Starts reading at the given checkpoint instead of the head
+    pub fn with_start(mut self, sequence_number: CheckpointSequenceNumber) -> Self {
+        self.start = Some(sequence_number);
+        self
+    }
+
+    /// Reuses a shared connection instead of building a client in `init`
+    ///
+    /// The connection's endpoint takes precedence over the RPC URL of the source.
+    pub fn with_connection(mut self, connection: SuiConnection) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Returns the last checkpoint emitted, resume after a restart with `with_start(cursor + 1)`
+    pub fn cursor(&self) -> Option<CheckpointSequenceNumber> {
+        self.pending
+            .front()
+            .map(|checkpoint| checkpoint.sequence_number.saturating_sub(1))
+            .or(self.cursor)
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+}
+
+#[async_trait]
+impl Source<ChainCheckpoint> for SuiCheckpointSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        let client = match &self.connection {
+            Some(connection) => connection.client().clone(),
+            None => {
+                rpc::call(
+                    "SuiCheckpointSource",
+                    "rpc.discover",
+                    "initialize Sui client",
+                    SuiClientBuilder::default().build(self.rpc_url.as_str()),
+                )
+                .await?
+            }
+        };
+
+        // The page cursor is exclusive, so it points at the checkpoint before the first
+        self.cursor = match self.start {
+            Some(start) => start.checked_sub(1),
+            None => Some(
+                rpc::call(
+                    "SuiCheckpointSource",
+                    "sui_getLatestCheckpointSequenceNumber",
+                    "fetch latest checkpoint",
+                    client.read_api().get_latest_checkpoint_sequence_number(),
+                )
+                .await?,
+            ),
+        };
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiCheckpointSource initialized with RPC URL: {}",
+            self.connection
+                .as_ref()
+                .map_or(self.rpc_url.as_str(), |connection| connection.rpc_url())
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<ChainCheckpoint>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiCheckpointSource not initialized".to_string(),
+            ));
+        }
+
+        // Emit checkpoints fetched by a previous poll first
+        if let Some(checkpoint) = self.pending.pop_front() {
+            return Ok(Some(Record::new(checkpoint)));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        if let Some(connection) = &self.connection {
+            connection.touch();
+        }
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiCheckpointSource client not available".to_string())
+        })?;
+
+        let page = rpc::call(
+            "SuiCheckpointSource",
+            "sui_getCheckpoints",
+            "fetch checkpoints",
+            client.read_api().get_checkpoints(
+                self.cursor.map(BigInt::from),
+                Some(CHECKPOINT_PAGE_LIMIT),
+                false,
+            ),
+        )
+        .await?;
+
+        if let Some(last) = page.data.last() {
+            self.cursor = Some(last.sequence_number);
+        }
+        self.pending
+            .extend(page.data.into_iter().map(ChainCheckpoint::from));
+
+        // Return None if no new checkpoint was produced
+        let Some(checkpoint) = self.pending.pop_front() else {
+            tracing::info!("No new checkpoints found");
+            return Ok(None);
+        };
+        tracing::debug!(
+            "Processed Sui checkpoint: {} transactions: {}",
+            checkpoint.sequence_number,
+            checkpoint.transactions.len()
+        );
+        Ok(Some(Record::new(checkpoint)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiCheckpointSource closed");
+        Ok(())
+    }
+}
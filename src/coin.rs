@@ -0,0 +1,311 @@
+use crate::connection::SuiConnection;
+use crate::rpc;
+use crate::units::format_amount;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::time::Duration;
+use sui_sdk::rpc_types::SuiCoinMetadata;
+use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoinRecord {
+    /// Owner address
+    pub owner: String,
+    /// Coin type, e.g. `0x2::sui::SUI`
+    pub coin_type: String,
+    /// Coin object ID
+    pub coin_object_id: String,
+    /// Object version
+    pub version: u64,
+    /// Object digest
+    pub digest: String,
+    /// Balance in the coin's smallest unit
+    pub balance: u64,
+    /// Balance scaled by the coin's decimals, None without coin metadata
+    pub decimal_balance: Option<String>,
+    /// Coin symbol, None without coin metadata
+    pub symbol: Option<String>,
+    /// Coin decimals, None without coin metadata
+    pub decimals: Option<u8>,
+    /// Digest of the transaction that last changed the coin
+    pub previous_transaction: String,
+}
+
+/// Sui blockchain data source emitting the coin objects of an address
+///
+/// Polls page through the address's coins of one type and emit those that are new
+/// or changed version since they were last emitted, so the first scan emits every
+/// coin. A poll that spends `max_coins` leaves the rest of the scan to the next
+/// polls, which resume at its page cursor and start over once every coin was read. Records carry the symbol and decimals of the coin metadata,
+/// fetched once at init. Coins merged or spent away are dropped from tracking.
+pub struct SuiCoinSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Shared connection reused instead of building a client
+    connection: Option<SuiConnection>,
+    /// Address whose coins are tracked
+    address: String,
+    /// Coin type to track
+    coin_type: String,
+    /// Coins fetched per page
+    page_size: usize,
+    /// Maximum number of coins scanned per poll
+    max_coins: usize,
+    /// Metadata of the coin type, None if the coin has none or it could not be fetched
+    metadata: Option<SuiCoinMetadata>,
+    /// Versions of the coins emitted so far by object ID
+    last_versions: HashMap<String, u64>,
+    /// Page cursor the next poll resumes the scan at, None to start from the first coin
+    scan_cursor: Option<ObjectID>,
+    /// Coins read by the previous polls of the current scan
+    scanned: HashSet<String>,
+}
+
+impl SuiCoinSource {
+    /// Creates a new SuiCoinSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `address` - Address whose coins are tracked
+    /// * `coin_type` - Coin type to track, e.g. `0x2::sui::SUI`
+    pub fn new(rpc_url: String, interval_ms: u64, address: String, coin_type: String) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            connection: None,
+            address,
+            coin_type,
+            page_size: 50,
+            max_coins: 1000,
+            metadata: None,
+            last_versions: HashMap::new(),
+            scan_cursor: None,
+            scanned: HashSet::new(),
+        }
+    }
+
+    /// Creates a new SuiCoinSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64, address: String, coin_type: String) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms, address, coin_type)
+    }
+
+    /// Sets the number of coins fetched per page
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Sets the maximum number of coins scanned per poll, larger accounts take several polls
+    pub fn with_max_coins(mut self, max_coins: usize) -> Self {
+        self.max_coins = max_coins;
+        self
+    }
+
+    /// Reuses a shared connection instead of building a client in `init`
+    ///
+    /// The connection's endpoint takes precedence over the RPC URL of the source,
+    /// and coin metadata is read through the connection's cache.
+    pub fn with_connection(mut self, connection: SuiConnection) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+}
+
+#[async_trait]
+impl Source<Vec<CoinRecord>> for SuiCoinSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client, reusing the shared connection if set
+        let client = match &self.connection {
+            Some(connection) => connection.client().clone(),
+            None => {
+                rpc::call(
+                    "SuiCoinSource",
+                    "rpc.discover",
+                    "initialize Sui client",
+                    SuiClientBuilder::default().build(self.rpc_url.as_str()),
+                )
+                .await?
+            }
+        };
+
+        // Coin metadata is fixed when the coin is created, so it is fetched once
+        let metadata = match &self.connection {
+            Some(connection) => connection.get_coin_metadata(&self.coin_type).await,
+            None => {
+                rpc::call(
+                    "SuiCoinSource",
+                    "suix_getCoinMetadata",
+                    &format!("fetch coin metadata of {}", self.coin_type),
+                    client
+                        .coin_read_api()
+                        .get_coin_metadata(self.coin_type.clone()),
+                )
+                .await
+            }
+        };
+        self.metadata = match metadata {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                tracing::warn!(
+                    "Coin records of {} will lack metadata: {}",
+                    self.coin_type,
+                    e
+                );
+                None
+            }
+        };
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiCoinSource initialized with RPC URL: {}",
+            self.connection
+                .as_ref()
+                .map_or(self.rpc_url.as_str(), SuiConnection::rpc_url)
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<CoinRecord>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiCoinSource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        // Mark the shared connection as active
+        if let Some(connection) = &self.connection {
+            connection.touch();
+        }
+
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiCoinSource client not available".to_string())
+        })?;
+
+        // Parse owner address
+        let owner = SuiAddress::from_str(&self.address).map_err(|e| {
+            tracing::error!("Invalid owner address: {}", e);
+            StreamError::Runtime(format!("Invalid owner address: {}", e))
+        })?;
+
+        // Paginate coins from where the scan stopped until exhausted or the budget is spent
+        let mut coins = Vec::new();
+        let mut cursor = self.scan_cursor;
+        let mut exhausted = false;
+        while coins.len() < self.max_coins {
+            let page = rpc::call(
+                "SuiCoinSource",
+                "suix_getCoins",
+                "fetch coins",
+                client.coin_read_api().get_coins(
+                    owner,
+                    Some(self.coin_type.clone()),
+                    cursor,
+                    Some(self.page_size.min(self.max_coins - coins.len())),
+                ),
+            )
+            .await?;
+            coins.extend(page.data);
+
+            if !page.has_next_page || page.next_cursor.is_none() {
+                exhausted = true;
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        // Forget coins no longer owned once every coin was scanned, then start over
+        self.scanned
+            .extend(coins.iter().map(|coin| coin.coin_object_id.to_string()));
+        if exhausted {
+            let owned = std::mem::take(&mut self.scanned);
+            self.last_versions
+                .retain(|coin_object_id, _| owned.contains(coin_object_id));
+            self.scan_cursor = None;
+        } else {
+            self.scan_cursor = cursor;
+        }
+
+        // Keep coins that are new or changed since they were last emitted
+        let symbol = self
+            .metadata
+            .as_ref()
+            .map(|metadata| metadata.symbol.clone());
+        let decimals = self.metadata.as_ref().map(|metadata| metadata.decimals);
+        let mut records = Vec::new();
+        for coin in coins {
+            let coin_object_id = coin.coin_object_id.to_string();
+            let version = coin.version.value();
+            if self
+                .last_versions
+                .get(&coin_object_id)
+                .is_some_and(|last_version| *last_version == version)
+            {
+                continue;
+            }
+            self.last_versions.insert(coin_object_id.clone(), version);
+            records.push(CoinRecord {
+                owner: self.address.clone(),
+                coin_type: coin.coin_type,
+                coin_object_id,
+                version,
+                digest: coin.digest.to_string(),
+                balance: coin.balance,
+                decimal_balance: decimals
+                    .map(|decimals| format_amount(coin.balance as i128, decimals)),
+                symbol: symbol.clone(),
+                decimals,
+                previous_transaction: coin.previous_transaction.to_string(),
+            });
+        }
+
+        // Return None if no coin changed
+        if records.is_empty() {
+            tracing::info!("No coin changes found");
+            return Ok(None);
+        }
+        tracing::debug!(
+            "Found {} changed {} coins of {}",
+            records.len(),
+            self.coin_type,
+            self.address
+        );
+
+        Ok(Some(Record::new(records)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiCoinSource closed");
+        Ok(())
+    }
+}
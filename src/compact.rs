@@ -0,0 +1,75 @@
+use crate::transaction::{SuiEvent, SuiTransactionSource};
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::{SuiExecutionStatus, SuiTransactionBlockEffectsAPI};
+
+/// Transaction reduced to the fields counting and statistics pipelines use
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactSuiEvent {
+    /// Transaction ID
+    pub transaction_digest: String,
+    /// Transaction type
+    pub transaction_type: String,
+    /// Sender address
+    pub sender: String,
+    /// Timestamp
+    pub timestamp: u64,
+    /// Checkpoint sequence number
+    pub checkpoint: Option<u64>,
+    /// Whether the transaction executed successfully, None without effects
+    pub success: Option<bool>,
+}
+
+impl From<SuiEvent> for CompactSuiEvent {
+    fn from(event: SuiEvent) -> Self {
+        CompactSuiEvent {
+            transaction_digest: event.transaction_digest,
+            transaction_type: event.transaction_type,
+            sender: event.sender,
+            timestamp: event.timestamp,
+            checkpoint: event.checkpoint,
+            success: event
+                .effects
+                .as_ref()
+                .map(|effects| matches!(effects.status(), SuiExecutionStatus::Success)),
+        }
+    }
+}
+
+/// Transaction source emitting `CompactSuiEvent` records, see `SuiTransactionSource::compact`
+pub struct CompactSuiTransactionSource {
+    /// Underlying transaction source, configured for compact records
+    inner: SuiTransactionSource,
+}
+
+impl CompactSuiTransactionSource {
+    pub(crate) fn new(inner: SuiTransactionSource) -> Self {
+        Self { inner }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl Source<Vec<CompactSuiEvent>> for CompactSuiTransactionSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<CompactSuiEvent>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+        Ok(Some(Record::new(
+            record.data.into_iter().map(CompactSuiEvent::from).collect(),
+        )))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,283 @@
+use crate::cache::TtlCache;
+use crate::rpc;
+use fluxus::utils::models::StreamResult;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::time::{Duration, Instant};
+use sui_sdk::rpc_types::{SuiCoinMetadata, SuiObjectDataOptions, SuiObjectResponse};
+use sui_sdk::types::base_types::ObjectID;
+use sui_sdk::{SuiClient, SuiClientBuilder};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+
+/// State shared by every handle of a connection
+struct Inner {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Sui client
+    client: SuiClient,
+    /// Last time a source used the connection
+    last_activity: Mutex<Instant>,
+    /// Background keep-warm task
+    keep_warm: Mutex<Option<JoinHandle<()>>>,
+    /// Cached responses of repeated reads
+    cache: Mutex<ResponseCache>,
+    /// Latest checkpoint sequence number and when it was fetched
+    latest_checkpoint: Mutex<Option<(Instant, u64)>>,
+}
+
+/// Responses cached by a connection, disabled until a TTL is set
+struct ResponseCache {
+    /// Time entries stay valid, caching disabled when None
+    ttl: Option<Duration>,
+    /// Objects by ID and requested options
+    objects: TtlCache<(ObjectID, String), SuiObjectResponse>,
+    /// Coin metadata by coin type
+    coin_metadata: TtlCache<String, Option<SuiCoinMetadata>>,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        let keep_warm = self
+            .keep_warm
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(task) = keep_warm.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Sui client shared by several sources
+///
+/// Sources configured with the same connection reuse one client instead of each
+/// building their own in `init`. Cloning a connection yields a handle to the same
+/// client.
+#[derive(Clone)]
+pub struct SuiConnection {
+    inner: Arc<Inner>,
+}
+
+impl SuiConnection {
+    /// Connects to a Sui RPC endpoint
+    pub async fn connect(rpc_url: &str) -> StreamResult<Self> {
+        let client = rpc::call(
+            "SuiConnection",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(rpc_url),
+        )
+        .await?;
+        tracing::info!("SuiConnection established with RPC URL: {}", rpc_url);
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                rpc_url: rpc_url.to_string(),
+                client,
+                last_activity: Mutex::new(Instant::now()),
+                keep_warm: Mutex::new(None),
+                cache: Mutex::new(ResponseCache {
+                    ttl: None,
+                    objects: TtlCache::new(),
+                    coin_metadata: TtlCache::new(),
+                }),
+                latest_checkpoint: Mutex::new(None),
+            }),
+        })
+    }
+
+    /// Returns the shared client
+    pub fn client(&self) -> &SuiClient {
+        &self.inner.client
+    }
+
+    /// Returns the RPC endpoint URL
+    pub fn rpc_url(&self) -> &str {
+        &self.inner.rpc_url
+    }
+
+    /// Starts a background task pinging the node whenever the connection is idle
+    ///
+    /// Each time `interval` passes without any source polling, the task issues a
+    /// `get_latest_checkpoint_sequence_number` call so the underlying HTTP connection
+    /// stays open and the next poll does not pay for a fresh handshake. Calling this
+    /// again replaces the previous task. The task stops when the last handle is dropped.
+    pub fn start_keep_warm(&self, interval: Duration) {
+        let weak = Arc::downgrade(&self.inner);
+        let task = tokio::spawn(keep_warm(weak, interval));
+
+        let mut keep_warm = self
+            .inner
+            .keep_warm
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(previous) = keep_warm.replace(task) {
+            previous.abort();
+        }
+    }
+
+    /// Stops the keep-warm task if running
+    pub fn stop_keep_warm(&self) {
+        let mut keep_warm = self
+            .inner
+            .keep_warm
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(task) = keep_warm.take() {
+            task.abort();
+        }
+    }
+
+    /// Caches object and coin metadata reads for `ttl`, shared by every handle
+    ///
+    /// Intended for data that rarely changes, such as packages, coin metadata and
+    /// Display templates; mutable objects may be served up to `ttl` stale.
+    pub fn enable_cache(&self, ttl: Duration) {
+        self.cache().ttl = Some(ttl);
+    }
+
+    /// Disables caching and drops every cached response
+    pub fn disable_cache(&self) {
+        let mut cache = self.cache();
+        cache.ttl = None;
+        cache.objects.clear();
+        cache.coin_metadata.clear();
+    }
+
+    /// Fetches an object, served from the cache when enabled and fresh
+    pub async fn get_object(
+        &self,
+        object_id: ObjectID,
+        options: SuiObjectDataOptions,
+    ) -> StreamResult<SuiObjectResponse> {
+        let key = (object_id, format!("{:?}", options));
+        let ttl = self.cache().ttl;
+        if let Some(ttl) = ttl
+            && let Some(object) = self.cache().objects.get(&key, ttl)
+        {
+            return Ok(object);
+        }
+
+        let object = rpc::call(
+            "SuiConnection",
+            "sui_getObject",
+            &format!("fetch object {}", object_id),
+            self.client()
+                .read_api()
+                .get_object_with_options(object_id, options),
+        )
+        .await?;
+        if let Some(ttl) = ttl {
+            self.cache().objects.insert(key, object.clone(), ttl);
+        }
+        Ok(object)
+    }
+
+    /// Fetches the metadata of a coin type, served from the cache when enabled and fresh
+    pub async fn get_coin_metadata(
+        &self,
+        coin_type: &str,
+    ) -> StreamResult<Option<SuiCoinMetadata>> {
+        let ttl = self.cache().ttl;
+        if let Some(ttl) = ttl
+            && let Some(metadata) = self.cache().coin_metadata.get(&coin_type.to_string(), ttl)
+        {
+            return Ok(metadata);
+        }
+
+        let metadata = rpc::call(
+            "SuiConnection",
+            "suix_getCoinMetadata",
+            &format!("fetch coin metadata of {}", coin_type),
+            self.client()
+                .coin_read_api()
+                .get_coin_metadata(coin_type.to_string()),
+        )
+        .await?;
+        if let Some(ttl) = ttl {
+            self.cache()
+                .coin_metadata
+                .insert(coin_type.to_string(), metadata.clone(), ttl);
+        }
+        Ok(metadata)
+    }
+
+    /// Fetches the latest checkpoint, shared by the sources of this connection
+    ///
+    /// A value fetched less than `max_age` ago by any handle is reused, so
+    /// partitioned sources following the head issue one call per interval.
+    pub(crate) async fn latest_checkpoint(&self, max_age: Duration) -> StreamResult<u64> {
+        let cached = *self
+            .inner
+            .latest_checkpoint
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some((fetched, latest)) = cached
+            && fetched.elapsed() < max_age
+        {
+            return Ok(latest);
+        }
+
+        let latest = rpc::call(
+            "SuiConnection",
+            "sui_getLatestCheckpointSequenceNumber",
+            "fetch latest checkpoint",
+            self.client()
+                .read_api()
+                .get_latest_checkpoint_sequence_number(),
+        )
+        .await?;
+        *self
+            .inner
+            .latest_checkpoint
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some((Instant::now(), latest));
+        Ok(latest)
+    }
+
+    fn cache(&self) -> std::sync::MutexGuard<'_, ResponseCache> {
+        self.inner
+            .cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Records that a source is using the connection
+    pub(crate) fn touch(&self) {
+        *self
+            .inner
+            .last_activity
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Instant::now();
+    }
+}
+
+/// Pings the node after every idle interval until the connection is dropped
+async fn keep_warm(inner: Weak<Inner>, interval: Duration) {
+    loop {
+        sleep(interval).await;
+        let Some(inner) = inner.upgrade() else {
+            return;
+        };
+
+        let idle = inner
+            .last_activity
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .elapsed();
+        if idle < interval {
+            continue;
+        }
+
+        // Failures are logged by the RPC wrapper, the next interval retries
+        let _ = rpc::call(
+            "SuiConnection",
+            "sui_getLatestCheckpointSequenceNumber",
+            "keep connection warm",
+            inner
+                .client
+                .read_api()
+                .get_latest_checkpoint_sequence_number(),
+        )
+        .await;
+    }
+}
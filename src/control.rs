@@ -0,0 +1,228 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle change of a stream, interleaved with data records when enabled
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ControlEvent {
+    /// The source was initialized
+    StreamStarted,
+    /// A poll found no new data after data was emitted, the stream is at the head
+    CaughtUp,
+    /// Polls moved to another endpoint of the pool
+    EndpointSwitched {
+        /// Previous endpoint URL
+        from: String,
+        /// New endpoint URL
+        to: String,
+    },
+    /// Checkpoints between two batches were not covered by either batch
+    GapDetected {
+        /// First missing checkpoint
+        from: u64,
+        /// Last missing checkpoint
+        to: u64,
+    },
+    /// Every transaction of the checkpoint has been delivered
+    CheckpointComplete {
+        /// Checkpoint sequence number
+        sequence: u64,
+    },
+    /// Transactions of a new epoch were observed
+    EpochChanged {
+        /// Previous epoch
+        from: u64,
+        /// New epoch
+        to: u64,
+    },
+    /// Items older than the maximum age were dropped, reported once the run ends
+    AgedOut {
+        /// Number of dropped items
+        skipped: u64,
+        /// Timestamp of the oldest dropped item (milliseconds)
+        oldest_timestamp: u64,
+        /// Timestamp of the newest dropped item (milliseconds)
+        newest_timestamp: u64,
+    },
+}
+
+/// Item of a stream with control events interleaved
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum StreamItem<T> {
+    /// Data record
+    Data(T),
+    /// Lifecycle change
+    Control(ControlEvent),
+}
+
+/// Sources that can report lifecycle changes
+pub trait ControlEvents {
+    /// Starts recording control events
+    fn enable_control_events(&mut self);
+
+    /// Takes the control events recorded since the last call
+    fn drain_control_events(&mut self) -> Vec<ControlEvent>;
+}
+
+/// Control events pending emission and the state needed to detect them
+#[derive(Default)]
+pub(crate) struct ControlQueue {
+    /// Whether events are recorded
+    enabled: bool,
+    /// Events not emitted yet
+    pending: Vec<ControlEvent>,
+    /// Endpoint of the last poll
+    endpoint: Option<String>,
+    /// Highest checkpoint seen so far
+    highest_checkpoint: Option<u64>,
+    /// Epoch of the last transaction seen
+    epoch: Option<u64>,
+}
+
+impl ControlQueue {
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    pub(crate) fn drain(&mut self) -> Vec<ControlEvent> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Records the endpoint serving a poll
+    pub(crate) fn observe_endpoint(&mut self, rpc_url: &str) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(previous) = self.endpoint.replace(rpc_url.to_string())
+            && previous != rpc_url
+        {
+            self.pending.push(ControlEvent::EndpointSwitched {
+                from: previous,
+                to: rpc_url.to_string(),
+            });
+        }
+    }
+
+    /// Records the checkpoints covered by a batch
+    pub(crate) fn observe_checkpoints(&mut self, lowest: u64, highest: u64) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(previous) = self.highest_checkpoint
+            && lowest > previous + 1
+        {
+            self.pending.push(ControlEvent::GapDetected {
+                from: previous + 1,
+                to: lowest - 1,
+            });
+        }
+        self.highest_checkpoint = Some(self.highest_checkpoint.map_or(highest, |h| h.max(highest)));
+    }
+
+    /// Records that every transaction of a checkpoint has been read
+    pub(crate) fn checkpoint_complete(&mut self, sequence: u64) {
+        if self.enabled {
+            self.pending
+                .push(ControlEvent::CheckpointComplete { sequence });
+        }
+    }
+
+    /// Records the epoch of the newest transaction of a batch
+    pub(crate) fn observe_epoch(&mut self, epoch: u64) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(previous) = self.epoch.replace(epoch)
+            && previous < epoch
+        {
+            self.pending.push(ControlEvent::EpochChanged {
+                from: previous,
+                to: epoch,
+            });
+        }
+    }
+
+    /// Records the end of a run of items dropped for their age
+    pub(crate) fn aged_out(&mut self, skipped: u64, oldest_timestamp: u64, newest_timestamp: u64) {
+        if self.enabled {
+            self.pending.push(ControlEvent::AgedOut {
+                skipped,
+                oldest_timestamp,
+                newest_timestamp,
+            });
+        }
+    }
+}
+
+/// Source interleaving control events with the data records of an inner source
+///
+/// Each record holds the control events recorded during the poll, followed by the
+/// data items of the inner source and then any `CheckpointComplete` markers, so a
+/// marker always follows the data it completes. `StreamStarted` is emitted with the
+/// first record and `CaughtUp` once per transition from data to an empty poll.
+pub struct ControlledSource<S> {
+    /// Underlying source
+    inner: S,
+    /// Control events recorded by the adapter itself
+    pending: Vec<ControlEvent>,
+    /// Whether the last poll emitted data
+    had_data: bool,
+}
+
+impl<S: ControlEvents> ControlledSource<S> {
+    /// Creates a new ControlledSource, enabling control events on the inner source
+    pub fn new(mut inner: S) -> Self {
+        inner.enable_control_events();
+        Self {
+            inner,
+            pending: Vec::new(),
+            had_data: false,
+        }
+    }
+}
+
+#[async_trait]
+impl<S, T> Source<Vec<StreamItem<T>>> for ControlledSource<S>
+where
+    S: Source<Vec<T>> + ControlEvents + Send + Sync,
+    T: Send + Sync + 'static,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await?;
+        self.pending.push(ControlEvent::StreamStarted);
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<StreamItem<T>>>>> {
+        let record = self.inner.next().await?;
+
+        let (markers, events): (Vec<ControlEvent>, Vec<ControlEvent>) = self
+            .pending
+            .drain(..)
+            .chain(self.inner.drain_control_events())
+            .partition(|event| matches!(event, ControlEvent::CheckpointComplete { .. }));
+        let mut items: Vec<StreamItem<T>> = events.into_iter().map(StreamItem::Control).collect();
+        match record {
+            Some(record) => {
+                self.had_data = true;
+                items.extend(record.data.into_iter().map(StreamItem::Data));
+            }
+            None if self.had_data => {
+                self.had_data = false;
+                items.push(StreamItem::Control(ControlEvent::CaughtUp));
+            }
+            None => {}
+        }
+        items.extend(markers.into_iter().map(StreamItem::Control));
+
+        if items.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Record::new(items)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+/// Error raised when an item returned by the node cannot be converted into a record
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConversionError {
+    /// A field required by the record was not returned by the node
+    MissingField(String),
+    /// A field was returned in an unexpected format
+    InvalidField {
+        /// Field name
+        field: String,
+        /// Why the value was rejected
+        reason: String,
+    },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::MissingField(field) => write!(f, "Missing field: {}", field),
+            ConversionError::InvalidField { field, reason } => {
+                write!(f, "Invalid field {}: {}", field, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// An item that could not be converted and was dropped from the stream
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Name of the source that dropped the item
+    pub source: String,
+    /// Identifier of the item (digest, event ID, or object ID)
+    pub item_id: String,
+    /// Why the conversion failed
+    pub error: ConversionError,
+    /// Debug representation of the raw RPC item
+    pub raw: String,
+}
+
+/// Sending half of a dead-letter channel, handed to sources
+pub type DeadLetterSender = UnboundedSender<DeadLetter>;
+/// Receiving half of a dead-letter channel, drained by the application
+pub type DeadLetterReceiver = UnboundedReceiver<DeadLetter>;
+
+/// Creates a dead-letter channel for items that fail conversion
+pub fn dead_letter_channel() -> (DeadLetterSender, DeadLetterReceiver) {
+    unbounded_channel()
+}
+
+/// Logs a dropped item and forwards it to the dead-letter channel, if one is configured
+pub(crate) fn route_dead_letter(sender: Option<&DeadLetterSender>, letter: DeadLetter) {
+    tracing::warn!(
+        "{} dropped item {}: {}",
+        letter.source,
+        letter.item_id,
+        letter.error
+    );
+    if let Some(sender) = sender
+        && sender.send(letter).is_err()
+    {
+        tracing::debug!("Dead-letter channel closed, dropped item not forwarded");
+    }
+}
@@ -0,0 +1,199 @@
+use async_trait::async_trait;
+use fluxus::utils::models::{StreamError, StreamResult};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Persists the position of sources between runs, keyed by source name
+///
+/// Cursors are opaque strings written by the sources: a transaction digest for
+/// `SuiTransactionSource`, `digest:seq` for `SuiEventSource`, and the JSON map of
+/// object versions for `SuiObjectSource`.
+pub trait CursorStore: Send + Sync {
+    /// Returns the cursor saved under `source`, None if nothing was saved
+    fn load(&self, source: &str) -> StreamResult<Option<String>>;
+
+    /// Saves the cursor of `source`, replacing the previous one
+    fn save(&self, source: &str, cursor: &str) -> StreamResult<()>;
+}
+
+/// Cursor store with asynchronous I/O, e.g. backed by object storage or Redis
+///
+/// Suits containers whose disk does not outlive them. Cursors are the same
+/// strings as with `CursorStore`, so names can shard the state per address, e.g.
+/// `objects/<address>`. A `CursorStore` is instead called inline on the runtime,
+/// which is fine for small local files.
+#[async_trait]
+pub trait AsyncCursorStore: Send + Sync {
+    /// Returns the cursor saved under `source`, None if nothing was saved
+    async fn load(&self, source: &str) -> StreamResult<Option<String>>;
+
+    /// Saves the cursor of `source`, replacing the previous one
+    async fn save(&self, source: &str, cursor: &str) -> StreamResult<()>;
+}
+
+/// First line of a cursor file, naming the format version of the rest
+const FILE_HEADER: &str = "fluxus-sui-cursor";
+
+/// Format version written by `FileCursorStore`
+const FILE_VERSION: u32 = 1;
+
+/// Cursor store keeping one file per source name in a directory
+///
+/// Files start with a `fluxus-sui-cursor <version>` line followed by the cursor.
+/// Files without the line, written before the format was versioned, are read as
+/// a bare cursor and rewritten in the current format. Files of a newer version
+/// fail to load instead of being misread.
+#[derive(Clone, Debug)]
+pub struct FileCursorStore {
+    /// Directory holding the cursor files, created on the first save
+    dir: PathBuf,
+}
+
+impl FileCursorStore {
+    /// Creates a store writing cursor files into `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// File holding the cursor of a source, with path separators replaced
+    fn path(&self, source: &str) -> PathBuf {
+        let name: String = source
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        self.dir.join(format!("{}.cursor", name))
+    }
+}
+
+impl CursorStore for FileCursorStore {
+    fn load(&self, source: &str) -> StreamResult<Option<String>> {
+        let path = self.path(source);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(StreamError::Runtime(format!(
+                    "Failed to read cursor {}: {}",
+                    path.display(),
+                    e
+                )));
+            }
+        };
+
+        let Some(versioned) = contents.strip_prefix(FILE_HEADER) else {
+            // Unversioned file of an earlier release, migrated to the current format
+            tracing::info!(
+                "Migrating cursor {} to version {}",
+                path.display(),
+                FILE_VERSION
+            );
+            self.save(source, &contents)?;
+            return Ok(Some(contents));
+        };
+        let (version, cursor) = versioned.split_once('\n').unwrap_or((versioned, ""));
+        match version.trim().parse::<u32>() {
+            Ok(FILE_VERSION) => Ok(Some(cursor.to_string())),
+            Ok(version) => Err(StreamError::Runtime(format!(
+                "Cursor {} has format version {}, this release reads up to {}",
+                path.display(),
+                version,
+                FILE_VERSION
+            ))),
+            Err(_) => Err(StreamError::Runtime(format!(
+                "Cursor {} has an invalid format header",
+                path.display()
+            ))),
+        }
+    }
+
+    fn save(&self, source: &str, cursor: &str) -> StreamResult<()> {
+        let path = self.path(source);
+        let temporary = path.with_extension("tmp");
+        let contents = format!("{} {}\n{}", FILE_HEADER, FILE_VERSION, cursor);
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&temporary, contents))
+            .and_then(|_| fs::rename(&temporary, &path))
+            .map_err(|e| {
+                StreamError::Runtime(format!("Failed to write cursor {}: {}", path.display(), e))
+            })
+    }
+}
+
+/// Synchronous store behind the asynchronous interface the sources call
+struct SyncCursorStore(Arc<dyn CursorStore>);
+
+#[async_trait]
+impl AsyncCursorStore for SyncCursorStore {
+    async fn load(&self, source: &str) -> StreamResult<Option<String>> {
+        self.0.load(source)
+    }
+
+    async fn save(&self, source: &str, cursor: &str) -> StreamResult<()> {
+        self.0.save(source, cursor)
+    }
+}
+
+/// Cursor store of a source and the name its cursor is saved under
+pub(crate) struct CursorSlot {
+    store: Arc<dyn AsyncCursorStore>,
+    name: String,
+    /// Cursor of a poll whose batches are not emitted yet
+    deferred: Option<String>,
+}
+
+impl CursorSlot {
+    pub(crate) fn new(store: Arc<dyn CursorStore>, name: String) -> Self {
+        Self::new_async(Arc::new(SyncCursorStore(store)), name)
+    }
+
+    pub(crate) fn new_async(store: Arc<dyn AsyncCursorStore>, name: String) -> Self {
+        Self {
+            store,
+            name,
+            deferred: None,
+        }
+    }
+
+    pub(crate) async fn load(&self) -> StreamResult<Option<String>> {
+        self.store.load(&self.name).await
+    }
+
+    /// Saves the cursor, logging failures instead of failing the poll
+    pub(crate) async fn save(&self, cursor: &str) -> bool {
+        match self.store.save(&self.name, cursor).await {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("Failed to save cursor of {}: {}", self.name, e);
+                false
+            }
+        }
+    }
+
+    /// Saves the cursor of a poll, or defers it while batches split off it are held back
+    ///
+    /// A restart resumes after the saved cursor, so saving it before the held back
+    /// batches are emitted would skip them.
+    pub(crate) async fn save_after(&mut self, cursor: String, held_back: bool) -> bool {
+        if held_back {
+            self.deferred = Some(cursor);
+            return true;
+        }
+        self.deferred = None;
+        self.save(&cursor).await
+    }
+
+    /// Saves the deferred cursor, once the last held back batch is emitted
+    pub(crate) async fn save_deferred(&mut self) -> bool {
+        match self.deferred.take() {
+            Some(cursor) => self.save(&cursor).await,
+            None => true,
+        }
+    }
+}
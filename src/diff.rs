@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+
+/// Field changed between two versions of an object's content
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// JSON pointer to the field, e.g. `/fields/balance`
+    pub path: String,
+    /// Value in the previous version, None if the field was added
+    pub previous: Option<Value>,
+    /// Value in the new version, None if the field was removed
+    pub current: Option<Value>,
+}
+
+/// Computes the field-level changes between two JSON values
+///
+/// Objects are compared key by key and arrays index by index, so each change
+/// points at the deepest field that differs. Changes are sorted by path.
+pub fn content_diff(previous: &Value, current: &Value) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_into(String::new(), previous, current, &mut changes);
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+fn diff_into(path: String, previous: &Value, current: &Value, changes: &mut Vec<FieldChange>) {
+    match (previous, current) {
+        (Value::Object(previous), Value::Object(current)) => {
+            for (key, previous_value) in previous {
+                let path = format!("{}/{}", path, escape(key));
+                match current.get(key) {
+                    Some(current_value) => diff_into(path, previous_value, current_value, changes),
+                    None => changes.push(FieldChange {
+                        path,
+                        previous: Some(previous_value.clone()),
+                        current: None,
+                    }),
+                }
+            }
+            for (key, current_value) in current {
+                if !previous.contains_key(key) {
+                    changes.push(FieldChange {
+                        path: format!("{}/{}", path, escape(key)),
+                        previous: None,
+                        current: Some(current_value.clone()),
+                    });
+                }
+            }
+        }
+        (Value::Array(previous), Value::Array(current)) => {
+            for index in 0..previous.len().max(current.len()) {
+                let path = format!("{}/{}", path, index);
+                match (previous.get(index), current.get(index)) {
+                    (Some(previous), Some(current)) => diff_into(path, previous, current, changes),
+                    (previous, current) => changes.push(FieldChange {
+                        path,
+                        previous: previous.cloned(),
+                        current: current.cloned(),
+                    }),
+                }
+            }
+        }
+        (previous, current) if previous != current => changes.push(FieldChange {
+            path,
+            previous: Some(previous.clone()),
+            current: Some(current.clone()),
+        }),
+        _ => {}
+    }
+}
+
+/// Escapes a key as a JSON pointer reference token
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Last seen content of objects, evicting the oldest entries beyond a capacity
+pub(crate) struct ContentCache {
+    /// Maximum number of objects kept
+    capacity: usize,
+    /// Object IDs in insertion order
+    order: VecDeque<String>,
+    /// Content by object ID
+    contents: HashMap<String, Value>,
+}
+
+impl ContentCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::new(),
+            contents: HashMap::new(),
+        }
+    }
+
+    /// Stores the content of an object, returning its previous content
+    pub(crate) fn replace(&mut self, object_id: &str, content: Value) -> Option<Value> {
+        let previous = self.contents.insert(object_id.to_string(), content);
+        if previous.is_none() {
+            self.order.push_back(object_id.to_string());
+            while self.order.len() > self.capacity {
+                if let Some(evicted) = self.order.pop_front() {
+                    self.contents.remove(&evicted);
+                }
+            }
+        }
+        previous
+    }
+}
@@ -0,0 +1,229 @@
+use crate::connection::SuiConnection;
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use sui_sdk::rpc_types::{SuiTransactionBlockResponseQuery, TransactionFilter};
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DigestRecord {
+    /// Transaction ID
+    pub digest: String,
+    /// Checkpoint sequence number
+    pub checkpoint: Option<u64>,
+}
+
+/// Sui data source streaming only transaction digests, for two-tier pipelines
+///
+/// Transactions are queried without any response options, so pages are a small
+/// fraction of full transaction pages. Reads are ascending from the head at init,
+/// or from `with_cursor`, and a poll following a full page skips the polling
+/// interval, so the source keeps up with the chain and catches up quickly.
+/// Selected digests can be hydrated later, e.g. with `TxHydrator`.
+pub struct SuiDigestSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Shared connection, a client is built in `init` when None
+    connection: Option<SuiConnection>,
+    /// Transaction filter
+    filter: Option<TransactionFilter>,
+    /// Last transaction read
+    cursor: Option<TransactionDigest>,
+    /// Maximum number of digests to fetch per poll
+    max_transactions: usize,
+    /// Largest page the node serves, checked against `max_transactions` at init
+    node_page_limit: usize,
+    /// Whether the last page was full, so the next poll skips the interval
+    behind: bool,
+}
+
+impl SuiDigestSource {
+    /// Creates a new SuiDigestSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `max_transactions` - Maximum number of digests to fetch per poll
+    pub fn new(rpc_url: String, interval_ms: u64, max_transactions: usize) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            connection: None,
+            filter: None,
+            cursor: None,
+            max_transactions,
+            node_page_limit: rpc::QUERY_MAX_RESULT_LIMIT,
+            behind: false,
+        }
+    }
+
+    /// Creates a new SuiDigestSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64, max_transactions: usize) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms, max_transactions)
+    }
+
+    /// Starts reading after the given transaction instead of at the head
+    pub fn with_cursor(mut self, cursor: TransactionDigest) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Streams only the digests of transactions matching the filter
+    pub fn with_filter(mut self, filter: TransactionFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Reuses a shared connection instead of building a client in `init`
+    ///
+    /// The connection's endpoint takes precedence over the RPC URL of the source.
+    pub fn with_connection(mut self, connection: SuiConnection) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Returns the last transaction read, to resume from after a restart
+    pub fn cursor(&self) -> Option<TransactionDigest> {
+        self.cursor
+    }
+
+    /// Sets the largest page the node serves, 50 by default as on standard fullnodes
+    pub fn with_node_page_limit(mut self, limit: usize) -> Self {
+        self.node_page_limit = limit;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+}
+
+#[async_trait]
+impl Source<Vec<DigestRecord>> for SuiDigestSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        let client = match &self.connection {
+            Some(connection) => connection.client().clone(),
+            None => {
+                rpc::call(
+                    "SuiDigestSource",
+                    "rpc.discover",
+                    "initialize Sui client",
+                    SuiClientBuilder::default().build(self.rpc_url.as_str()),
+                )
+                .await?
+            }
+        };
+        self.max_transactions = rpc::check_page_size(
+            "SuiDigestSource",
+            "max_transactions",
+            self.max_transactions,
+            self.node_page_limit,
+        )?;
+
+        // Start at the newest matching transaction rather than at genesis
+        if self.cursor.is_none() {
+            let newest = rpc::call(
+                "SuiDigestSource",
+                "suix_queryTransactionBlocks",
+                "fetch newest transaction",
+                client.read_api().query_transaction_blocks(
+                    SuiTransactionBlockResponseQuery::new(self.filter.clone(), None),
+                    None,
+                    Some(1),
+                    true,
+                ),
+            )
+            .await?;
+            self.cursor = newest.data.first().map(|transaction| transaction.digest);
+        }
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiDigestSource initialized with RPC URL: {}",
+            self.connection
+                .as_ref()
+                .map_or(self.rpc_url.as_str(), |connection| connection.rpc_url())
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<DigestRecord>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiDigestSource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval, skipped while catching up
+        if !self.behind {
+            sleep(self.interval).await;
+        }
+
+        if let Some(connection) = &self.connection {
+            connection.touch();
+        }
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiDigestSource client not available".to_string())
+        })?;
+
+        let page = rpc::call(
+            "SuiDigestSource",
+            "suix_queryTransactionBlocks",
+            "fetch transaction digests",
+            client.read_api().query_transaction_blocks(
+                SuiTransactionBlockResponseQuery::new(self.filter.clone(), None),
+                self.cursor,
+                Some(self.max_transactions),
+                false,
+            ),
+        )
+        .await?;
+
+        self.behind = page.has_next_page;
+        if let Some(cursor) = page.next_cursor {
+            self.cursor = Some(cursor);
+        }
+
+        // Return None if no new transactions
+        if page.data.is_empty() {
+            return Ok(None);
+        }
+
+        let digests = page
+            .data
+            .into_iter()
+            .map(|transaction| DigestRecord {
+                digest: transaction.digest.to_string(),
+                checkpoint: transaction.checkpoint,
+            })
+            .collect();
+        Ok(Some(Record::new(digests)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiDigestSource closed");
+        Ok(())
+    }
+}
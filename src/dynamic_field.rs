@@ -0,0 +1,323 @@
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::time::Duration;
+use sui_sdk::rpc_types::{DynamicFieldInfo, SuiObjectDataOptions};
+use sui_sdk::types::base_types::ObjectID;
+use sui_sdk::types::dynamic_field::DynamicFieldType;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+/// How a dynamic field changed since the previous poll
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DynamicFieldChangeKind {
+    /// The field was not listed before
+    Added,
+    /// The field's object moved to a new version
+    Changed,
+    /// The field is no longer listed
+    Removed,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DynamicFieldEntry {
+    /// Parent object ID
+    pub parent_id: String,
+    /// Change observed by the poll
+    pub kind: DynamicFieldChangeKind,
+    /// Type of the field name, e.g. `address` or `0x1::string::String`
+    pub name_type: String,
+    /// Field name as JSON
+    pub name: serde_json::Value,
+    /// Whether the field is a dynamic object field, whose value is a standalone object
+    pub object_field: bool,
+    /// Type of the field value
+    pub value_type: String,
+    /// ID of the object holding the field
+    pub object_id: String,
+    /// Version of the object holding the field, the last version seen when removed
+    pub version: u64,
+    /// Field value as JSON, None when removed, values are disabled, or the object is unreadable
+    pub value: Option<serde_json::Value>,
+}
+
+/// Sui blockchain data source emitting the dynamic field changes of a parent object
+///
+/// Every poll pages through `suix_getDynamicFields` of the parent and emits the
+/// fields added or moved to a new version since the previous poll, so the first
+/// poll emits every field as added. Removals are reported only when a poll lists
+/// every field, i.e. when they fit within `max_fields`. Values of added and
+/// changed fields are fetched in one multi-get per page of the node's query limit.
+pub struct SuiDynamicFieldSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Parent object whose fields are tracked
+    parent_id: String,
+    /// Parent object ID parsed at init
+    parent: Option<ObjectID>,
+    /// Fields fetched per page
+    page_size: usize,
+    /// Maximum number of fields scanned per poll
+    max_fields: usize,
+    /// Whether field values are fetched
+    fetch_values: bool,
+    /// Last emitted entry by the ID of the object holding the field, without its value
+    fields: HashMap<String, DynamicFieldEntry>,
+}
+
+impl SuiDynamicFieldSource {
+    /// Creates a new SuiDynamicFieldSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `parent_id` - Object whose dynamic fields are tracked, e.g. a `Table` or `Bag`
+    pub fn new(rpc_url: String, interval_ms: u64, parent_id: String) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            parent_id,
+            parent: None,
+            page_size: rpc::QUERY_MAX_RESULT_LIMIT,
+            max_fields: 1000,
+            fetch_values: true,
+            fields: HashMap::new(),
+        }
+    }
+
+    /// Creates a new SuiDynamicFieldSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64, parent_id: String) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms, parent_id)
+    }
+
+    /// Sets the number of fields fetched per page
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Sets the maximum number of fields scanned per poll
+    pub fn with_max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = max_fields;
+        self
+    }
+
+    /// Sets whether field values are fetched, true by default
+    pub fn with_values(mut self, fetch_values: bool) -> Self {
+        self.fetch_values = fetch_values;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Fetches the values of fields, by the ID of the object holding them
+    async fn fetch_values(
+        client: &SuiClient,
+        fields: &[&DynamicFieldInfo],
+    ) -> StreamResult<HashMap<String, serde_json::Value>> {
+        let mut values = HashMap::new();
+        for page in fields.chunks(rpc::QUERY_MAX_RESULT_LIMIT) {
+            let responses = rpc::call(
+                "SuiDynamicFieldSource",
+                "sui_multiGetObjects",
+                "fetch dynamic field values",
+                client.read_api().multi_get_object_with_options(
+                    page.iter().map(|field| field.object_id).collect(),
+                    SuiObjectDataOptions::new().with_content(),
+                ),
+            )
+            .await?;
+            for (field, response) in page.iter().zip(responses) {
+                let Some(content) = response.data.and_then(|data| data.content) else {
+                    continue;
+                };
+                let Ok(content) = serde_json::to_value(&content) else {
+                    continue;
+                };
+                // A dynamic field's object wraps the value, a dynamic object field's is the value
+                let value = match field.type_ {
+                    DynamicFieldType::DynamicField => content["fields"]["value"].clone(),
+                    DynamicFieldType::DynamicObject => content["fields"].clone(),
+                };
+                values.insert(field.object_id.to_string(), value);
+            }
+        }
+        Ok(values)
+    }
+}
+
+#[async_trait]
+impl Source<Vec<DynamicFieldEntry>> for SuiDynamicFieldSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        self.parent = Some(ObjectID::from_str(&self.parent_id).map_err(|e| {
+            tracing::error!("Invalid parent object ID: {}", e);
+            StreamError::Runtime(format!(
+                "Invalid parent object ID {}: {}",
+                self.parent_id, e
+            ))
+        })?);
+
+        // Initialize Sui client
+        let client = rpc::call(
+            "SuiDynamicFieldSource",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(self.rpc_url.as_str()),
+        )
+        .await?;
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiDynamicFieldSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<DynamicFieldEntry>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiDynamicFieldSource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiDynamicFieldSource client not available".to_string())
+        })?;
+        let parent = self.parent.ok_or_else(|| {
+            StreamError::Runtime("SuiDynamicFieldSource parent not available".to_string())
+        })?;
+
+        // Paginate fields until exhausted or the scan budget is spent
+        let mut listed = Vec::new();
+        let mut cursor: Option<ObjectID> = None;
+        let mut exhausted = false;
+        while listed.len() < self.max_fields {
+            let page = rpc::call(
+                "SuiDynamicFieldSource",
+                "suix_getDynamicFields",
+                &format!("fetch dynamic fields of {}", self.parent_id),
+                client.read_api().get_dynamic_fields(
+                    parent,
+                    cursor,
+                    Some(self.page_size.min(self.max_fields - listed.len())),
+                ),
+            )
+            .await?;
+            listed.extend(page.data);
+
+            if !page.has_next_page || page.next_cursor.is_none() {
+                exhausted = true;
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        // Keep fields that are new or changed version since they were last emitted
+        let moved: Vec<&DynamicFieldInfo> = listed
+            .iter()
+            .filter(|field| {
+                self.fields
+                    .get(&field.object_id.to_string())
+                    .is_none_or(|entry| entry.version != field.version.value())
+            })
+            .collect();
+        let values = if self.fetch_values && !moved.is_empty() {
+            Self::fetch_values(client, &moved).await?
+        } else {
+            HashMap::new()
+        };
+
+        let mut entries = Vec::new();
+        for field in moved {
+            let object_id = field.object_id.to_string();
+            let entry = DynamicFieldEntry {
+                parent_id: self.parent_id.clone(),
+                kind: if self.fields.contains_key(&object_id) {
+                    DynamicFieldChangeKind::Changed
+                } else {
+                    DynamicFieldChangeKind::Added
+                },
+                name_type: field.name.type_.to_canonical_string(true),
+                name: field.name.value.clone(),
+                object_field: field.type_ == DynamicFieldType::DynamicObject,
+                value_type: field.object_type.clone(),
+                object_id: object_id.clone(),
+                version: field.version.value(),
+                value: None,
+            };
+            self.fields.insert(object_id.clone(), entry.clone());
+            entries.push(DynamicFieldEntry {
+                value: values.get(&object_id).cloned(),
+                ..entry
+            });
+        }
+
+        // Report fields no longer listed, once every field was scanned
+        if exhausted {
+            let current: HashSet<String> = listed
+                .iter()
+                .map(|field| field.object_id.to_string())
+                .collect();
+            let mut removed: Vec<String> = self
+                .fields
+                .keys()
+                .filter(|object_id| !current.contains(*object_id))
+                .cloned()
+                .collect();
+            removed.sort();
+            for object_id in removed {
+                if let Some(entry) = self.fields.remove(&object_id) {
+                    entries.push(DynamicFieldEntry {
+                        kind: DynamicFieldChangeKind::Removed,
+                        ..entry
+                    });
+                }
+            }
+        }
+
+        // Return None if no field changed
+        if entries.is_empty() {
+            tracing::info!("No dynamic field changes found");
+            return Ok(None);
+        }
+        tracing::debug!(
+            "Found {} dynamic field changes of {}",
+            entries.len(),
+            self.parent_id
+        );
+
+        Ok(Some(Record::new(entries)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiDynamicFieldSource closed");
+        Ok(())
+    }
+}
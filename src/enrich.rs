@@ -0,0 +1,196 @@
+use crate::cache::TtlCache;
+use crate::event::ChainEvent;
+use crate::object::ChainObject;
+use crate::transaction::SuiEvent;
+use async_trait::async_trait;
+use fluxus::utils::models::StreamResult;
+use futures::StreamExt;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+use tokio::time::{Instant, timeout_at};
+
+/// Default maximum number of lookups of one enricher in flight at once
+const DEFAULT_CONCURRENT_LOOKUPS: usize = 8;
+
+/// Lookup attaching external data to records before emission
+///
+/// Each record contributes an optional key, e.g. its sender address, and the value
+/// looked up for it is stored in the record's `enrichments` under the enricher's
+/// name. Lookups are cached per key for `cache_ttl`, so address labels, prices,
+/// Display metadata or SuiNS names are fetched once per key rather than per record.
+/// The keys of a poll are looked up concurrently, up to the source's
+/// `with_enrichment_concurrency` limit and within its `with_enrichment_deadline`.
+///
+/// ```rust,no_run
+/// use async_trait::async_trait;
+/// use fluxus::utils::models::StreamResult;
+/// use fluxus_source_sui::{ChainEvent, Enricher, SuiEventSource};
+///
+/// struct SenderLabels;
+///
+/// #[async_trait]
+/// impl Enricher<ChainEvent> for SenderLabels {
+///     fn name(&self) -> &str {
+///         "sender_label"
+///     }
+///
+///     fn key(&self, event: &ChainEvent) -> Option<String> {
+///         Some(event.sender.clone())
+///     }
+///
+///     async fn lookup(&self, sender: &str) -> StreamResult<Option<serde_json::Value>> {
+///         Ok((sender == "0x0").then(|| "system".into()))
+///     }
+/// }
+///
+/// let source = SuiEventSource::new_with_mainnet(1000, 50).with_enricher(SenderLabels);
+/// ```
+#[async_trait]
+pub trait Enricher<T>: Send + Sync {
+    /// Name the looked up values are stored under
+    fn name(&self) -> &str;
+
+    /// Key looked up for a record, None to leave the record unchanged
+    ///
+    /// Enrichers run in the order they were added, so keys may depend on values
+    /// attached by earlier enrichers.
+    fn key(&self, item: &T) -> Option<String>;
+
+    /// Looks up the value of a key, None if there is nothing to attach
+    async fn lookup(&self, key: &str) -> StreamResult<Option<Value>>;
+
+    /// Time looked up values stay cached
+    fn cache_ttl(&self) -> Duration {
+        Duration::from_secs(300)
+    }
+}
+
+/// Records carrying enrichment values
+pub(crate) trait Enrichable {
+    fn enrichments_mut(&mut self) -> &mut BTreeMap<String, Value>;
+}
+
+impl Enrichable for ChainEvent {
+    fn enrichments_mut(&mut self) -> &mut BTreeMap<String, Value> {
+        &mut self.enrichments
+    }
+}
+
+impl Enrichable for SuiEvent {
+    fn enrichments_mut(&mut self) -> &mut BTreeMap<String, Value> {
+        &mut self.enrichments
+    }
+}
+
+impl Enrichable for ChainObject {
+    fn enrichments_mut(&mut self) -> &mut BTreeMap<String, Value> {
+        &mut self.enrichments
+    }
+}
+
+/// Enricher and the values it looked up recently
+struct Stage<T> {
+    enricher: Box<dyn Enricher<T>>,
+    cache: TtlCache<String, Option<Value>>,
+}
+
+/// Ordered enrichers of a source
+pub(crate) struct EnrichmentChain<T> {
+    stages: Vec<Stage<T>>,
+    /// Maximum number of lookups of one enricher in flight at once
+    concurrency: usize,
+    /// Time the lookups of one poll may take, unbounded when None
+    deadline: Option<Duration>,
+}
+
+impl<T> Default for EnrichmentChain<T> {
+    fn default() -> Self {
+        Self {
+            stages: Vec::new(),
+            concurrency: DEFAULT_CONCURRENT_LOOKUPS,
+            deadline: None,
+        }
+    }
+}
+
+impl<T: Enrichable> EnrichmentChain<T> {
+    pub(crate) fn push(&mut self, enricher: impl Enricher<T> + 'static) {
+        self.stages.push(Stage {
+            enricher: Box::new(enricher),
+            cache: TtlCache::new(),
+        });
+    }
+
+    pub(crate) fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency.max(1);
+    }
+
+    pub(crate) fn set_deadline(&mut self, deadline: Duration) {
+        self.deadline = Some(deadline);
+    }
+
+    /// Runs every enricher over the records, returning the number of failed lookups
+    ///
+    /// Failed lookups are logged and leave the records unchanged rather than
+    /// failing the poll. Lookups still in flight at the deadline are cancelled and
+    /// counted as failed, and later enrichers only attach cached values.
+    pub(crate) async fn apply(&mut self, items: &mut [T]) -> usize {
+        let deadline = self.deadline.map(|deadline| Instant::now() + deadline);
+        let mut failures = 0;
+        for stage in &mut self.stages {
+            let enricher = stage.enricher.as_ref();
+            let ttl = enricher.cache_ttl();
+            let keys: Vec<Option<String>> = items.iter().map(|item| enricher.key(item)).collect();
+
+            // Look up the keys missing from the cache, a bounded number at a time
+            let missing: HashSet<&String> = keys
+                .iter()
+                .flatten()
+                .filter(|key| stage.cache.get(key, ttl).is_none())
+                .collect();
+            let mut pending = missing.len();
+            let mut lookups = futures::stream::iter(missing)
+                .map(|key| async move { (key.clone(), enricher.lookup(key).await) })
+                .buffer_unordered(self.concurrency);
+            while pending > 0 && deadline.is_none_or(|deadline| Instant::now() < deadline) {
+                let next = match deadline {
+                    Some(deadline) => match timeout_at(deadline, lookups.next()).await {
+                        Ok(next) => next,
+                        Err(_) => break,
+                    },
+                    None => lookups.next().await,
+                };
+                let Some((key, result)) = next else {
+                    break;
+                };
+                pending -= 1;
+                match result {
+                    Ok(value) => stage.cache.insert(key, value, ttl),
+                    Err(e) => {
+                        failures += 1;
+                        tracing::warn!("Enricher {} failed for {}: {}", enricher.name(), key, e);
+                    }
+                }
+            }
+            if pending > 0 {
+                failures += pending;
+                tracing::warn!(
+                    "Enricher {} passed the deadline with {} lookups pending",
+                    enricher.name(),
+                    pending
+                );
+            }
+
+            for (item, key) in items.iter_mut().zip(&keys) {
+                if let Some(key) = key
+                    && let Some(Some(value)) = stage.cache.get(key, ttl)
+                {
+                    item.enrichments_mut()
+                        .insert(enricher.name().to_string(), value);
+                }
+            }
+        }
+        failures
+    }
+}
@@ -0,0 +1,372 @@
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use sui_sdk::rpc_types::{Checkpoint, CheckpointId};
+use sui_sdk::types::sui_system_state::sui_system_state_summary::SuiSystemStateSummary;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorInfo {
+    /// Validator address
+    pub address: String,
+    /// Validator name
+    pub name: String,
+    /// SUI staked with the validator's pool (MIST)
+    pub stake: u64,
+    /// Voting power, out of 10,000
+    pub voting_power: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StakeChange {
+    /// Validator address
+    pub address: String,
+    /// Validator name
+    pub name: String,
+    /// Stake at the end of the previous epoch seen (MIST)
+    pub old_stake: u64,
+    /// Stake at the start of the new epoch (MIST)
+    pub new_stake: u64,
+    /// Signed change from the old to the new stake
+    pub delta: i128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitteeMember {
+    /// Authority protocol public key
+    pub authority: String,
+    /// Voting power, out of 10,000
+    pub voting_power: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochRecord {
+    /// New epoch
+    pub epoch: u64,
+    /// Epoch start timestamp (milliseconds)
+    pub start_timestamp: u64,
+    /// Reference gas price of the new epoch (MIST)
+    pub reference_gas_price: u64,
+    /// Protocol version of the new epoch
+    pub protocol_version: u64,
+    /// Last checkpoint of the previous epoch, None if it could not be located
+    pub last_checkpoint: Option<u64>,
+    /// Committee of the new epoch, from the end-of-epoch data of `last_checkpoint`
+    pub next_epoch_committee: Vec<CommitteeMember>,
+    /// Protocol version of the new epoch, from the end-of-epoch data of `last_checkpoint`
+    pub next_epoch_protocol_version: Option<u64>,
+    /// Validators active in the new epoch but not the previous one seen
+    pub validators_joined: Vec<ValidatorInfo>,
+    /// Validators active in the previous epoch seen but not the new one
+    pub validators_left: Vec<ValidatorInfo>,
+    /// Stake changes of the validators active in both epochs
+    pub stake_changes: Vec<StakeChange>,
+}
+
+/// Epoch and validator set observed by the last poll
+struct EpochState {
+    /// Epoch number
+    epoch: u64,
+    /// Active validators by address
+    validators: HashMap<String, ValidatorInfo>,
+    /// Newest checkpoint known to belong to this epoch or an earlier one
+    checkpoint: u64,
+}
+
+/// Sui blockchain data source emitting a record at each epoch boundary
+///
+/// Polls `suix_getLatestSuiSystemState` and emits a record once the epoch moves,
+/// with the validator set diffed against the previous epoch seen. Polls within an
+/// epoch yield None. When the source was down across several boundaries, a single
+/// record reports the newest epoch against the last one seen.
+///
+/// Each record also carries the committee and protocol version announced by the
+/// end-of-epoch data of the checkpoint closing the previous epoch.
+pub struct SuiEpochSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// State of the last poll, set at init
+    state: Option<EpochState>,
+}
+
+impl SuiEpochSource {
+    /// Creates a new SuiEpochSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            state: None,
+        }
+    }
+
+    /// Creates a new SuiEpochSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms)
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Returns the epoch observed by the last poll, None before init
+    pub fn current_epoch(&self) -> Option<u64> {
+        self.state.as_ref().map(|state| state.epoch)
+    }
+
+    /// Reads the newest checkpoint, then the system state
+    ///
+    /// The checkpoint is read first so it belongs to the returned epoch or an
+    /// earlier one, as required by `EpochState::checkpoint`.
+    async fn observe(client: &SuiClient) -> StreamResult<(u64, SuiSystemStateSummary)> {
+        let checkpoint = latest_checkpoint(client).await?;
+        let summary = rpc::call(
+            "SuiEpochSource",
+            "suix_getLatestSuiSystemState",
+            "fetch system state",
+            client.governance_api().get_latest_sui_system_state(),
+        )
+        .await?;
+        Ok((checkpoint, summary))
+    }
+}
+
+async fn latest_checkpoint(client: &SuiClient) -> StreamResult<u64> {
+    rpc::call(
+        "SuiEpochSource",
+        "sui_getLatestCheckpointSequenceNumber",
+        "fetch latest checkpoint",
+        client.read_api().get_latest_checkpoint_sequence_number(),
+    )
+    .await
+}
+
+/// Active validators of a system state by address
+fn validators(summary: &SuiSystemStateSummary) -> HashMap<String, ValidatorInfo> {
+    summary
+        .active_validators
+        .iter()
+        .map(|validator| {
+            let address = validator.sui_address.to_string();
+            (
+                address.clone(),
+                ValidatorInfo {
+                    address,
+                    name: validator.name.clone(),
+                    stake: validator.staking_pool_sui_balance,
+                    voting_power: validator.voting_power,
+                },
+            )
+        })
+        .collect()
+}
+
+async fn checkpoint(client: &SuiClient, sequence_number: u64) -> StreamResult<Checkpoint> {
+    rpc::call(
+        "SuiEpochSource",
+        "sui_getCheckpoint",
+        &format!("fetch checkpoint {}", sequence_number),
+        client
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(sequence_number)),
+    )
+    .await
+}
+
+/// Finds the last checkpoint before `epoch`, searching between a checkpoint known
+/// to precede it and one known to belong to it or a later epoch
+async fn last_checkpoint_before(
+    client: &SuiClient,
+    epoch: u64,
+    mut before: u64,
+    mut after: u64,
+) -> StreamResult<Checkpoint> {
+    while after > before + 1 {
+        let middle = before + (after - before) / 2;
+        if checkpoint(client, middle).await?.epoch < epoch {
+            before = middle;
+        } else {
+            after = middle;
+        }
+    }
+    checkpoint(client, before).await
+}
+
+#[async_trait]
+impl Source<Vec<EpochRecord>> for SuiEpochSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client
+        let client = rpc::call(
+            "SuiEpochSource",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(self.rpc_url.as_str()),
+        )
+        .await?;
+
+        // Record the current epoch, the first record is its successor
+        let (checkpoint, summary) = Self::observe(&client).await?;
+        self.state = Some(EpochState {
+            epoch: summary.epoch,
+            validators: validators(&summary),
+            checkpoint,
+        });
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiEpochSource initialized with RPC URL: {} at epoch {}",
+            self.rpc_url,
+            summary.epoch
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<EpochRecord>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiEpochSource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiEpochSource client not available".to_string())
+        })?;
+        let state = self.state.as_mut().ok_or_else(|| {
+            StreamError::Runtime("SuiEpochSource state not available".to_string())
+        })?;
+
+        let (checkpoint, summary) = Self::observe(client).await?;
+
+        // Return None within the same epoch
+        if summary.epoch <= state.epoch {
+            state.checkpoint = state.checkpoint.max(checkpoint);
+            tracing::info!("Epoch {} continues", state.epoch);
+            return Ok(None);
+        }
+
+        // Locate the checkpoint closing the previous epoch, which holds its end-of-epoch data
+        let closing = match latest_checkpoint(client).await {
+            Ok(head) => last_checkpoint_before(client, summary.epoch, state.checkpoint, head)
+                .await
+                .inspect_err(|e| tracing::warn!("Failed to locate end of epoch: {}", e))
+                .ok(),
+            Err(e) => {
+                tracing::warn!("Failed to locate end of epoch: {}", e);
+                None
+            }
+        };
+        let last_checkpoint = closing
+            .as_ref()
+            .map(|checkpoint| checkpoint.sequence_number);
+        let end_of_epoch = closing.and_then(|checkpoint| checkpoint.end_of_epoch_data);
+        if last_checkpoint.is_some() && end_of_epoch.is_none() {
+            tracing::warn!(
+                "Checkpoint closing epoch {} has no end-of-epoch data",
+                state.epoch
+            );
+        }
+        let next_epoch_committee = end_of_epoch
+            .as_ref()
+            .map(|data| {
+                data.next_epoch_committee
+                    .iter()
+                    .map(|(authority, voting_power)| CommitteeMember {
+                        authority: authority.to_string(),
+                        voting_power: *voting_power,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Diff the validator sets
+        let current = validators(&summary);
+        let mut validators_joined: Vec<ValidatorInfo> = current
+            .values()
+            .filter(|validator| !state.validators.contains_key(&validator.address))
+            .cloned()
+            .collect();
+        let mut validators_left: Vec<ValidatorInfo> = state
+            .validators
+            .values()
+            .filter(|validator| !current.contains_key(&validator.address))
+            .cloned()
+            .collect();
+        let mut stake_changes: Vec<StakeChange> = current
+            .values()
+            .filter_map(|validator| {
+                let previous = state.validators.get(&validator.address)?;
+                (previous.stake != validator.stake).then(|| StakeChange {
+                    address: validator.address.clone(),
+                    name: validator.name.clone(),
+                    old_stake: previous.stake,
+                    new_stake: validator.stake,
+                    delta: validator.stake as i128 - previous.stake as i128,
+                })
+            })
+            .collect();
+        validators_joined.sort_by(|a, b| a.address.cmp(&b.address));
+        validators_left.sort_by(|a, b| a.address.cmp(&b.address));
+        stake_changes.sort_by(|a, b| a.address.cmp(&b.address));
+
+        let record = EpochRecord {
+            epoch: summary.epoch,
+            start_timestamp: summary.epoch_start_timestamp_ms,
+            reference_gas_price: summary.reference_gas_price,
+            protocol_version: summary.protocol_version,
+            last_checkpoint,
+            next_epoch_committee,
+            next_epoch_protocol_version: end_of_epoch
+                .map(|data| data.next_epoch_protocol_version.as_u64()),
+            validators_joined,
+            validators_left,
+            stake_changes,
+        };
+        tracing::debug!(
+            "Epoch {} started, validators joined: {} left: {}",
+            record.epoch,
+            record.validators_joined.len(),
+            record.validators_left.len()
+        );
+
+        *state = EpochState {
+            epoch: summary.epoch,
+            validators: current,
+            checkpoint: last_checkpoint.unwrap_or(state.checkpoint),
+        };
+
+        Ok(Some(Record::new(vec![record])))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiEpochSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use fluxus::utils::models::StreamError;
+use std::fmt;
+use sui_sdk::types::event::EventID;
+
+/// Typed failure of a Sui source, surfaced through `last_error()`
+///
+/// `Source::next` can only return a `StreamError`, so sources convert these into
+/// `StreamError::Runtime` and keep the typed value for callers that need to act on it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SuiSourceError {
+    /// The cursor points at history the node has already pruned
+    HistoryPruned {
+        /// Oldest event still retained for the query, None if nothing matches
+        oldest_available: Option<EventID>,
+    },
+    /// The node reports another chain than the one the cursor was recorded on
+    NetworkReset {
+        /// Chain identifier the source expected
+        expected: String,
+        /// Chain identifier the node reports
+        actual: String,
+    },
+    /// The node does not serve an RPC method the source depends on
+    MethodUnavailable {
+        /// RPC method name, e.g. `suix_queryEvents`
+        method: String,
+    },
+}
+
+impl fmt::Display for SuiSourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SuiSourceError::HistoryPruned {
+                oldest_available: Some(id),
+            } => write!(
+                f,
+                "History pruned, oldest available event is {}:{}",
+                id.tx_digest, id.event_seq
+            ),
+            SuiSourceError::HistoryPruned {
+                oldest_available: None,
+            } => write!(f, "History pruned, no events available"),
+            SuiSourceError::NetworkReset { expected, actual } => write!(
+                f,
+                "Network reset, chain identifier changed from {} to {}",
+                expected, actual
+            ),
+            SuiSourceError::MethodUnavailable { method } => {
+                write!(f, "Method unavailable, the node does not serve {}", method)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SuiSourceError {}
+
+impl From<SuiSourceError> for StreamError {
+    fn from(error: SuiSourceError) -> Self {
+        StreamError::Runtime(error.to_string())
+    }
+}
+
+/// Phrases a node reports when a cursor points at history it no longer retains
+const PRUNED_PHRASES: &[&str] = &[
+    "pruned",
+    "could not find the referenced transaction",
+    "could not find the referenced checkpoint",
+    "could not find the referenced event",
+];
+
+/// Returns the node's part of an error message, without the `rpc::call` prefix
+///
+/// The prefix names the action, e.g. `Failed to fetch events [SuiEventSource-7]: `,
+/// so keywords in it must not be taken for the node's answer.
+fn node_message(message: &str) -> &str {
+    match message.strip_prefix("Failed to ") {
+        Some(rest) => rest.split_once("]: ").map_or(message, |(_, body)| body),
+        None => message,
+    }
+}
+
+/// Returns whether an RPC error message reports a cursor missing from pruned history
+///
+/// Only the node's pruning phrases count, so missing methods, objects and other
+/// not-found answers are reported as they are.
+pub(crate) fn is_history_pruned(message: &str) -> bool {
+    if is_method_unavailable(message) {
+        return false;
+    }
+    let body = node_message(message).to_lowercase();
+    PRUNED_PHRASES.iter().any(|phrase| body.contains(phrase))
+}
+
+/// Returns whether an RPC error message rejects descending order for the query's filter
+pub(crate) fn is_descending_unsupported(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("descending")
+        && (message.contains("not supported") || message.contains("unsupported"))
+}
+
+/// Returns whether an RPC error message rejects the combined from-or-to address filter
+pub(crate) fn is_address_filter_unsupported(message: &str) -> bool {
+    let message = message.to_lowercase();
+    (message.contains("fromortoaddress") || message.contains("filter"))
+        && (message.contains("not supported") || message.contains("unsupported"))
+}
+
+/// Returns whether an RPC error message reports a response exceeding the node's size limit
+pub(crate) fn is_response_too_large(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("too big")
+        || message.contains("too large")
+        || message.contains("exceeded max limit")
+}
+
+/// Returns whether an RPC error message reports the method as missing or disabled
+pub(crate) fn is_method_unavailable(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("method not found")
+        || message.contains("-32601")
+        || (message.contains("method")
+            && (message.contains("disabled") || message.contains("not available")))
+}
+
+/// Returns whether an RPC error message reports event subscriptions as unavailable
+pub(crate) fn is_subscription_unsupported(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("method not found")
+        || message.contains("subscription only supported")
+        || (message.contains("subscri") && message.contains("not supported"))
+}
+
+/// Returns whether an RPC error message reports a failure worth retrying
+///
+/// Covers rate limiting, server errors behind the node's proxy, and timeouts.
+/// Status codes are matched as reported by the HTTP transport, since messages
+/// also carry digests and correlation IDs that may contain any digits.
+pub(crate) fn is_transient(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("rejected `429`")
+        || message.contains("rejected `5")
+        || message.contains("too many requests")
+        || message.contains("rate limit")
+        || message.contains("service unavailable")
+        || message.contains("bad gateway")
+        || message.contains("timed out")
+        || message.contains("timeout")
+        || message.contains("connection reset")
+}
@@ -1,9 +1,44 @@
+use crate::batch::BatchSplitter;
+use crate::chain::{ChainMetadata, network_name};
+use crate::connection::SuiConnection;
+use crate::control::{ControlEvent, ControlEvents, ControlQueue, ControlledSource};
+use crate::conversion::{ConversionError, DeadLetter, DeadLetterSender, route_dead_letter};
+use crate::cursor::{AsyncCursorStore, CursorSlot, CursorStore};
+use crate::enrich::{Enricher, EnrichmentChain};
+use crate::error::{
+    SuiSourceError, is_descending_unsupported, is_history_pruned, is_method_unavailable,
+    is_response_too_large, is_transient,
+};
+use crate::health::{HealthMonitor, NodeHealth};
+use crate::intern::{intern, intern_id};
+use crate::item_result::{ItemErrorQueue, ItemErrors, ItemResultSource, SourceItemError};
+use crate::jitter::StartDelay;
+use crate::move_type::{MoveModulePath, MoveTypeTag};
+use crate::order::EmitOrder;
+use crate::pause::PauseHandle;
+use crate::policy::{ErrorPolicy, Recovery, RetryConfig};
+use crate::pool::EndpointPool;
+use crate::profile::{Phase, PhaseTimer};
+use crate::redaction::{Redact, RedactionConfig};
+use crate::registry::{DecodedEventSource, MoveEventRegistry};
+use crate::rpc;
+use crate::scheduler::{Priority, SuiScheduler};
+use crate::stats::{SourceStats, StatsRecorder};
+use crate::subscription::EventSubscription;
+use crate::typed::TypedEventSource;
 use async_trait::async_trait;
 use fluxus::sources::Source;
 use fluxus::utils::models::{Record, StreamError, StreamResult};
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
-use sui_sdk::rpc_types::EventFilter;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sui_sdk::rpc_types::{
+    EventFilter, EventPage, Filter, SuiEvent, SuiTransactionBlockResponse,
+    SuiTransactionBlockResponseOptions, SuiTransactionBlockResponseQuery, TransactionFilter,
+};
+use sui_sdk::types::digests::TransactionDigest;
 use sui_sdk::types::event::EventID;
 use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
 use tokio::time::sleep;
@@ -12,18 +47,109 @@ use tokio::time::sleep;
 pub struct ChainEvent {
     /// Event ID
     pub id: EventID,
-    /// Package ID
-    pub package_id: String,
-    /// Module name
-    pub module_name: String,
+    /// Module of the function that emitted the event
+    pub module: MoveModulePath,
     /// Event type
-    pub event_type: String,
+    pub event_type: MoveTypeTag,
     /// Sender address
     pub sender: String,
     /// Event data
     pub data: String,
+    /// Event data as parsed JSON
+    pub parsed_json: serde_json::Value,
     /// Timestamp
     pub timestamp: u64,
+    /// Network the event was read from
+    pub chain: Option<ChainMetadata>,
+    /// Values attached by the source's enrichers, by enricher name
+    pub enrichments: BTreeMap<String, serde_json::Value>,
+}
+
+impl TryFrom<&SuiEvent> for ChainEvent {
+    type Error = ConversionError;
+
+    fn try_from(event: &SuiEvent) -> Result<Self, Self::Error> {
+        let timestamp = event
+            .timestamp_ms
+            .ok_or_else(|| ConversionError::MissingField("timestamp_ms".to_string()))?;
+
+        Ok(ChainEvent {
+            id: event.id,
+            module: MoveModulePath {
+                package: intern_id(event.package_id),
+                module: intern(event.transaction_module.as_str()),
+            },
+            event_type: event.type_.to_string().parse()?,
+            sender: event.sender.to_string(),
+            data: format!("{:?}", event.parsed_json),
+            parsed_json: event.parsed_json.clone(),
+            timestamp,
+            chain: None,
+            enrichments: BTreeMap::new(),
+        })
+    }
+}
+
+/// Converts the events embedded in a transaction response, ordered by sequence number
+///
+/// Embedded events without their own timestamp inherit the transaction's.
+pub(crate) fn embedded_events(transaction: &SuiTransactionBlockResponse) -> Vec<ChainEvent> {
+    let mut events: Vec<ChainEvent> = transaction
+        .events
+        .iter()
+        .flat_map(|events| &events.data)
+        .filter_map(|event| {
+            let mut event = event.clone();
+            event.timestamp_ms = event.timestamp_ms.or(transaction.timestamp_ms);
+            ChainEvent::try_from(&event).ok()
+        })
+        .collect();
+    events.sort_by_key(|event| event.id.event_seq);
+    events
+}
+
+/// Where the event source reads events from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventDelivery {
+    /// The node's event index, through `suix_queryEvents`
+    #[default]
+    EventApi,
+    /// The events embedded in transactions, through `suix_queryTransactionBlocks`
+    FromTransactions,
+}
+
+/// IDs of recently fetched events, evicting the oldest beyond a capacity
+#[derive(Default)]
+struct RecentIds {
+    /// IDs in the order they were fetched
+    order: VecDeque<EventID>,
+    /// IDs for lookups
+    ids: HashSet<EventID>,
+}
+
+impl RecentIds {
+    fn contains(&self, id: &EventID) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Remembers the IDs of a page, keeping at most `capacity` IDs
+    fn extend(&mut self, page: impl IntoIterator<Item = EventID>, capacity: usize) {
+        for id in page {
+            if self.ids.insert(id) {
+                self.order.push_back(id);
+            }
+        }
+        while self.order.len() > capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.ids.remove(&evicted);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.order.clear();
+        self.ids.clear();
+    }
 }
 
 /// Sui blockchain data source for fetching event data from the Sui network
@@ -38,6 +164,8 @@ pub struct SuiEventSource {
     client: Option<SuiClient>,
     /// Last processed event ID
     last_processed_event_id: Option<String>,
+    /// Newest event read, where ascending reads resume if descending ones are rejected
+    newest_event: Option<EventID>,
     /// Event query filter
     query: EventFilter,
     /// Cursor for pagination
@@ -46,6 +174,64 @@ pub struct SuiEventSource {
     descending_order: bool,
     /// Maximum number of events to fetch
     max_events: usize,
+    /// Largest page the node serves, checked against `max_events` at init
+    node_page_limit: usize,
+    /// Order of events within emitted batches
+    emit_order: EmitOrder,
+    /// Channel receiving events that fail conversion
+    dead_letters: Option<DeadLetterSender>,
+    /// Shared request scheduler and this source's priority class
+    scheduler: Option<(SuiScheduler, Priority)>,
+    /// Shared connection reused instead of building a client
+    connection: Option<SuiConnection>,
+    /// Network name override, derived from the RPC URL when None
+    network: Option<String>,
+    /// Chain metadata fetched at init
+    chain: Option<ChainMetadata>,
+    /// Fullnode lag check, disabled when None
+    health: Option<HealthMonitor>,
+    /// Whether a pruned cursor is dropped instead of failing the poll
+    restart_on_pruning: bool,
+    /// Chain identifier the cursor was recorded on, unchecked when None
+    expected_chain: Option<String>,
+    /// Whether the cursor is dropped instead of failing after a network reset
+    restart_on_reset: bool,
+    /// Typed error of the last failed poll
+    last_error: Option<SuiSourceError>,
+    /// Delay applied before the first poll
+    start_delay: StartDelay,
+    /// Field redaction applied before emission, disabled when None
+    redaction: Option<RedactionConfig>,
+    /// Runtime counters
+    stats: StatsRecorder,
+    /// Endpoints polls are spread across, using the source's own client when None
+    pool: Option<EndpointPool>,
+    /// Control events pending emission
+    control: ControlQueue,
+    /// Failed items pending emission as error records
+    item_errors: ItemErrorQueue,
+    /// Handling of events that fail conversion
+    decode_error_policy: ErrorPolicy,
+    /// Handling of failed RPC requests
+    rpc_error_policy: ErrorPolicy,
+    /// Backoff of transient RPC failures, disabled when None
+    retry: Option<RetryConfig>,
+    /// Enrichers run over records before emission
+    enrichers: EnrichmentChain<ChainEvent>,
+    /// Byte budget of emitted batches and the batches held back by it
+    batches: BatchSplitter<ChainEvent>,
+    /// IDs of the events fetched by recent polls, to drop events re-read by later pages
+    emitted_ids: RecentIds,
+    /// Pause state shared with handles returned by `pause_handle`
+    pause: PauseHandle,
+    /// Where events are read from
+    delivery: EventDelivery,
+    /// Whether events are read from transactions when the node disables the events API
+    transaction_fallback: bool,
+    /// WebSocket subscription replacing polls while open, disabled when None
+    subscription: Option<EventSubscription>,
+    /// Store the position is saved to and resumed from, disabled when None
+    cursor_store: Option<CursorSlot>,
 }
 
 impl SuiEventSource {
@@ -62,10 +248,40 @@ impl SuiEventSource {
             initialized: false,
             client: None,
             last_processed_event_id: None,
+            newest_event: None,
             query: EventFilter::All([]),
             cursor: None,
             descending_order: true,
             max_events,
+            node_page_limit: rpc::QUERY_MAX_RESULT_LIMIT,
+            emit_order: EmitOrder::default(),
+            dead_letters: None,
+            scheduler: None,
+            connection: None,
+            network: None,
+            chain: None,
+            start_delay: StartDelay::default(),
+            redaction: None,
+            stats: StatsRecorder::default(),
+            pool: None,
+            control: ControlQueue::default(),
+            item_errors: ItemErrorQueue::default(),
+            decode_error_policy: ErrorPolicy::SkipAndLog,
+            rpc_error_policy: ErrorPolicy::FailFast,
+            retry: None,
+            enrichers: EnrichmentChain::default(),
+            batches: BatchSplitter::default(),
+            emitted_ids: RecentIds::default(),
+            pause: PauseHandle::default(),
+            delivery: EventDelivery::default(),
+            transaction_fallback: false,
+            subscription: None,
+            cursor_store: None,
+            health: None,
+            restart_on_pruning: false,
+            expected_chain: None,
+            restart_on_reset: false,
+            last_error: None,
         }
     }
 
@@ -86,35 +302,378 @@ impl SuiEventSource {
         self
     }
 
+    /// Sets the order of events within emitted batches
+    pub fn with_emit_order(mut self, emit_order: EmitOrder) -> Self {
+        self.emit_order = emit_order;
+        self
+    }
+
+    /// Sets the channel receiving events that fail conversion
+    pub fn with_dead_letter_channel(mut self, sender: DeadLetterSender) -> Self {
+        self.dead_letters = Some(sender);
+        self
+    }
+
+    /// Registers the source with a shared request scheduler
+    pub fn with_scheduler(mut self, scheduler: SuiScheduler, priority: Priority) -> Self {
+        self.scheduler = Some((scheduler, priority));
+        self
+    }
+
+    /// Wraps the source so events of registered Move types are decoded into `E`
+    pub fn decoded<E>(self, registry: MoveEventRegistry<E>) -> DecodedEventSource<E> {
+        DecodedEventSource::new(self, registry)
+    }
+
+    /// Wraps the source so the parsed JSON of every event is deserialized into `T`
+    ///
+    /// ```rust,no_run
+    /// use fluxus_source_sui::SuiEventSource;
+    /// use serde::Deserialize;
+    /// use sui_sdk::rpc_types::EventFilter;
+    /// use sui_sdk::types::parse_sui_struct_tag;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct SwapEvent {
+    ///     amount_in: String,
+    ///     amount_out: String,
+    /// }
+    ///
+    /// let event_type = parse_sui_struct_tag("0xabc::pool::SwapEvent").unwrap();
+    /// let source = SuiEventSource::new_with_mainnet(1000, 50)
+    ///     .with_query(EventFilter::MoveEventType(event_type))
+    ///     .typed::<SwapEvent>();
+    /// ```
+    pub fn typed<T>(self) -> TypedEventSource<T> {
+        TypedEventSource::new(self)
+    }
+
+    /// Enables periodic checks of the connected fullnode against local time
+    ///
+    /// # Parameters
+    /// * `interval` - Minimum time between checks, each costing one extra RPC call
+    /// * `lag_threshold` - Lag above which a warning is logged
+    pub fn with_health_check(mut self, interval: Duration, lag_threshold: Duration) -> Self {
+        self.health = Some(HealthMonitor::new(interval, lag_threshold));
+        self
+    }
+
+    /// Compares the fullnode with a reference endpoint during health checks
+    pub fn with_health_reference(mut self, rpc_url: impl Into<String>) -> Self {
+        self.health = self
+            .health
+            .map(|health| health.with_reference(rpc_url.into()));
+        self
+    }
+
+    /// Returns the result of the last health check, None unless enabled
+    pub fn health(&self) -> Option<&NodeHealth> {
+        self.health.as_ref().map(HealthMonitor::health)
+    }
+
+    /// Restarts from the oldest available event when the cursor has been pruned
+    ///
+    /// The cursor is cleared, so ascending queries resume at the oldest event the
+    /// node retains. When disabled, polling a pruned cursor fails with
+    /// `SuiSourceError::HistoryPruned`.
+    pub fn with_restart_on_pruning(mut self, restart_on_pruning: bool) -> Self {
+        self.restart_on_pruning = restart_on_pruning;
+        self
+    }
+
+    /// Sets the chain identifier the cursor was recorded on, e.g. persisted with it
+    ///
+    /// Compare with `chain_metadata()` of the run that saved the cursor. When the
+    /// node reports another chain, `init` fails with `SuiSourceError::NetworkReset`.
+    /// The chain is checked again when a poll fails on a missing cursor, so a wipe
+    /// while the source runs is reported the same way.
+    pub fn with_expected_chain(mut self, chain_identifier: impl Into<String>) -> Self {
+        self.expected_chain = Some(chain_identifier.into());
+        self
+    }
+
+    /// Drops the cursor instead of failing when the network has been reset
+    ///
+    /// The reset is still reported through `last_error`.
+    pub fn with_restart_on_reset(mut self, restart_on_reset: bool) -> Self {
+        self.restart_on_reset = restart_on_reset;
+        self
+    }
+
+    /// Returns the typed error of the last failed poll
+    pub fn last_error(&self) -> Option<&SuiSourceError> {
+        self.last_error.as_ref()
+    }
+
+    /// Saves the position to a cursor store and resumes from it at init
+    ///
+    /// The ID of the newest emitted event is saved as `digest:seq` after every poll.
+    /// A saved ID replaces the configured cursor and switches to ascending order.
+    pub fn with_cursor_store(
+        mut self,
+        store: Arc<dyn CursorStore>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.cursor_store = Some(CursorSlot::new(store, name.into()));
+        self
+    }
+
+    /// Saves the position to an asynchronous cursor store, see `with_cursor_store`
+    pub fn with_async_cursor_store(
+        mut self,
+        store: Arc<dyn AsyncCursorStore>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.cursor_store = Some(CursorSlot::new_async(store, name.into()));
+        self
+    }
+
+    /// Reuses a shared connection instead of building a client in `init`
+    ///
+    /// The connection's endpoint takes precedence over the RPC URL of the source.
+    pub fn with_connection(mut self, connection: SuiConnection) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Sets the network name stamped on records, e.g. for custom endpoints
+    pub fn with_network(mut self, network: impl Into<String>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
+    /// Returns the chain metadata fetched at init
+    pub fn chain_metadata(&self) -> Option<&ChainMetadata> {
+        self.chain.as_ref()
+    }
+
+    /// Delays the first poll by a random duration of up to `max_jitter`
+    pub fn with_start_jitter(mut self, max_jitter: Duration) -> Self {
+        self.start_delay.jitter = max_jitter;
+        self
+    }
+
+    /// Delays the first poll by a fixed offset, e.g. `replica * interval / replicas`
+    pub fn with_phase_offset(mut self, offset: Duration) -> Self {
+        self.start_delay.phase_offset = offset;
+        self
+    }
+
+    /// Redacts sender, recipient and metadata fields of records before emission
+    pub fn with_redaction(mut self, config: RedactionConfig) -> Self {
+        self.redaction = Some(config);
+        self
+    }
+
+    /// Spreads polls across the endpoints of a pool instead of one RPC URL
+    pub fn with_endpoint_pool(mut self, pool: EndpointPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Appends an enricher run over records before emission, after those added before
+    pub fn with_enricher(mut self, enricher: impl Enricher<ChainEvent> + 'static) -> Self {
+        self.enrichers.push(enricher);
+        self
+    }
+
+    /// Sets the maximum number of lookups of one enricher in flight at once, 8 by default
+    pub fn with_enrichment_concurrency(mut self, concurrency: usize) -> Self {
+        self.enrichers.set_concurrency(concurrency);
+        self
+    }
+
+    /// Bounds the time enrichers may delay a poll, cancelling the lookups still in flight
+    pub fn with_enrichment_deadline(mut self, deadline: Duration) -> Self {
+        self.enrichers.set_deadline(deadline);
+        self
+    }
+
+    /// Splits emitted batches whose JSON encoding exceeds `max_bytes`
+    ///
+    /// The batches split off are emitted by the following calls to `next` before
+    /// the source polls again. A single record above the budget is emitted alone.
+    /// A cursor store saves the position of the poll once its last batch is emitted.
+    pub fn with_max_batch_bytes(mut self, max_bytes: usize) -> Self {
+        self.batches.set_max_bytes(max_bytes);
+        self
+    }
+
+    /// Sets how per-item decode errors are handled, `SkipAndLog` by default
+    pub fn with_decode_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.decode_error_policy = policy;
+        self
+    }
+
+    /// Sets how per-poll RPC errors are handled, `FailFast` by default
+    pub fn with_rpc_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.rpc_error_policy = policy;
+        self
+    }
+
+    /// Retries transient RPC failures with exponential backoff, disabled by default
+    ///
+    /// Rate limiting, server errors and timeouts are retried within the poll, up to
+    /// `max_attempts` requests. Other failures, and transient ones still failing
+    /// after the last attempt, are handled by the RPC error policy.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Sets where events are read from, the events API by default
+    ///
+    /// `FromTransactions` reconstructs events from the transactions that emitted
+    /// them, for nodes whose event index lags or is pruned further than their
+    /// transactions. `max_events` then bounds the transactions read per poll, and
+    /// the query filter is applied locally, after narrowing the transactions by
+    /// sender or module on the node where the filter allows.
+    pub fn with_delivery(mut self, delivery: EventDelivery) -> Self {
+        self.delivery = delivery;
+        self
+    }
+
+    /// Switches to `EventDelivery::FromTransactions` when the node disables the events API
+    ///
+    /// Init probes `suix_queryEvents` and, without the fallback, fails with
+    /// `SuiSourceError::MethodUnavailable` on nodes that do not serve it.
+    pub fn with_transaction_fallback(mut self, transaction_fallback: bool) -> Self {
+        self.transaction_fallback = transaction_fallback;
+        self
+    }
+
+    /// Receives events pushed over a WebSocket subscription instead of polling
+    ///
+    /// Each `next` waits up to the polling interval for pushed events and returns
+    /// at most `max_events` of them. A dropped subscription is reopened with
+    /// exponential backoff, polling forward from the last pushed event in the
+    /// meantime. Before reading a new subscription, a bounded query backfill
+    /// reads the events between the newest event read and the subscription
+    /// start, so none are lost across the reconnect. A node that does not
+    /// support subscriptions is polled as before. The query filter is passed to
+    /// `suix_subscribeEvent`, which accepts fewer filters than `suix_queryEvents`.
+    pub fn with_subscription(mut self, ws_url: impl Into<String>) -> Self {
+        self.subscription = Some(EventSubscription::new(ws_url.into()));
+        self
+    }
+
+    /// Fetches a page of events from the configured delivery path
+    async fn query_page(&self, client: &SuiClient, limit: usize) -> StreamResult<EventPage> {
+        match self.delivery {
+            EventDelivery::EventApi => {
+                rpc::call(
+                    "SuiEventSource",
+                    "suix_queryEvents",
+                    "fetch events",
+                    client.event_api().query_events(
+                        self.query.clone(),
+                        self.cursor,
+                        Some(limit),
+                        self.descending_order,
+                    ),
+                )
+                .await
+            }
+            EventDelivery::FromTransactions => {
+                events_from_transactions(
+                    client,
+                    &self.query,
+                    self.cursor,
+                    limit,
+                    self.descending_order,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Fails on a network reset unless the source restarts on it
+    fn reset_network(&mut self, error: SuiSourceError) -> StreamResult<()> {
+        self.stats.error("network_reset");
+        self.last_error = Some(error.clone());
+        if !self.restart_on_reset {
+            return Err(error.into());
+        }
+
+        tracing::warn!("{}, dropping the cursor", error);
+        self.cursor = None;
+        self.last_processed_event_id = None;
+        self.newest_event = None;
+        self.emitted_ids.clear();
+        Ok(())
+    }
+
+    /// Interleaves lifecycle control events with the emitted records
+    pub fn controlled(self) -> ControlledSource<Self> {
+        ControlledSource::new(self)
+    }
+
+    /// Emits failed items and polls as error records instead of only logging them
+    pub fn item_results(self) -> ItemResultSource<Self> {
+        ItemResultSource::new(self)
+    }
+
+    /// Returns a handle pausing and resuming the polls of this source
+    ///
+    /// Pausing keeps the client and cursor, e.g. during downstream maintenance.
+    pub fn pause_handle(&self) -> PauseHandle {
+        self.pause.clone()
+    }
+
+    /// Returns a snapshot of the runtime counters
+    pub fn stats(&self) -> SourceStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets whether `bytes_fetched` is counted, disabled by default
+    pub fn with_byte_stats(mut self, byte_stats: bool) -> Self {
+        self.stats.count_bytes(byte_stats);
+        self
+    }
+
+    /// Sets the largest page the node serves, 50 by default as on standard fullnodes
+    pub fn with_node_page_limit(mut self, limit: usize) -> Self {
+        self.node_page_limit = limit;
+        self
+    }
+
     pub fn is_initialized(&self) -> bool {
         self.initialized
     }
+
+    /// Returns the client built at init
+    pub(crate) fn client(&self) -> Option<&SuiClient> {
+        self.client.as_ref()
+    }
 }
 
-#[async_trait]
-impl Source<Vec<ChainEvent>> for SuiEventSource {
-    async fn init(&mut self) -> StreamResult<()> {
-        if self.initialized {
-            return Ok(());
-        }
+impl ControlEvents for SuiEventSource {
+    fn enable_control_events(&mut self) {
+        self.control.enable();
+    }
 
-        // Initialize Sui client
-        let client = SuiClientBuilder::default()
-            .build(self.rpc_url.as_str())
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to initialize Sui client: {}", e);
-                StreamError::Runtime(format!("Failed to initialize Sui client: {}", e))
-            })?;
+    fn drain_control_events(&mut self) -> Vec<ControlEvent> {
+        self.control.drain()
+    }
+}
 
-        self.client = Some(client);
-        self.initialized = true;
-        tracing::info!("SuiEventSource initialized with RPC URL: {}", self.rpc_url);
+impl ItemErrors for SuiEventSource {
+    fn source_name(&self) -> &'static str {
+        "SuiEventSource"
+    }
 
-        Ok(())
+    fn enable_item_errors(&mut self) {
+        self.item_errors.enable();
     }
 
-    async fn next(&mut self) -> StreamResult<Option<Record<Vec<ChainEvent>>>> {
+    fn drain_item_errors(&mut self) -> Vec<SourceItemError> {
+        self.item_errors.drain()
+    }
+}
+
+impl SuiEventSource {
+    /// Polls once, drawing every request of the poll from the scheduler budget
+    async fn poll(&mut self) -> StreamResult<Option<Record<Vec<ChainEvent>>>> {
         // Ensure initialized
         if !self.initialized || self.client.is_none() {
             return Err(StreamError::Runtime(
@@ -122,82 +681,622 @@ impl Source<Vec<ChainEvent>> for SuiEventSource {
             ));
         }
 
-        // Polling interval
-        sleep(self.interval).await;
+        // Hold polling while paused through a control handle
+        self.pause.wait_resumed().await;
 
-        let client = self.client.as_ref().ok_or_else(|| {
-            StreamError::Runtime("SuiEventSource client not available".to_string())
-        })?;
+        // Emit batches held back by the byte budget before polling again
+        if let Some(batch) = self.batches.next_pending() {
+            if !self.batches.has_pending()
+                && let Some(slot) = &mut self.cursor_store
+                && !slot.save_deferred().await
+            {
+                self.stats.error("cursor_store");
+            }
+            self.stats.emitted(batch.len());
+            return Ok(Some(Record::new(batch)));
+        }
 
-        // Query events
-        let events = client
-            .event_api()
-            .query_events(
-                self.query.clone(),
-                self.cursor,
-                Some(self.max_events),
-                self.descending_order,
-            )
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch events: {}", e);
-                StreamError::Runtime(format!("Failed to fetch events: {}", e))
-            })?;
+        // Spread the first poll of replicas started together
+        self.start_delay.wait().await;
+
+        // Read pushed events while subscribed, polling otherwise
+        let rpc_url = self
+            .connection
+            .as_ref()
+            .map_or(self.rpc_url.as_str(), SuiConnection::rpc_url);
+        let pushed = match &mut self.subscription {
+            Some(subscription) => {
+                // A new subscription first queries from the newest event read
+                let resume = self.cursor.or(self.newest_event);
+                let pushed = subscription
+                    .receive(
+                        rpc_url,
+                        &self.query,
+                        self.max_events,
+                        self.interval,
+                        resume.is_some(),
+                    )
+                    .await;
+                if subscription.bridging() && self.cursor.is_none() {
+                    self.cursor = resume;
+                    self.descending_order = false;
+                }
+                pushed
+            }
+            None => None,
+        };
+        let subscribed = pushed.is_some();
+        let bridging = self
+            .subscription
+            .as_ref()
+            .is_some_and(EventSubscription::bridging);
+
+        // Polling interval, skipped while catching up before a new subscription
+        if !subscribed && !bridging {
+            sleep(self.interval).await;
+        }
+
+        // Mark the shared connection as active
+        if let Some(connection) = &self.connection {
+            connection.touch();
+        }
+
+        self.stats.poll();
+
+        // Pick the endpoint of this poll when spreading polls across a pool
+        let picked = match &self.pool {
+            Some(pool) => Some(pool.pick().await?),
+            None => None,
+        };
+        if let Some(endpoint) = &picked {
+            self.control.observe_endpoint(&endpoint.rpc_url);
+        }
+        let client = match &picked {
+            Some(endpoint) => &endpoint.client,
+            None => self.client.as_ref().ok_or_else(|| {
+                StreamError::Runtime("SuiEventSource client not available".to_string())
+            })?,
+        };
+
+        // Check fullnode lag against local time
+        if let Some(health) = &mut self.health {
+            health.check("SuiEventSource", client).await;
+        }
+
+        // Query events, halving the page while the node rejects it as too large
+        let mut fetch = PhaseTimer::start("SuiEventSource", Phase::Fetch);
+        let mut limit = self.max_events;
+        let mut failures = 0;
+        let mut retries = 0;
+        let events = if let Some(data) = pushed {
+            // Pushed events arrive oldest first, so polls filling in for a dropped
+            // subscription read forward from the last one
+            let next_cursor = data.last().map(|event| event.id);
+            if next_cursor.is_some() {
+                self.descending_order = false;
+            }
+            let events = EventPage {
+                data,
+                next_cursor,
+                has_next_page: false,
+            };
+            self.stats.fetched(&events);
+            events
+        } else {
+            loop {
+                match self.query_page(client, limit).await {
+                    Ok(events) => {
+                        self.stats.fetched(&events);
+                        break events;
+                    }
+                    Err(StreamError::Runtime(message))
+                        if limit > 1 && is_response_too_large(&message) =>
+                    {
+                        self.stats.error("response_too_large");
+                        limit /= 2;
+                        tracing::warn!("Event page too large, retrying with {} events", limit);
+                    }
+                    Err(StreamError::Runtime(message))
+                        if self.descending_order && is_descending_unsupported(&message) =>
+                    {
+                        // Read forward from now instead, a full scan would start at genesis
+                        self.stats.error("descending_unsupported");
+                        if matches!(self.query, EventFilter::All(_)) {
+                            let now = SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .unwrap_or_default()
+                                .as_millis() as u64;
+                            self.query = EventFilter::TimeRange {
+                                start_time: now.saturating_sub(self.interval.as_millis() as u64),
+                                end_time: u64::MAX,
+                            };
+                            tracing::warn!(
+                                "Node rejects descending event queries, reading ascending from {}",
+                                now
+                            );
+                        } else if let Some(newest) = self.newest_event {
+                            self.cursor = Some(newest);
+                            tracing::warn!(
+                                "Node rejects descending event queries, reading ascending after {}:{}",
+                                newest.tx_digest,
+                                newest.event_seq
+                            );
+                        } else {
+                            // A time range cannot be combined with this filter, so
+                            // nothing anchors an ascending read at the head
+                            return Err(StreamError::Runtime(
+                                "Node rejects descending event queries and no event has been \
+                                 read to resume from, set a cursor with with_cursor or query \
+                                 EventFilter::All"
+                                    .to_string(),
+                            ));
+                        }
+                        self.descending_order = false;
+                    }
+                    Err(StreamError::Runtime(message))
+                        if self.delivery == EventDelivery::EventApi
+                            && is_method_unavailable(&message) =>
+                    {
+                        // The provider disabled the events API after init
+                        let error = SuiSourceError::MethodUnavailable {
+                            method: "suix_queryEvents".to_string(),
+                        };
+                        self.stats.error("method_unavailable");
+                        self.last_error = Some(error.clone());
+                        if !self.transaction_fallback {
+                            return Err(error.into());
+                        }
+                        tracing::warn!("{}, reading events from transactions", error);
+                        self.delivery = EventDelivery::FromTransactions;
+                        return Ok(None);
+                    }
+                    Err(StreamError::Runtime(message))
+                        if self.cursor.is_some() && is_history_pruned(&message) =>
+                    {
+                        // A cursor missing after a wipe is a reset rather than pruning
+                        if let Some(chain) = &self.chain {
+                            let current = ChainMetadata::fetch(
+                                "SuiEventSource",
+                                client,
+                                chain.network.clone(),
+                            )
+                            .await?;
+                            if let Some(error) = current.reset_from(&chain.chain_identifier) {
+                                self.chain = Some(current);
+                                self.reset_network(error)?;
+                                return Ok(None);
+                            }
+                        }
+
+                        let oldest_available =
+                            oldest_available_event(client, self.query.clone()).await?;
+                        let error = SuiSourceError::HistoryPruned { oldest_available };
+                        self.stats.error("history_pruned");
+                        self.last_error = Some(error.clone());
+                        if !self.restart_on_pruning {
+                            return Err(error.into());
+                        }
+
+                        tracing::warn!("{}, restarting from the oldest available event", error);
+                        self.cursor = None;
+                        self.last_processed_event_id = None;
+                        self.newest_event = None;
+                        return Ok(None);
+                    }
+                    Err(StreamError::Runtime(message))
+                        if is_transient(&message)
+                            && self.retry.is_some_and(|retry| retry.allows(retries)) =>
+                    {
+                        self.stats.error("transient");
+                        retries += 1;
+                        let delay = self
+                            .retry
+                            .map_or(Duration::ZERO, |retry| retry.delay(retries));
+                        tracing::warn!(
+                            "Transient RPC failure, retrying in {:?}: {}",
+                            delay,
+                            message
+                        );
+                        sleep(delay).await;
+                    }
+                    Err(e) => {
+                        self.stats.error("rpc");
+                        if let Some(endpoint) = &picked {
+                            endpoint.mark_failed();
+                        }
+                        failures += 1;
+                        match self.rpc_error_policy.recovery(failures) {
+                            Recovery::Fail => return Err(e),
+                            Recovery::Skip => {
+                                tracing::warn!("Skipping poll after {} failed attempts", failures);
+                                self.item_errors.fetch_failed("SuiEventSource", &e);
+                                return Ok(None);
+                            }
+                            Recovery::Retry => sleep(self.interval).await,
+                        }
+                    }
+                }
+            }
+        };
+        self.last_error = None;
+        fetch.items(events.data.len());
+        drop(fetch);
+
+        if !subscribed && let Some(subscription) = &mut self.subscription {
+            subscription.bridged(events.has_next_page);
+        }
+
+        // Ascending reads move forward page by page, descending ones re-read the head
+        let next_cursor = events.next_cursor.filter(|_| !self.descending_order);
 
         // Return None if no new events
         if events.data.is_empty() {
+            self.cursor = next_cursor.or(self.cursor);
             tracing::info!("No new events found");
             return Ok(None);
         }
 
+        // Newest event of the page, saved as the position to resume from
+        let newest_id = if self.descending_order && !subscribed {
+            events.data.first()
+        } else {
+            events.data.last()
+        }
+        .map(|event| event.id);
+
         // Get latest event ID
         let latest_event = events
             .data
             .last()
             .ok_or_else(|| StreamError::Runtime("Failed to get latest event".to_string()))?;
-        let latest_event_id = latest_event.id.tx_digest.to_string();
+        let latest_id = latest_event.id;
+        let latest_event_id = latest_id.tx_digest.to_string();
 
         // Return None if event already processed
         if let Some(last_id) = &self.last_processed_event_id
             && last_id == &latest_event_id
         {
+            self.cursor = next_cursor.or(self.cursor);
             tracing::info!("No new events since last check");
             return Ok(None);
         }
 
-        // Update last processed event ID
+        // Convert to chain events, routing malformed ones to the dead-letter channel
+        let mut decode = PhaseTimer::start("SuiEventSource", Phase::Decode);
+        let mut chain_events = Vec::with_capacity(events.data.len());
+        for event in &events.data {
+            let mut converted = ChainEvent::try_from(event);
+            if let Err(error) = &converted {
+                match self.decode_error_policy {
+                    ErrorPolicy::FailFast => {
+                        self.stats.error("conversion");
+                        return Err(StreamError::Runtime(format!(
+                            "Failed to convert event {}:{}: {}",
+                            event.id.tx_digest, event.id.event_seq, error
+                        )));
+                    }
+                    ErrorPolicy::SkipAndLog => {}
+                    ErrorPolicy::RetryThenSkip { retries } => {
+                        // Fields such as the timestamp may be missing until the node catches up
+                        for _ in 0..retries {
+                            sleep(self.interval).await;
+                            let Ok(refetched) = rpc::call(
+                                "SuiEventSource",
+                                "sui_getEvents",
+                                &format!("refetch events of {}", event.id.tx_digest),
+                                client.event_api().get_events(event.id.tx_digest),
+                            )
+                            .await
+                            else {
+                                continue;
+                            };
+                            if let Some(refetched) = refetched.iter().find(|e| e.id == event.id) {
+                                converted = ChainEvent::try_from(refetched);
+                                if converted.is_ok() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            match converted {
+                Ok(mut chain_event) => {
+                    chain_event.chain = self.chain.clone();
+                    tracing::debug!(
+                        "Processed Sui event: {} from package: {}",
+                        chain_event.id.tx_digest,
+                        chain_event.module.package
+                    );
+                    chain_events.push(chain_event);
+                }
+                Err(error) => {
+                    self.stats.error("conversion");
+                    let letter = DeadLetter {
+                        source: "SuiEventSource".to_string(),
+                        item_id: format!("{}:{}", event.id.tx_digest, event.id.event_seq),
+                        error,
+                        raw: format!("{:?}", event),
+                    };
+                    self.item_errors.decode_failed(&letter);
+                    route_dead_letter(self.dead_letters.as_ref(), letter);
+                }
+            }
+        }
+
+        decode.items(chain_events.len());
+        drop(decode);
+
+        // Move past the page only once it converted, so a failed page is read again
+        self.cursor = next_cursor.or(self.cursor);
+        self.stats.set_cursor(Some(format!(
+            "{}:{}",
+            latest_id.tx_digest, latest_id.event_seq
+        )));
         self.last_processed_event_id = Some(latest_event_id);
+        self.newest_event = newest_id;
 
-        // Convert to chain events
-        let chain_events: Vec<ChainEvent> = events
-            .data
-            .into_iter()
-            .map(|event| {
-                let chain_event = ChainEvent {
-                    id: event.id,
-                    package_id: event.package_id.to_string(),
-                    module_name: event.transaction_module.to_string(),
-                    event_type: event.type_.to_string(),
-                    sender: event.sender.to_string(),
-                    data: format!("{:?}", event.parsed_json),
-                    timestamp: event.timestamp_ms.expect("Timestamp not available"),
-                };
-                tracing::debug!(
-                    "Processed Sui event: {} from package: {}",
-                    chain_event.id.tx_digest,
-                    chain_event.package_id
-                );
-                chain_event
-            })
-            .collect();
+        // Return None if every event was dropped
+        if chain_events.is_empty() {
+            tracing::info!("No convertible events found");
+            return Ok(None);
+        }
+
+        // Drop events recent polls already fetched, remembering the whole page
+        // rather than the events emitted from it: a head page re-read by later
+        // polls overlaps every page fetched since its oldest event was new
+        let fetched = chain_events.len();
+        chain_events.retain(|event| !self.emitted_ids.contains(&event.id));
+        self.emitted_ids.extend(
+            events.data.iter().map(|event| event.id),
+            2 * self.max_events,
+        );
+        let duplicates = fetched - chain_events.len();
+        if duplicates > 0 {
+            tracing::debug!("Dropped {} duplicate events", duplicates);
+            self.stats.duplicates(duplicates);
+        }
+        if chain_events.is_empty() {
+            tracing::info!("No events beyond the previous poll");
+            return Ok(None);
+        }
 
+        let mut emit = PhaseTimer::start("SuiEventSource", Phase::Emit);
+
+        // Pushed events arrive oldest first
+        self.emit_order.arrange(
+            &mut chain_events,
+            self.descending_order && !subscribed,
+            |event| event.timestamp,
+        );
+
+        let failures = self.enrichers.apply(&mut chain_events).await;
+        self.stats.errors("enrichment", failures);
+
+        if let Some(redaction) = &self.redaction {
+            for item in &mut chain_events {
+                item.redact(redaction);
+            }
+        }
+
+        let chain_events = self.batches.split(chain_events);
+        if let (Some(slot), Some(id)) = (&mut self.cursor_store, newest_id)
+            && !slot
+                .save_after(
+                    format!("{}:{}", id.tx_digest, id.event_seq),
+                    self.batches.has_pending(),
+                )
+                .await
+        {
+            self.stats.error("cursor_store");
+        }
+        self.stats.emitted(chain_events.len());
+        emit.items(chain_events.len());
         Ok(Some(Record::new(chain_events)))
     }
+}
+
+#[async_trait]
+impl Source<Vec<ChainEvent>> for SuiEventSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client, reusing the endpoint pool or shared connection if set
+        let client = match (&self.pool, &self.connection) {
+            (Some(pool), _) => {
+                pool.connect("SuiEventSource").await?;
+                pool.pick().await?.client
+            }
+            (None, Some(connection)) => connection.client().clone(),
+            (None, None) => {
+                rpc::call(
+                    "SuiEventSource",
+                    "rpc.discover",
+                    "initialize Sui client",
+                    SuiClientBuilder::default().build(self.rpc_url.as_str()),
+                )
+                .await?
+            }
+        };
+
+        // Resume from the position saved by a previous run
+        if let Some(slot) = &self.cursor_store
+            && let Some(saved) = slot.load().await?
+        {
+            let id = parse_event_id(saved.trim())
+                .ok_or_else(|| StreamError::Runtime(format!("Invalid saved cursor {}", saved)))?;
+            tracing::info!(
+                "SuiEventSource resuming after {}:{}",
+                id.tx_digest,
+                id.event_seq
+            );
+            self.cursor = Some(id);
+            self.descending_order = false;
+        }
+
+        // Identify the chain stamped on every record
+        let rpc_url = self
+            .connection
+            .as_ref()
+            .map_or(self.rpc_url.as_str(), SuiConnection::rpc_url);
+        let network = self
+            .network
+            .clone()
+            .unwrap_or_else(|| network_name(rpc_url));
+        let chain = ChainMetadata::fetch("SuiEventSource", &client, network).await?;
+
+        // Detect a network wiped since the cursor was recorded
+        if let Some(expected) = &self.expected_chain
+            && let Some(error) = chain.reset_from(expected)
+        {
+            self.reset_network(error)?;
+        }
+        self.chain = Some(chain);
+
+        // Detect providers disabling the events API before the first poll
+        if self.delivery == EventDelivery::EventApi
+            && let Err(StreamError::Runtime(message)) = rpc::call(
+                "SuiEventSource",
+                "suix_queryEvents",
+                "probe events API",
+                client
+                    .event_api()
+                    .query_events(self.query.clone(), None, Some(1), true),
+            )
+            .await
+            && is_method_unavailable(&message)
+        {
+            let error = SuiSourceError::MethodUnavailable {
+                method: "suix_queryEvents".to_string(),
+            };
+            self.stats.error("method_unavailable");
+            self.last_error = Some(error.clone());
+            if !self.transaction_fallback {
+                return Err(error.into());
+            }
+            tracing::warn!("{}, reading events from transactions", error);
+            self.delivery = EventDelivery::FromTransactions;
+        }
+
+        // Keep pages within the node limit instead of failing at runtime
+        self.max_events = rpc::check_page_size(
+            "SuiEventSource",
+            "max_events",
+            self.max_events,
+            self.node_page_limit,
+        )?;
+
+        self.client = Some(client);
+        self.initialized = true;
+        self.stats.start();
+        tracing::info!("SuiEventSource initialized with RPC URL: {}", self.rpc_url);
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<ChainEvent>>>> {
+        rpc::with_budget(self.scheduler.clone(), self.poll()).await
+    }
 
     async fn close(&mut self) -> StreamResult<()> {
         self.initialized = false;
         self.client = None;
+        if let Some(subscription) = &mut self.subscription {
+            subscription.close();
+        }
         tracing::info!("SuiEventSource closed");
         Ok(())
     }
 }
+
+/// Parses an event ID written as `digest:seq`
+fn parse_event_id(value: &str) -> Option<EventID> {
+    let (digest, seq) = value.split_once(':')?;
+    Some(EventID {
+        tx_digest: TransactionDigest::from_str(digest).ok()?,
+        event_seq: seq.parse().ok()?,
+    })
+}
+
+/// Reassembles a page of events from the events embedded in a page of transactions
+///
+/// The page cursor points at the last transaction read, so the following page
+/// resumes after it.
+async fn events_from_transactions(
+    client: &SuiClient,
+    query: &EventFilter,
+    cursor: Option<EventID>,
+    limit: usize,
+    descending_order: bool,
+) -> StreamResult<EventPage> {
+    let filter = match query {
+        EventFilter::Sender(sender) => Some(TransactionFilter::FromAddress(*sender)),
+        EventFilter::MoveModule { package, module } => Some(TransactionFilter::MoveFunction {
+            package: *package,
+            module: Some(module.to_string()),
+            function: None,
+        }),
+        _ => None,
+    };
+    let transactions = rpc::call(
+        "SuiEventSource",
+        "suix_queryTransactionBlocks",
+        "fetch transactions with events",
+        client.read_api().query_transaction_blocks(
+            SuiTransactionBlockResponseQuery::new(
+                filter,
+                Some(SuiTransactionBlockResponseOptions::new().with_events()),
+            ),
+            cursor.map(|id| id.tx_digest),
+            Some(limit),
+            descending_order,
+        ),
+    )
+    .await?;
+
+    let mut data = Vec::new();
+    for transaction in &transactions.data {
+        let mut events: Vec<SuiEvent> = transaction
+            .events
+            .iter()
+            .flat_map(|events| &events.data)
+            .map(|event| {
+                let mut event = event.clone();
+                event.timestamp_ms = event.timestamp_ms.or(transaction.timestamp_ms);
+                event
+            })
+            .filter(|event| query.matches(event))
+            .collect();
+        // Descending event pages list the events of a transaction newest first too
+        if descending_order {
+            events.reverse();
+        }
+        data.extend(events);
+    }
+
+    Ok(EventPage {
+        data,
+        next_cursor: transactions.next_cursor.map(|tx_digest| EventID {
+            tx_digest,
+            event_seq: 0,
+        }),
+        has_next_page: transactions.has_next_page,
+    })
+}
+
+/// Fetches the ID of the oldest event the node retains for a query
+async fn oldest_available_event(
+    client: &SuiClient,
+    query: EventFilter,
+) -> StreamResult<Option<EventID>> {
+    let page = rpc::call(
+        "SuiEventSource",
+        "suix_queryEvents",
+        "fetch oldest available event",
+        client.event_api().query_events(query, None, Some(1), false),
+    )
+    .await?;
+    Ok(page.data.first().map(|event| event.id))
+}
@@ -0,0 +1,172 @@
+use crate::transaction::SuiTransactionSource;
+use crate::window::TumblingWindow;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+use sui_sdk::rpc_types::{
+    SuiCommand, SuiExecutionStatus, SuiTransactionBlockDataAPI, SuiTransactionBlockEffectsAPI,
+    SuiTransactionBlockKind,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageFailureRate {
+    /// Package ID
+    pub package: String,
+    /// Successful transactions calling the package within the window
+    pub successes: u64,
+    /// Failed transactions calling the package within the window
+    pub failures: u64,
+    /// Most frequent errors and their counts, most frequent first
+    pub top_errors: Vec<(String, u64)>,
+    /// Window start, local time (milliseconds)
+    pub window_start: u64,
+    /// Window end, local time (milliseconds)
+    pub window_end: u64,
+}
+
+/// Per-package outcome counts within the current window
+#[derive(Default)]
+struct Outcomes {
+    successes: u64,
+    failures: u64,
+    errors: HashMap<String, u64>,
+}
+
+/// Source emitting per-package success and failure counts over tumbling windows
+///
+/// A transaction counts towards every package its Move calls target, so the inner
+/// source must fetch both input and effects. Move aborts are grouped by abort
+/// location and code, other errors by their message without the failing command
+/// index. Windows follow local time like `SuiCallFrequencySource`.
+pub struct SuiFailureRateSource {
+    /// Underlying transaction source
+    inner: SuiTransactionSource,
+    /// Counting window
+    window: TumblingWindow,
+    /// Number of errors reported per package
+    top_errors: usize,
+    /// Outcomes counted in the current window
+    outcomes: HashMap<String, Outcomes>,
+}
+
+impl SuiFailureRateSource {
+    /// Creates a new SuiFailureRateSource
+    ///
+    /// # Parameters
+    /// * `inner` - Transaction source providing the outcomes
+    /// * `window` - Length of the counting window
+    pub fn new(inner: SuiTransactionSource, window: Duration) -> Self {
+        Self {
+            inner,
+            window: TumblingWindow::new(window),
+            top_errors: 5,
+            outcomes: HashMap::new(),
+        }
+    }
+
+    /// Sets the number of errors reported per package (default 5)
+    pub fn with_top_errors(mut self, top_errors: usize) -> Self {
+        self.top_errors = top_errors;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+/// Strips the failing command index so equal errors of different commands group together
+fn error_key(error: &str) -> String {
+    match error.rsplit_once(" in command ") {
+        Some((error, index)) if index.chars().all(|c| c.is_ascii_digit()) => error.to_string(),
+        _ => error.to_string(),
+    }
+}
+
+#[async_trait]
+impl Source<Vec<PackageFailureRate>> for SuiFailureRateSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<PackageFailureRate>>>> {
+        self.window.open();
+
+        if let Some(record) = self.inner.next().await? {
+            for transaction in record.data {
+                if !self.window.first_seen(&transaction.transaction_digest) {
+                    continue;
+                }
+                let (Some(metadata), Some(effects)) = (&transaction.metadata, &transaction.effects)
+                else {
+                    continue;
+                };
+                let SuiTransactionBlockKind::ProgrammableTransaction(programmable) =
+                    metadata.transaction()
+                else {
+                    continue;
+                };
+
+                let packages: BTreeSet<String> = programmable
+                    .commands
+                    .iter()
+                    .filter_map(|command| match command {
+                        SuiCommand::MoveCall(call) => Some(call.package.to_string()),
+                        _ => None,
+                    })
+                    .collect();
+                for package in packages {
+                    let outcomes = self.outcomes.entry(package).or_default();
+                    match effects.status() {
+                        SuiExecutionStatus::Success => outcomes.successes += 1,
+                        SuiExecutionStatus::Failure { error } => {
+                            outcomes.failures += 1;
+                            let key = match &transaction.abort {
+                                Some(abort) => abort.to_string(),
+                                None => error_key(error),
+                            };
+                            *outcomes.errors.entry(key).or_insert(0) += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Keep counting until the window closes
+        let Some((window_start, window_end)) = self.window.close() else {
+            return Ok(None);
+        };
+
+        let mut rates: Vec<PackageFailureRate> = self
+            .outcomes
+            .drain()
+            .map(|(package, outcomes)| {
+                let mut top_errors: Vec<(String, u64)> = outcomes.errors.into_iter().collect();
+                top_errors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+                top_errors.truncate(self.top_errors);
+                PackageFailureRate {
+                    package,
+                    successes: outcomes.successes,
+                    failures: outcomes.failures,
+                    top_errors,
+                    window_start,
+                    window_end,
+                }
+            })
+            .collect();
+        if rates.is_empty() {
+            tracing::info!("No package calls within the window");
+            return Ok(None);
+        }
+        rates.sort_by(|a, b| b.failures.cmp(&a.failures));
+
+        Ok(Some(Record::new(rates)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,170 @@
+use crate::event::{ChainEvent, SuiEventSource};
+use crate::move_type::MoveTypeTag;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::str::FromStr;
+use sui_sdk::types::base_types::ObjectID;
+
+/// Governance proposal created or updated on chain
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Proposal {
+    /// Proposal ID, from the `proposal_id`, `proposal` or `id` field
+    pub proposal_id: Option<String>,
+    /// Governance package ID
+    pub package: String,
+    /// Move event type
+    pub event_type: MoveTypeTag,
+    /// Proposer, from the `proposer` field or the event sender
+    pub proposer: String,
+    /// Event fields as parsed JSON
+    pub fields: Value,
+    /// Timestamp (milliseconds)
+    pub timestamp: u64,
+}
+
+/// Vote cast on a governance proposal
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Vote {
+    /// Proposal ID, from the `proposal_id`, `proposal` or `id` field
+    pub proposal_id: Option<String>,
+    /// Governance package ID
+    pub package: String,
+    /// Move event type
+    pub event_type: MoveTypeTag,
+    /// Voter, from the `voter` field or the event sender
+    pub voter: String,
+    /// Choice, from the `choice`, `vote` or `support` field
+    pub choice: Option<String>,
+    /// Voting power, from the `weight`, `votes` or `voting_power` field
+    pub weight: Option<u128>,
+    /// Timestamp (milliseconds)
+    pub timestamp: u64,
+}
+
+/// Normalized governance record
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GovernanceRecord {
+    Proposal(Proposal),
+    Vote(Vote),
+}
+
+impl GovernanceRecord {
+    /// Normalizes a governance event, None if it is neither a proposal nor a vote
+    ///
+    /// Events are classified by the name of their Move struct: names containing
+    /// `Vote` are votes, names containing `Proposal` are proposals. Fields are read
+    /// by their common names across governance packages.
+    pub fn from_event(event: &ChainEvent) -> Option<Self> {
+        let name = &*event.event_type.name;
+        let json = &event.parsed_json;
+        let proposal_id = string_field(json, &["proposal_id", "proposal", "id"]);
+
+        if name.contains("Vote") {
+            Some(GovernanceRecord::Vote(Vote {
+                proposal_id,
+                package: event.module.package.to_string(),
+                event_type: event.event_type.clone(),
+                voter: string_field(json, &["voter"]).unwrap_or_else(|| event.sender.clone()),
+                choice: string_field(json, &["choice", "vote", "support"]),
+                weight: string_field(json, &["weight", "votes", "voting_power"])
+                    .and_then(|weight| weight.parse().ok()),
+                timestamp: event.timestamp,
+            }))
+        } else if name.contains("Proposal") {
+            Some(GovernanceRecord::Proposal(Proposal {
+                proposal_id,
+                package: event.module.package.to_string(),
+                event_type: event.event_type.clone(),
+                proposer: string_field(json, &["proposer"]).unwrap_or_else(|| event.sender.clone()),
+                fields: json.clone(),
+                timestamp: event.timestamp,
+            }))
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads the first present field as a string, unwrapping `{ "id": .. }` wrappers
+fn string_field(json: &Value, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| match json.get(name)? {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        Value::Object(object) => object.get("id").and_then(|id| match id {
+            Value::String(id) => Some(id.clone()),
+            _ => None,
+        }),
+        _ => None,
+    })
+}
+
+/// Source emitting normalized proposal and vote records of governance packages
+///
+/// Events of the inner source are kept when emitted by one of the configured
+/// packages; restrict the inner source with a `with_query` filter on those packages
+/// to avoid fetching unrelated events. Events that are neither proposals nor votes
+/// are skipped.
+pub struct SuiGovernanceSource {
+    /// Underlying event source
+    inner: SuiEventSource,
+    /// Governance package IDs
+    packages: HashSet<ObjectID>,
+}
+
+impl SuiGovernanceSource {
+    /// Creates a new SuiGovernanceSource
+    ///
+    /// # Parameters
+    /// * `inner` - Event source providing the governance events
+    /// * `packages` - IDs of the governance packages tracked
+    pub fn new(inner: SuiEventSource, packages: impl IntoIterator<Item = ObjectID>) -> Self {
+        Self {
+            inner,
+            packages: packages.into_iter().collect(),
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+
+    fn is_tracked(&self, event: &ChainEvent) -> bool {
+        ObjectID::from_str(&event.module.package)
+            .is_ok_and(|package| self.packages.contains(&package))
+    }
+}
+
+#[async_trait]
+impl Source<Vec<GovernanceRecord>> for SuiGovernanceSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<GovernanceRecord>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let records: Vec<GovernanceRecord> = record
+            .data
+            .iter()
+            .filter(|event| self.is_tracked(event))
+            .filter_map(GovernanceRecord::from_event)
+            .collect();
+        if records.is_empty() {
+            tracing::info!("No governance events found");
+            return Ok(None);
+        }
+
+        Ok(Some(Record::new(records)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::{Mutex, MutexGuard};
+
+/// Cheaply cloneable handle to one source
+///
+/// Clones share the same source, client and cursors, so a source can be
+/// constructed and initialized once and handed to operators that require `Clone`.
+/// Calls to `next` from different clones are serialized and each record is
+/// returned to exactly one caller. `init` is forwarded to the source, which
+/// ignores repeated calls, and `close` closes it once every handle has been
+/// closed or dropped.
+pub struct SourceHandle<S> {
+    /// Shared source
+    inner: Arc<Mutex<S>>,
+    /// Handles neither closed nor dropped yet
+    open: Arc<AtomicUsize>,
+    /// Whether this handle was closed
+    closed: bool,
+}
+
+impl<S> Clone for SourceHandle<S> {
+    fn clone(&self) -> Self {
+        self.open.fetch_add(1, Ordering::SeqCst);
+        Self {
+            inner: self.inner.clone(),
+            open: self.open.clone(),
+            closed: false,
+        }
+    }
+}
+
+impl<S> Drop for SourceHandle<S> {
+    fn drop(&mut self) {
+        if !self.closed {
+            self.open.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<S> SourceHandle<S> {
+    /// Wraps a source into a shareable handle
+    pub fn new(source: S) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(source)),
+            open: Arc::new(AtomicUsize::new(1)),
+            closed: false,
+        }
+    }
+
+    /// Locks the source, e.g. to read its stats, waiting for a poll in progress
+    pub async fn lock(&self) -> MutexGuard<'_, S> {
+        self.inner.lock().await
+    }
+}
+
+#[async_trait]
+impl<T, S> Source<T> for SourceHandle<S>
+where
+    S: Source<T> + Send,
+    T: Send + 'static,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.lock().await.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<T>>> {
+        self.inner.lock().await.next().await
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        if self.closed {
+            return Ok(());
+        }
+        self.closed = true;
+        if self.open.fetch_sub(1, Ordering::SeqCst) > 1 {
+            return Ok(());
+        }
+        self.inner.lock().await.close().await
+    }
+}
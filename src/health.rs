@@ -0,0 +1,201 @@
+use crate::rpc;
+use fluxus::utils::models::StreamResult;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use sui_sdk::rpc_types::Checkpoint;
+use sui_sdk::{SuiClient, SuiClientBuilder};
+
+/// Freshness of the connected fullnode, estimated from checkpoint timestamps
+///
+/// With a reference endpoint, `lag_ms` compares the newest checkpoint of the
+/// node with the newest one of the reference, both stamped by the chain, and
+/// `clock_skew_ms` is local time minus the fresher of the two timestamps.
+///
+/// Without a reference, `lag_ms` is local time minus the timestamp of the
+/// newest checkpoint the node serves. It then mixes network lag of the node
+/// with local clock skew: a large positive value means the node is behind (or
+/// the local clock is ahead) and a negative value means the local clock is behind.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct NodeHealth {
+    /// Newest checkpoint served by the node
+    pub latest_checkpoint: Option<u64>,
+    /// Timestamp of the newest checkpoint (milliseconds)
+    pub checkpoint_timestamp_ms: Option<u64>,
+    /// Reference checkpoint timestamp minus node checkpoint timestamp, or local time without a reference (milliseconds)
+    pub lag_ms: Option<i64>,
+    /// Newest checkpoint served by the reference endpoint
+    pub reference_checkpoint: Option<u64>,
+    /// Checkpoints the node is behind the reference endpoint
+    pub checkpoints_behind: Option<u64>,
+    /// Local time minus the fresher checkpoint timestamp, None without a reference (milliseconds)
+    pub clock_skew_ms: Option<i64>,
+    /// Local time of the last successful check (milliseconds)
+    pub checked_at_ms: Option<u64>,
+}
+
+impl NodeHealth {
+    /// Returns whether the last check found the node lagging beyond the threshold
+    pub fn is_lagging(&self, threshold: Duration) -> bool {
+        self.lag_ms
+            .is_some_and(|lag| lag > threshold.as_millis() as i64)
+    }
+}
+
+/// Periodic lag check shared by the polling sources
+pub(crate) struct HealthMonitor {
+    /// Minimum time between checks
+    interval: Duration,
+    /// Lag above which a warning is logged
+    lag_threshold: Duration,
+    /// Time of the last check attempt
+    last_check: Option<Instant>,
+    /// Result of the last successful check
+    health: NodeHealth,
+    /// RPC endpoint URL the node is compared with
+    reference_url: Option<String>,
+    /// Client of the reference endpoint, connected at the first check
+    reference: Option<SuiClient>,
+}
+
+impl HealthMonitor {
+    pub(crate) fn new(interval: Duration, lag_threshold: Duration) -> Self {
+        Self {
+            interval,
+            lag_threshold,
+            last_check: None,
+            health: NodeHealth::default(),
+            reference_url: None,
+            reference: None,
+        }
+    }
+
+    pub(crate) fn with_reference(mut self, rpc_url: String) -> Self {
+        self.reference_url = Some(rpc_url);
+        self.reference = None;
+        self
+    }
+
+    pub(crate) fn health(&self) -> &NodeHealth {
+        &self.health
+    }
+
+    /// Fetches the newest checkpoint if the check interval elapsed
+    ///
+    /// Failures are logged and leave the previous result in place, so a health
+    /// check never fails the poll it runs in. A failing reference falls back to
+    /// comparing with local time.
+    pub(crate) async fn check(&mut self, source: &str, client: &SuiClient) {
+        if let Some(last_check) = self.last_check
+            && last_check.elapsed() < self.interval
+        {
+            return;
+        }
+        self.last_check = Some(Instant::now());
+
+        let checkpoint = match latest_checkpoint(source, client).await {
+            Ok(Some(checkpoint)) => checkpoint,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!("{} health check failed: {}", source, e);
+                return;
+            }
+        };
+        let reference = match self.reference_checkpoint(source).await {
+            Ok(reference) => reference,
+            Err(e) => {
+                tracing::warn!("{} health check reference failed: {}", source, e);
+                None
+            }
+        };
+
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        self.health = NodeHealth {
+            latest_checkpoint: Some(checkpoint.sequence_number),
+            checkpoint_timestamp_ms: Some(checkpoint.timestamp_ms),
+            lag_ms: Some(
+                reference.as_ref().map_or(now_ms, |r| r.timestamp_ms) as i64
+                    - checkpoint.timestamp_ms as i64,
+            ),
+            reference_checkpoint: reference.as_ref().map(|r| r.sequence_number),
+            checkpoints_behind: reference
+                .as_ref()
+                .map(|r| r.sequence_number.saturating_sub(checkpoint.sequence_number)),
+            clock_skew_ms: reference
+                .as_ref()
+                .map(|r| now_ms as i64 - r.timestamp_ms.max(checkpoint.timestamp_ms) as i64),
+            checked_at_ms: Some(now_ms),
+        };
+
+        let threshold = self.lag_threshold.as_millis() as i64;
+        let lag_ms = self.health.lag_ms.unwrap_or_default();
+        if self.health.is_lagging(self.lag_threshold) {
+            match reference {
+                Some(reference) => tracing::warn!(
+                    "{} fullnode is lagging: checkpoint {} is {} ms behind reference checkpoint {}",
+                    source,
+                    checkpoint.sequence_number,
+                    lag_ms,
+                    reference.sequence_number
+                ),
+                None => tracing::warn!(
+                    "{} fullnode is lagging: checkpoint {} is {} ms behind local time",
+                    source,
+                    checkpoint.sequence_number,
+                    lag_ms
+                ),
+            }
+        }
+        match self.health.clock_skew_ms {
+            Some(skew_ms) if skew_ms.abs() > threshold => tracing::warn!(
+                "{} local clock is {} ms {} checkpoint {}",
+                source,
+                skew_ms.abs(),
+                if skew_ms < 0 { "behind" } else { "ahead of" },
+                checkpoint.sequence_number
+            ),
+            None if -lag_ms > threshold => tracing::warn!(
+                "{} local clock is {} ms behind checkpoint {}",
+                source,
+                -lag_ms,
+                checkpoint.sequence_number
+            ),
+            _ => {}
+        }
+    }
+
+    /// Fetches the newest checkpoint of the reference endpoint, connecting it first if needed
+    async fn reference_checkpoint(&mut self, source: &str) -> StreamResult<Option<Checkpoint>> {
+        let Some(rpc_url) = &self.reference_url else {
+            return Ok(None);
+        };
+        if self.reference.is_none() {
+            let client = rpc::call(
+                source,
+                "rpc.discover",
+                "initialize reference client",
+                SuiClientBuilder::default().build(rpc_url.as_str()),
+            )
+            .await?;
+            self.reference = Some(client);
+        }
+        match &self.reference {
+            Some(client) => latest_checkpoint(source, client).await,
+            None => Ok(None),
+        }
+    }
+}
+
+/// Fetches the newest checkpoint a node serves
+async fn latest_checkpoint(source: &str, client: &SuiClient) -> StreamResult<Option<Checkpoint>> {
+    let page = rpc::call(
+        source,
+        "sui_getCheckpoints",
+        "fetch latest checkpoint",
+        client.read_api().get_checkpoints(None, Some(1), true),
+    )
+    .await?;
+    Ok(page.data.into_iter().next())
+}
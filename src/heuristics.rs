@@ -0,0 +1,139 @@
+use crate::transaction::SuiEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use sui_sdk::rpc_types::{
+    SuiCommand, SuiTransactionBlockDataAPI, SuiTransactionBlockEffectsAPI, SuiTransactionBlockKind,
+    SuiTransactionBlockResponse,
+};
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::types::object::Owner;
+
+/// Anti-spam classification attached to transaction records
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HeuristicTag {
+    /// Objects were transferred but nothing left the sender
+    SelfTransfer,
+    /// Every amount credited to another address is at or below the dust threshold
+    Dusting,
+    /// Assets were spread across many distinct recipients
+    AirdropSpray,
+    /// The sender submitted many failed transactions within one batch
+    FailedTxSpam,
+}
+
+/// Thresholds for the heuristics enrichment
+#[derive(Clone, Debug)]
+pub struct HeuristicsConfig {
+    /// Largest amount credited to a recipient that still counts as dust
+    pub dust_threshold: u128,
+    /// Minimum number of distinct recipients for an airdrop spray
+    pub spray_min_recipients: usize,
+    /// Minimum number of failed transactions from one sender within a batch to count as spam
+    pub failed_spam_min: usize,
+}
+
+impl Default for HeuristicsConfig {
+    fn default() -> Self {
+        Self {
+            dust_threshold: 1_000,
+            spray_min_recipients: 10,
+            failed_spam_min: 3,
+        }
+    }
+}
+
+impl HeuristicsConfig {
+    /// Computes the heuristics of a single transaction from its effects and balance changes
+    pub fn classify(&self, transaction: &SuiTransactionBlockResponse) -> Vec<HeuristicTag> {
+        let mut tags = Vec::new();
+        let Some(block) = &transaction.transaction else {
+            return tags;
+        };
+        let Some(effects) = &transaction.effects else {
+            return tags;
+        };
+        // Failed transactions are classified across the batch
+        if !effects.status().is_ok() {
+            return tags;
+        }
+        let sender = *block.data.sender();
+
+        // Amounts credited to addresses other than the sender
+        let mut credits: HashMap<SuiAddress, i128> = HashMap::new();
+        for change in transaction.balance_changes.iter().flatten() {
+            if let Owner::AddressOwner(owner) = &change.owner
+                && *owner != sender
+                && change.amount > 0
+            {
+                *credits.entry(*owner).or_insert(0) += change.amount;
+            }
+        }
+
+        // Addresses other than the sender that received coins or objects
+        let mut recipients: HashSet<SuiAddress> = credits.keys().copied().collect();
+        for object in effects.created().iter().chain(effects.mutated()) {
+            if let Owner::AddressOwner(owner) = &object.owner
+                && *owner != sender
+            {
+                recipients.insert(*owner);
+            }
+        }
+
+        let transfers_objects = match block.data.transaction() {
+            SuiTransactionBlockKind::ProgrammableTransaction(programmable) => programmable
+                .commands
+                .iter()
+                .any(|command| matches!(command, SuiCommand::TransferObjects(..))),
+            _ => false,
+        };
+
+        if transfers_objects && recipients.is_empty() {
+            tags.push(HeuristicTag::SelfTransfer);
+        }
+        if !credits.is_empty()
+            && credits
+                .values()
+                .all(|amount| amount.unsigned_abs() <= self.dust_threshold)
+        {
+            tags.push(HeuristicTag::Dusting);
+        }
+        if recipients.len() >= self.spray_min_recipients {
+            tags.push(HeuristicTag::AirdropSpray);
+        }
+
+        tags
+    }
+
+    /// Tags the failed transactions of senders that exceed the failed-transaction threshold
+    pub(crate) fn tag_failed_spam(
+        &self,
+        transactions: &[SuiTransactionBlockResponse],
+        events: &mut [SuiEvent],
+    ) {
+        let mut failures: HashMap<SuiAddress, Vec<String>> = HashMap::new();
+        for transaction in transactions {
+            let failed = transaction
+                .effects
+                .as_ref()
+                .is_some_and(|effects| !effects.status().is_ok());
+            if let (true, Some(block)) = (failed, &transaction.transaction) {
+                failures
+                    .entry(*block.data.sender())
+                    .or_default()
+                    .push(transaction.digest.to_string());
+            }
+        }
+
+        let spam: HashSet<String> = failures
+            .into_values()
+            .filter(|digests| digests.len() >= self.failed_spam_min)
+            .flatten()
+            .collect();
+        for event in events
+            .iter_mut()
+            .filter(|event| spam.contains(&event.transaction_digest))
+        {
+            event.tags.push(HeuristicTag::FailedTxSpam);
+        }
+    }
+}
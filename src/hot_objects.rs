@@ -0,0 +1,210 @@
+use crate::transaction::SuiTransactionSource;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Duration, Instant};
+use sui_sdk::rpc_types::SuiTransactionBlockEffectsAPI;
+use sui_sdk::types::base_types::ObjectID;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotObject {
+    /// Shared object ID
+    pub object_id: String,
+    /// Transactions touching the object within the window
+    pub transactions: u64,
+    /// Transactions mutating the object within the window, contending for its lock
+    pub writes: u64,
+    /// Window start, chain time (milliseconds)
+    pub window_start: u64,
+    /// Window end, timestamp of the newest transaction counted (milliseconds)
+    pub window_end: u64,
+}
+
+/// Touches counted for one shared object
+#[derive(Default)]
+struct Touches {
+    transactions: u64,
+    writes: u64,
+}
+
+/// Source emitting the most touched shared objects over a sliding window
+///
+/// Shared objects are taken from the effects of the transactions polled by the
+/// inner source, so it must fetch effects. The window slides with the timestamp of
+/// the newest transaction seen, dropping older transactions as it moves. Every
+/// emission holds the `top_n` objects touched by the most transactions, ties broken
+/// by writes; polls before the emit interval elapsed yield None.
+pub struct SuiHotObjectSource {
+    /// Underlying transaction source
+    inner: SuiTransactionSource,
+    /// Length of the sliding window
+    window: Duration,
+    /// Objects emitted per record
+    top_n: usize,
+    /// Minimum time between emissions, every poll counting new transactions when zero
+    emit_interval: Duration,
+    /// Time of the last emission
+    last_emit: Option<Instant>,
+    /// Shared objects of the transactions within the window, by timestamp and digest,
+    /// with whether the transaction mutated them
+    touched: BTreeMap<(u64, String), Vec<(String, bool)>>,
+    /// Digests of the transactions within the window
+    seen: HashSet<String>,
+    /// Touches within the window by object ID
+    counts: HashMap<String, Touches>,
+    /// Timestamp of the newest transaction counted
+    newest: u64,
+}
+
+impl SuiHotObjectSource {
+    /// Creates a new SuiHotObjectSource
+    ///
+    /// # Parameters
+    /// * `inner` - Transaction source providing the effects
+    /// * `window` - Length of the sliding window
+    /// * `top_n` - Number of objects emitted per record
+    pub fn new(inner: SuiTransactionSource, window: Duration, top_n: usize) -> Self {
+        Self {
+            inner,
+            window,
+            top_n,
+            emit_interval: Duration::ZERO,
+            last_emit: None,
+            touched: BTreeMap::new(),
+            seen: HashSet::new(),
+            counts: HashMap::new(),
+            newest: 0,
+        }
+    }
+
+    /// Emits at most once per interval instead of after every poll with new transactions
+    pub fn with_emit_interval(mut self, emit_interval: Duration) -> Self {
+        self.emit_interval = emit_interval;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+
+    /// Drops the transactions that fell out of the window
+    fn evict(&mut self) {
+        let start = self.window_start();
+        while let Some(entry) = self.touched.first_entry() {
+            if entry.key().0 >= start {
+                break;
+            }
+            let ((_, digest), objects) = entry.remove_entry();
+            self.seen.remove(&digest);
+            for (object_id, mutated) in objects {
+                let Some(touches) = self.counts.get_mut(&object_id) else {
+                    continue;
+                };
+                touches.transactions -= 1;
+                touches.writes -= mutated as u64;
+                if touches.transactions == 0 {
+                    self.counts.remove(&object_id);
+                }
+            }
+        }
+    }
+
+    fn window_start(&self) -> u64 {
+        self.newest.saturating_sub(self.window.as_millis() as u64)
+    }
+}
+
+#[async_trait]
+impl Source<Vec<HotObject>> for SuiHotObjectSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<HotObject>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let mut counted = 0;
+        for transaction in record.data {
+            let Some(effects) = &transaction.effects else {
+                continue;
+            };
+            if effects.shared_objects().is_empty()
+                || !self.seen.insert(transaction.transaction_digest.clone())
+            {
+                continue;
+            }
+
+            let mutated: HashSet<ObjectID> = effects
+                .mutated()
+                .iter()
+                .map(|object| object.reference.object_id)
+                .collect();
+            let objects: Vec<(String, bool)> = effects
+                .shared_objects()
+                .iter()
+                .map(|object| {
+                    (
+                        object.object_id.to_string(),
+                        mutated.contains(&object.object_id),
+                    )
+                })
+                .collect();
+            for (object_id, mutated) in &objects {
+                let touches = self.counts.entry(object_id.clone()).or_default();
+                touches.transactions += 1;
+                touches.writes += *mutated as u64;
+            }
+            self.touched.insert(
+                (transaction.timestamp, transaction.transaction_digest),
+                objects,
+            );
+            self.newest = self.newest.max(transaction.timestamp);
+            counted += 1;
+        }
+        self.evict();
+
+        // Emit once new transactions were counted and the interval elapsed
+        if counted == 0
+            || self
+                .last_emit
+                .is_some_and(|last_emit| last_emit.elapsed() < self.emit_interval)
+        {
+            return Ok(None);
+        }
+        self.last_emit = Some(Instant::now());
+
+        let window_start = self.window_start();
+        let mut hot: Vec<HotObject> = self
+            .counts
+            .iter()
+            .map(|(object_id, touches)| HotObject {
+                object_id: object_id.clone(),
+                transactions: touches.transactions,
+                writes: touches.writes,
+                window_start,
+                window_end: self.newest,
+            })
+            .collect();
+        hot.sort_by(|a, b| {
+            b.transactions
+                .cmp(&a.transactions)
+                .then(b.writes.cmp(&a.writes))
+                .then_with(|| a.object_id.cmp(&b.object_id))
+        });
+        hot.truncate(self.top_n);
+        if hot.is_empty() {
+            tracing::info!("No shared objects within the window");
+            return Ok(None);
+        }
+
+        Ok(Some(Record::new(hot)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,214 @@
+use crate::cache::TtlCache;
+use crate::connection::SuiConnection;
+use crate::digest::DigestRecord;
+use crate::rpc;
+use crate::transaction::SuiEvent;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use futures::{StreamExt, TryStreamExt};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions};
+use sui_sdk::types::digests::TransactionDigest;
+use tokio::time::sleep;
+
+/// Default number of page requests in flight at once
+const DEFAULT_CONCURRENCY: usize = 4;
+/// Default number of retries of a failed page request
+const DEFAULT_RETRIES: u32 = 2;
+
+/// Fetches the full transactions of digest-only records
+///
+/// Digests are fetched in pages of the node's query limit, with up to
+/// `concurrency` pages in flight and failed pages retried. Hydrated transactions
+/// are cached for `cache_ttl`, so overlapping batches are fetched once. Clones
+/// share the cache.
+#[derive(Clone)]
+pub struct TxHydrator {
+    /// Sui client
+    client: SuiClient,
+    /// Data fetched per transaction
+    options: SuiTransactionBlockResponseOptions,
+    /// Maximum number of page requests in flight
+    concurrency: usize,
+    /// Retries of a failed page request
+    retries: u32,
+    /// Delay before each retry
+    retry_delay: Duration,
+    /// Time hydrated transactions stay cached, caching disabled when zero
+    cache_ttl: Duration,
+    /// Hydrated transactions by digest
+    cache: Arc<Mutex<TtlCache<String, SuiEvent>>>,
+}
+
+impl TxHydrator {
+    /// Creates a hydrator fetching input, effects, events and balance changes
+    pub fn new(connection: &SuiConnection) -> Self {
+        Self {
+            client: connection.client().clone(),
+            options: SuiTransactionBlockResponseOptions::new()
+                .with_input()
+                .with_effects()
+                .with_events()
+                .with_balance_changes(),
+            concurrency: DEFAULT_CONCURRENCY,
+            retries: DEFAULT_RETRIES,
+            retry_delay: Duration::from_millis(500),
+            cache_ttl: Duration::from_secs(300),
+            cache: Arc::new(Mutex::new(TtlCache::new())),
+        }
+    }
+
+    /// Sets the data fetched per transaction; the input is needed for conversion
+    pub fn with_options(mut self, options: SuiTransactionBlockResponseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the maximum number of page requests in flight, 4 by default
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets the retries of a failed page request and the delay before each, 2 and 500 ms by default
+    pub fn with_retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.retries = retries;
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Sets how long hydrated transactions stay cached, 5 minutes by default, zero to disable
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Fetches the transactions of the records, in the order of the records
+    ///
+    /// Transactions the node does not return or that fail conversion are left out
+    /// and logged; a page failing after all retries fails the call.
+    pub async fn hydrate(&self, records: &[DigestRecord]) -> StreamResult<Vec<SuiEvent>> {
+        let mut hydrated: HashMap<String, SuiEvent> = HashMap::new();
+        let mut missing = Vec::new();
+        {
+            let cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+            for record in records {
+                if let Some(event) = cache.get(&record.digest, self.cache_ttl) {
+                    hydrated.insert(record.digest.clone(), event);
+                    continue;
+                }
+                match TransactionDigest::from_str(&record.digest) {
+                    Ok(digest) => missing.push(digest),
+                    Err(e) => tracing::warn!("Invalid digest {}: {}", record.digest, e),
+                }
+            }
+        }
+
+        // Fetch the missing transactions page by page, a bounded number at once
+        let mut pages = futures::stream::iter(
+            missing
+                .chunks(rpc::QUERY_MAX_RESULT_LIMIT)
+                .map(|page| self.fetch(page.to_vec())),
+        )
+        .buffer_unordered(self.concurrency);
+        while let Some(responses) = pages.try_next().await? {
+            for response in responses {
+                match SuiEvent::try_from(&response) {
+                    Ok(event) => {
+                        hydrated.insert(event.transaction_digest.clone(), event);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to hydrate transaction {}: {}", response.digest, e)
+                    }
+                }
+            }
+        }
+
+        if !self.cache_ttl.is_zero() {
+            let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+            for (digest, event) in &hydrated {
+                cache.insert(digest.clone(), event.clone(), self.cache_ttl);
+            }
+        }
+
+        Ok(records
+            .iter()
+            .filter_map(|record| hydrated.remove(&record.digest))
+            .collect())
+    }
+
+    /// Fetches one page of transactions, retrying failures
+    async fn fetch(
+        &self,
+        digests: Vec<TransactionDigest>,
+    ) -> StreamResult<Vec<SuiTransactionBlockResponse>> {
+        let mut attempt = 0;
+        loop {
+            let result = rpc::call(
+                "TxHydrator",
+                "sui_multiGetTransactionBlocks",
+                "hydrate transactions",
+                self.client
+                    .read_api()
+                    .multi_get_transactions_with_options(digests.clone(), self.options.clone()),
+            )
+            .await;
+            match result {
+                Err(_) if attempt < self.retries => {
+                    attempt += 1;
+                    sleep(self.retry_delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Source hydrating the digest records of an inner source
+///
+/// Runs hydration as its own stage, so the digest source can keep tailing at
+/// full rate, e.g. behind a `SharedSuiSource`, while this stage fetches the
+/// heavy transaction data.
+pub struct SuiHydratedSource<S> {
+    /// Source of digest records
+    inner: S,
+    /// Hydrator fetching the transactions
+    hydrator: TxHydrator,
+}
+
+impl<S> SuiHydratedSource<S> {
+    /// Creates a new SuiHydratedSource
+    pub fn new(inner: S, hydrator: TxHydrator) -> Self {
+        Self { inner, hydrator }
+    }
+}
+
+#[async_trait]
+impl<S> Source<Vec<SuiEvent>> for SuiHydratedSource<S>
+where
+    S: Source<Vec<DigestRecord>> + Send + Sync,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<SuiEvent>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+        let events = self.hydrator.hydrate(&record.data).await?;
+        if events.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Record::new(events)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+use sui_sdk::types::base_types::ObjectID;
+
+/// Strings kept by the interner, beyond which new strings are allocated unshared
+///
+/// Package IDs, module names and type names seen on a network number in the tens
+/// of thousands at most, so the cap is only reached by unbounded inputs such as
+/// type parameters built from arbitrary values.
+const MAX_INTERNED: usize = 65_536;
+
+/// Returns the shared copy of an object ID in its canonical `0x`-prefixed form
+///
+/// The ID is formatted on the stack, so a package seen before is looked up
+/// without the allocation of `to_string`.
+pub(crate) fn intern_id(id: ObjectID) -> Arc<str> {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut formatted = [0u8; 2 + 2 * ObjectID::LENGTH];
+    formatted[..2].copy_from_slice(b"0x");
+    for (i, byte) in id.into_bytes().iter().enumerate() {
+        formatted[2 + 2 * i] = DIGITS[(byte >> 4) as usize];
+        formatted[3 + 2 * i] = DIGITS[(byte & 0x0f) as usize];
+    }
+    intern(std::str::from_utf8(&formatted).unwrap_or_default())
+}
+
+/// Returns the shared copy of a string, allocating it on first sight
+///
+/// Records converted from head traffic repeat the same package, module and type
+/// identifiers, which then point to one allocation instead of one per record.
+/// Lookups of strings already interned take a shared read lock and allocate
+/// nothing, though callers that format the string first still allocate for it.
+pub(crate) fn intern(value: &str) -> Arc<str> {
+    static INTERNED: OnceLock<RwLock<HashSet<Arc<str>>>> = OnceLock::new();
+    let interned = INTERNED.get_or_init(RwLock::default);
+
+    if let Some(shared) = interned
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(value)
+    {
+        return shared.clone();
+    }
+
+    let mut interned = interned.write().unwrap_or_else(PoisonError::into_inner);
+    if let Some(shared) = interned.get(value) {
+        return shared.clone();
+    }
+    let shared: Arc<str> = Arc::from(value);
+    if interned.len() < MAX_INTERNED {
+        interned.insert(shared.clone());
+    }
+    shared
+}
@@ -0,0 +1,140 @@
+use crate::conversion::DeadLetter;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Failure emitted into the stream in place of the items it cost
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum SourceItemError {
+    /// An item could not be converted, carrying the same letter as the dead-letter channel
+    Decode(DeadLetter),
+    /// A poll failed, so the items it would have fetched are missing
+    Fetch {
+        /// Name of the source whose poll failed
+        source: String,
+        /// Error of the poll
+        message: String,
+    },
+}
+
+impl fmt::Display for SourceItemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceItemError::Decode(letter) => write!(
+                f,
+                "{} could not convert {}: {}",
+                letter.source, letter.item_id, letter.error
+            ),
+            SourceItemError::Fetch { source, message } => {
+                write!(f, "{} poll failed: {}", source, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SourceItemError {}
+
+/// Item of a stream emitting failures as records
+pub type ItemResult<T> = Result<T, SourceItemError>;
+
+/// Sources that can report failed items instead of only logging them
+pub trait ItemErrors {
+    /// Name of the source, used for the failures of whole polls
+    fn source_name(&self) -> &'static str;
+
+    /// Starts recording failed items
+    fn enable_item_errors(&mut self);
+
+    /// Takes the failures recorded since the last call
+    fn drain_item_errors(&mut self) -> Vec<SourceItemError>;
+}
+
+/// Failures pending emission
+#[derive(Default)]
+pub(crate) struct ItemErrorQueue {
+    /// Whether failures are recorded
+    enabled: bool,
+    /// Failures not emitted yet
+    pending: Vec<SourceItemError>,
+}
+
+impl ItemErrorQueue {
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    pub(crate) fn drain(&mut self) -> Vec<SourceItemError> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Records an item dropped on conversion
+    pub(crate) fn decode_failed(&mut self, letter: &DeadLetter) {
+        if self.enabled {
+            self.pending.push(SourceItemError::Decode(letter.clone()));
+        }
+    }
+
+    /// Records a poll skipped after failed requests
+    pub(crate) fn fetch_failed(&mut self, source: &str, error: &StreamError) {
+        if self.enabled {
+            self.pending.push(SourceItemError::Fetch {
+                source: source.to_string(),
+                message: error.to_string(),
+            });
+        }
+    }
+}
+
+/// Source emitting decode and fetch failures as error records next to the data
+///
+/// Items that fail conversion are emitted as `SourceItemError::Decode` after the
+/// data of their poll, as well as being routed to the dead-letter channel. Polls
+/// skipped by the RPC error policy are emitted as `SourceItemError::Fetch`, and so
+/// are errors returned by the inner source, which would otherwise end the stream:
+/// the adapter returns them as records and keeps polling.
+pub struct ItemResultSource<S> {
+    /// Underlying source
+    inner: S,
+}
+
+impl<S: ItemErrors> ItemResultSource<S> {
+    /// Creates a new ItemResultSource, enabling failure records on the inner source
+    pub fn new(mut inner: S) -> Self {
+        inner.enable_item_errors();
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<S, T> Source<Vec<ItemResult<T>>> for ItemResultSource<S>
+where
+    S: Source<Vec<T>> + ItemErrors + Send + Sync,
+    T: Send + Sync + 'static,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<ItemResult<T>>>>> {
+        let mut items: Vec<ItemResult<T>> = match self.inner.next().await {
+            Ok(Some(record)) => record.data.into_iter().map(Ok).collect(),
+            Ok(None) => Vec::new(),
+            Err(e) => vec![Err(SourceItemError::Fetch {
+                source: self.inner.source_name().to_string(),
+                message: e.to_string(),
+            })],
+        };
+        items.extend(self.inner.drain_item_errors().into_iter().map(Err));
+
+        if items.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Record::new(items)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// One-off delay before the first poll, spreading replicas that start together
+#[derive(Clone, Debug, Default)]
+pub(crate) struct StartDelay {
+    /// Upper bound of the random part of the delay
+    pub(crate) jitter: Duration,
+    /// Fixed part of the delay
+    pub(crate) phase_offset: Duration,
+    /// Whether the delay has been applied
+    applied: bool,
+}
+
+impl StartDelay {
+    /// Sleeps for the phase offset plus a random jitter, once
+    pub(crate) async fn wait(&mut self) {
+        if self.applied {
+            return;
+        }
+        self.applied = true;
+
+        let delay = self.phase_offset + self.jitter.mul_f64(random_fraction());
+        if !delay.is_zero() {
+            tracing::debug!("Delaying first poll by {:?}", delay);
+            sleep(delay).await;
+        }
+    }
+}
+
+/// Returns a random value in `[0, 1)` from the per-process randomized hasher seed
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
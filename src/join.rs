@@ -0,0 +1,68 @@
+use crate::event::ChainEvent;
+use crate::transaction::{SuiEvent, SuiTransactionSource};
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransactionWithEvents {
+    /// Parent transaction, its `events` moved into `events`
+    pub transaction: SuiEvent,
+    /// Events emitted by the transaction, ordered by sequence number
+    pub events: Vec<ChainEvent>,
+}
+
+/// Source attaching events to their parent transaction
+///
+/// Events are taken from the transaction responses themselves rather than joined
+/// against a separate event source, so every transaction carries exactly the
+/// events it emitted regardless of polling timing, and no second request is made.
+/// Transactions keep the order of the inner source. Effects-only sources emit no
+/// events.
+pub struct TxEventJoiner {
+    /// Underlying transaction source
+    inner: SuiTransactionSource,
+}
+
+impl TxEventJoiner {
+    /// Creates a new TxEventJoiner wrapping a transaction source
+    pub fn new(inner: SuiTransactionSource) -> Self {
+        Self { inner }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl Source<Vec<TransactionWithEvents>> for TxEventJoiner {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<TransactionWithEvents>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let joined = record
+            .data
+            .into_iter()
+            .map(|mut transaction| {
+                let events = std::mem::take(&mut transaction.events);
+                TransactionWithEvents {
+                    transaction,
+                    events,
+                }
+            })
+            .collect();
+
+        Ok(Some(Record::new(joined)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,297 @@
+use crate::event::{ChainEvent, SuiEventSource};
+use crate::swaps::normalize_coin_type;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::str::FromStr;
+use sui_sdk::types::base_types::ObjectID;
+
+/// Money market whose position events are decoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LendingProtocol {
+    /// Scallop lending market
+    Scallop,
+    /// NAVI lending pools
+    Navi,
+    /// Suilend main market
+    Suilend,
+}
+
+/// Kind of position change
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LendingActionKind {
+    /// Collateral or liquidity supplied
+    Deposit,
+    /// Debt taken
+    Borrow,
+    /// Debt paid back
+    Repay,
+    /// Debt repaid by a liquidator in exchange for collateral
+    Liquidation,
+}
+
+impl LendingProtocol {
+    /// Every supported protocol
+    pub const ALL: [LendingProtocol; 3] = [
+        LendingProtocol::Scallop,
+        LendingProtocol::Navi,
+        LendingProtocol::Suilend,
+    ];
+
+    /// Mainnet package defining the protocol's position events
+    pub fn package(&self) -> &'static str {
+        match self {
+            LendingProtocol::Scallop => {
+                "0xefe8b36d5b2e43728cc323298626b83177803521d195cfb11e15b910e892fddf"
+            }
+            LendingProtocol::Navi => {
+                "0xd899cf7d2b5db716bd2cf55599fb0d5ee38a3061e7b6bb6eebf73fa5bc4c81ca"
+            }
+            LendingProtocol::Suilend => {
+                "0xf95b06141ed4a174f239417323bde3f209b972f5930d8521ea38a52aff3a6ddf"
+            }
+        }
+    }
+
+    /// Move event types of the protocol's position changes, e.g. for an event filter
+    pub fn event_types(&self) -> Vec<String> {
+        self.events()
+            .iter()
+            .map(|(module, name, _)| format!("{}::{}::{}", self.package(), module, name))
+            .collect()
+    }
+
+    /// Modules, struct names and kinds of the protocol's position events
+    fn events(&self) -> &'static [(&'static str, &'static str, LendingActionKind)] {
+        use LendingActionKind::*;
+        match self {
+            LendingProtocol::Scallop => &[
+                ("deposit_collateral", "CollateralDepositEvent", Deposit),
+                ("mint", "MintEvent", Deposit),
+                ("borrow", "BorrowEvent", Borrow),
+                ("borrow", "BorrowEventV2", Borrow),
+                ("borrow", "BorrowEventV3", Borrow),
+                ("repay", "RepayEvent", Repay),
+                ("liquidate", "LiquidateEvent", Liquidation),
+                ("liquidate", "LiquidateEventV2", Liquidation),
+            ],
+            LendingProtocol::Navi => &[
+                ("lending", "DepositEvent", Deposit),
+                ("lending", "BorrowEvent", Borrow),
+                ("lending", "RepayEvent", Repay),
+                ("lending", "LiquidationCallEvent", Liquidation),
+            ],
+            LendingProtocol::Suilend => &[
+                ("lending_market", "DepositEvent", Deposit),
+                ("lending_market", "BorrowEvent", Borrow),
+                ("lending_market", "RepayEvent", Repay),
+                ("lending_market", "LiquidateEvent", Liquidation),
+            ],
+        }
+    }
+
+    /// Protocol and kind of an event, None if it is not a position event of a supported protocol
+    fn of(event: &ChainEvent) -> Option<(Self, LendingActionKind)> {
+        let package = ObjectID::from_str(&event.event_type.module.package).ok()?;
+        let protocol = Self::ALL.into_iter().find(|protocol| {
+            ObjectID::from_str(protocol.package()).is_ok_and(|id| id == package)
+        })?;
+        protocol
+            .events()
+            .iter()
+            .find(|(module, name, _)| {
+                *module == &*event.event_type.module.module && *name == &*event.event_type.name
+            })
+            .map(|(_, _, kind)| (protocol, *kind))
+    }
+}
+
+/// Position change normalized across money markets
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LendingAction {
+    /// Protocol of the market
+    pub protocol: LendingProtocol,
+    /// Kind of position change
+    pub kind: LendingActionKind,
+    /// Position changed: the obligation ID, or the user's address on markets
+    /// without obligations
+    pub account: String,
+    /// Coin type deposited, borrowed or repaid, None if the event only names a
+    /// reserve index
+    pub coin_type: Option<String>,
+    /// Amount deposited, borrowed or repaid, in the smallest unit of `coin_type`
+    pub amount: u128,
+    /// Collateral coin type seized by a liquidation, None for other kinds or if
+    /// the event does not name it
+    pub collateral_type: Option<String>,
+    /// Collateral amount seized by a liquidation, None for other kinds or if the
+    /// event does not report it
+    pub collateral_amount: Option<u128>,
+    /// Liquidator address, None for other kinds
+    pub liquidator: Option<String>,
+    /// Sender of the transaction
+    pub sender: String,
+    /// Digest of the transaction
+    pub transaction_digest: String,
+    /// Timestamp (milliseconds)
+    pub timestamp: u64,
+}
+
+impl LendingAction {
+    /// Normalizes a position event, None if it is not a position event of a
+    /// supported protocol or lacks an amount
+    ///
+    /// Fields are read by their common names across the protocols' event versions.
+    /// Coin types given as Move `TypeName`s are normalized to canonical types with
+    /// a `0x` prefix.
+    pub fn from_event(event: &ChainEvent) -> Option<Self> {
+        let (protocol, kind) = LendingProtocol::of(event)?;
+        let json = &event.parsed_json;
+        let liquidation = kind == LendingActionKind::Liquidation;
+
+        Some(LendingAction {
+            protocol,
+            kind,
+            account: string_field(
+                json,
+                &[
+                    "obligation",
+                    "obligation_id",
+                    "liquidate_user",
+                    "borrower",
+                    "repayer",
+                    "provider",
+                    "minter",
+                    "sender",
+                ],
+            )
+            .unwrap_or_else(|| event.sender.clone()),
+            coin_type: type_field(
+                json,
+                &[
+                    "coin_type",
+                    "repay_coin_type",
+                    "debt_type",
+                    "asset",
+                    "deposit_asset",
+                ],
+            ),
+            amount: amount_field(
+                json,
+                &[
+                    "amount",
+                    "deposit_amount",
+                    "liquidity_amount",
+                    "repay_amount",
+                    "repay_on_behalf",
+                    "liquidate_amount",
+                    "ctoken_amount",
+                ],
+            )?,
+            collateral_type: liquidation
+                .then(|| type_field(json, &["collateral_type", "withdraw_coin_type"]))
+                .flatten(),
+            collateral_amount: liquidation
+                .then(|| amount_field(json, &["liq_amount", "withdraw_amount"]))
+                .flatten(),
+            liquidator: liquidation.then(|| {
+                string_field(json, &["liquidator"]).unwrap_or_else(|| event.sender.clone())
+            }),
+            sender: event.sender.clone(),
+            transaction_digest: event.id.tx_digest.to_string(),
+            timestamp: event.timestamp,
+        })
+    }
+}
+
+/// Reads the first present field as a string, unwrapping `{ "id": .. }` wrappers
+fn string_field(json: &Value, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| match json.get(name)? {
+        Value::String(value) => Some(value.clone()),
+        Value::Object(object) => object.get("id")?.as_str().map(str::to_string),
+        _ => None,
+    })
+}
+
+/// Reads the first present coin type, given as a string or a `{ "name": .. }` `TypeName`
+fn type_field(json: &Value, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| {
+        let value = json.get(name)?;
+        let coin_type = value.as_str().or_else(|| value.get("name")?.as_str())?;
+        Some(normalize_coin_type(coin_type))
+    })
+}
+
+/// Reads the first present amount, encoded as a JSON string as Move integers are, or a number
+fn amount_field(json: &Value, names: &[&str]) -> Option<u128> {
+    names.iter().find_map(|name| match json.get(name)? {
+        Value::String(amount) => amount.parse().ok(),
+        Value::Number(amount) => amount.as_u64().map(u128::from),
+        _ => None,
+    })
+}
+
+/// Source emitting normalized position changes of the enabled money markets
+///
+/// Events of the inner source are kept when they are position events of an
+/// enabled protocol; restrict the inner source with a `with_query` filter on the
+/// protocols' `event_types` to avoid fetching unrelated events.
+pub struct SuiLendingSource {
+    /// Underlying event source
+    inner: SuiEventSource,
+    /// Protocols whose position changes are emitted
+    protocols: HashSet<LendingProtocol>,
+}
+
+impl SuiLendingSource {
+    /// Creates a new SuiLendingSource decoding every supported protocol
+    pub fn new(inner: SuiEventSource) -> Self {
+        Self {
+            inner,
+            protocols: LendingProtocol::ALL.into_iter().collect(),
+        }
+    }
+
+    /// Restricts the emitted position changes to the given protocols
+    pub fn with_protocols(mut self, protocols: impl IntoIterator<Item = LendingProtocol>) -> Self {
+        self.protocols = protocols.into_iter().collect();
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl Source<Vec<LendingAction>> for SuiLendingSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<LendingAction>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let actions: Vec<LendingAction> = record
+            .data
+            .iter()
+            .filter_map(LendingAction::from_event)
+            .filter(|action| self.protocols.contains(&action.protocol))
+            .collect();
+        if actions.is_empty() {
+            tracing::info!("No lending events found");
+            return Ok(None);
+        }
+
+        Ok(Some(Record::new(actions)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -7,9 +7,29 @@
 //!
 //! - **Sui Network Integration**: Direct connection to Sui blockchain networks (Mainnet, Testnet, Devnet).
 //! - **Transaction Streaming**: Real-time streaming of Sui blockchain transactions with configurable batch sizes.
-//! - **Event Monitoring**: Real-time streaming of Sui blockchain events.
+//! - **Event Monitoring**: Real-time streaming of Sui blockchain events, polled or pushed over WebSocket subscriptions.
+//! - **Checkpoint Streaming**: Whole checkpoints in sequence order, for building indexers.
+//! - **DEX Swaps**: Cetus, Turbos, and Aftermath swap events normalized into one `Swap` stream.
+//! - **Lending Positions**: Scallop, NAVI, and Suilend deposits, borrows, repayments, and liquidations as typed records.
+//! - **Typed Move Events**: Decode registered Move event types into Rust structs.
 //! - **Object Tracking**: Monitor changes to Sui objects owned by specific addresses.
-//! - **Flexible Configuration**: Customizable polling intervals and batch sizes.
+//! - **Object History**: Walk the version lineage of objects through the past object API.
+//! - **Dynamic Fields**: Added, changed, and removed dynamic fields of a parent object such as a `Table` or `Bag`.
+//! - **Portfolio Snapshots**: Periodic coin, staking, and NFT summaries for watched wallets.
+//! - **Balance Monitoring**: Balance changes of watched addresses per coin type, with old and new values.
+//! - **Coin Tracking**: Coin objects of an address and coin type, with symbol and decimals from the coin metadata.
+//! - **Transaction Submission**: Submit a signed transaction and stream its lifecycle (`submit` feature).
+//! - **Ready-Made Pipelines**: One call builds a windowed stream of transaction, event, object, or transfer volume aggregates.
+//! - **Flexible Configuration**: Customizable polling intervals, batch sizes, and emit order.
+//! - **Cursor Persistence**: Resume after restarts from a pluggable cursor store, files included.
+//! - **Error Records**: Decode and fetch failures emitted into the stream as `ItemResult` errors, without ending it.
+//! - **Shared Connections**: One client per endpoint, kept warm through idle periods.
+//! - **Shared Rate Limiting**: A process-wide scheduler keeps fleets of sources within one provider budget.
+//! - **Fullnode Health**: Estimate node lag and clock skew from checkpoint timestamps.
+//! - **Network Telemetry**: TPS, checkpoint rate, and checkpoint fullness per window from checkpoint headers.
+//! - **Epoch Tracking**: One record per epoch boundary with gas price and validator set changes.
+//! - **Package Publishes**: Newly published and upgraded packages with their modules and dependencies.
+//! - **Poll Profiling**: Fetch, decode, and emit timings of every poll (`profiling` feature).
 //! - **Efficient Data Processing**: Optimized for handling high-throughput blockchain data streams.
 //! - **Seamless Framework Integration**: Built for smooth integration with the Fluxus data processing framework.
 //!
@@ -54,11 +74,151 @@
 //!     }
 //! }
 //! ```
+//!
+//! ## Shared Source Options
+//!
+//! The transaction, event and object sources share these builders, some of them
+//! also found on other sources:
+//!
+//! - `with_start_jitter` delays the first poll by a random duration, so replicas
+//!   started together drift apart instead of polling in lockstep.
+//! - `with_enrichment_deadline` bounds the time enrichers may delay a poll. Lookups
+//!   still in flight at the deadline are cancelled and counted as enrichment
+//!   errors, and their records are emitted without the values.
+//! - `with_node_page_limit`, also on the digest source, sets the largest page the
+//!   node serves, 50 by default as on standard fullnodes. Nodes do not publish
+//!   their limit, so one configured with a different `QUERY_MAX_RESULT_LIMIT` has
+//!   to be told, and page sizes above it are lowered at init.
+//! - `with_health_reference`, on the transaction and event sources only, compares the
+//!   fullnode with a second endpoint to separate its lag from local clock skew, see
+//!   `NodeHealth`. It applies to the check enabled by `with_health_check`, so it is
+//!   called afterwards.
 
+mod abort;
+mod balance;
+mod balances;
+mod batch;
+mod bloom;
+mod cache;
+mod calls;
+mod chain;
+mod checkpoint;
+mod coin;
+mod compact;
+mod connection;
+mod control;
+mod conversion;
+mod cursor;
+mod diff;
+mod digest;
+mod dynamic_field;
+mod enrich;
+mod epoch;
+mod error;
 mod event;
+mod failures;
+mod governance;
+mod handle;
+mod health;
+mod heuristics;
+mod hot_objects;
+mod hydrate;
+mod intern;
+mod item_result;
+mod jitter;
+mod join;
+mod lending;
+mod move_type;
+mod notify;
 mod object;
+mod object_change;
+mod object_history;
+mod object_summary;
+mod order;
+mod package;
+mod pause;
+pub mod pipelines;
+mod policy;
+mod pool;
+mod portfolio;
+mod profile;
+mod redaction;
+mod registry;
+mod rpc;
+mod scheduler;
+mod shared;
+mod stats;
+#[cfg(feature = "submit")]
+mod submit;
+mod subscription;
+mod swaps;
+mod taxonomy;
+mod telemetry;
 mod transaction;
+mod typed;
+mod units;
+mod volume;
+mod window;
 
-pub use event::{ChainEvent, SuiEventSource};
-pub use object::{ChainObject, SuiObjectSource};
+pub use abort::{AbortLocation, MoveAbort};
+pub use balance::BalanceChange;
+pub use balances::{BalanceUpdate, SuiBalanceSource};
+pub use batch::batch_digest;
+pub use bloom::DigestFilter;
+pub use calls::{CallCount, SuiCallFrequencySource};
+pub use chain::ChainMetadata;
+pub use checkpoint::{ChainCheckpoint, SuiCheckpointSource};
+pub use coin::{CoinRecord, SuiCoinSource};
+pub use compact::{CompactSuiEvent, CompactSuiTransactionSource};
+pub use connection::SuiConnection;
+pub use control::{ControlEvent, ControlEvents, ControlledSource, StreamItem};
+pub use conversion::{
+    ConversionError, DeadLetter, DeadLetterReceiver, DeadLetterSender, dead_letter_channel,
+};
+pub use cursor::{AsyncCursorStore, CursorStore, FileCursorStore};
+pub use diff::{FieldChange, content_diff};
+pub use digest::{DigestRecord, SuiDigestSource};
+pub use dynamic_field::{DynamicFieldChangeKind, DynamicFieldEntry, SuiDynamicFieldSource};
+pub use enrich::Enricher;
+pub use epoch::{CommitteeMember, EpochRecord, StakeChange, SuiEpochSource, ValidatorInfo};
+pub use error::SuiSourceError;
+pub use event::{ChainEvent, EventDelivery, SuiEventSource};
+pub use failures::{PackageFailureRate, SuiFailureRateSource};
+pub use governance::{GovernanceRecord, Proposal, SuiGovernanceSource, Vote};
+pub use handle::SourceHandle;
+pub use health::NodeHealth;
+pub use heuristics::{HeuristicTag, HeuristicsConfig};
+pub use hot_objects::{HotObject, SuiHotObjectSource};
+pub use hydrate::{SuiHydratedSource, TxHydrator};
+pub use item_result::{ItemErrors, ItemResult, ItemResultSource, SourceItemError};
+pub use join::{TransactionWithEvents, TxEventJoiner};
+pub use lending::{LendingAction, LendingActionKind, LendingProtocol, SuiLendingSource};
+pub use move_type::{MoveModulePath, MoveTypeTag};
+pub use notify::NotificationRegistry;
+pub use object::{ChainObject, ObjectTimestamp, SnapshotMode, SuiObjectSource};
+pub use object_change::{ObjectBatch, ObjectChange, SuiObjectChangeSource};
+pub use object_history::{ObjectVersion, SuiObjectHistorySource};
+pub use object_summary::{ObjectHydrator, ObjectSummary, SuiObjectSummarySource};
+pub use order::EmitOrder;
+pub use package::{PackagePublish, SuiPackageSource};
+pub use pause::PauseHandle;
+pub use policy::{ErrorPolicy, RetryConfig};
+pub use pool::EndpointPool;
+pub use portfolio::{PortfolioSnapshot, SuiPortfolioSource};
+pub use redaction::{Redact, Redaction, RedactionConfig};
+pub use registry::{DecodedEvent, DecodedEventSource, MoveEventRegistry};
+pub use rpc::next_correlation_id;
+pub use scheduler::{Priority, SuiScheduler};
+pub use shared::{SharedSubscription, SharedSuiSource};
+pub use stats::SourceStats;
+#[cfg(feature = "submit")]
+pub use submit::{LifecycleState, SuiSubmissionSource, TransactionSigner, submit};
+pub use swaps::{SuiSwapSource, Swap, SwapProtocol};
+pub use taxonomy::{ObjectCategory, ObjectClassifier};
+pub use telemetry::{NetworkTelemetry, SuiTelemetrySource};
 pub use transaction::{SuiEvent, SuiTransactionSource};
+pub use typed::TypedEventSource;
+pub use units::{
+    MIST_PER_SUI, SUI_DECIMALS, format_amount, mist_to_sui, parse_amount, sui_to_mist,
+};
+pub use volume::{CoinVolume, SuiVolumeSource};
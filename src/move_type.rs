@@ -0,0 +1,131 @@
+use crate::conversion::ConversionError;
+use crate::intern::{intern, intern_id};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use sui_sdk::types::base_types::ObjectID;
+use sui_sdk::types::parse_sui_struct_tag;
+
+/// Move module, identified by its package ID and module name
+///
+/// Package IDs are kept in their canonical `0x`-prefixed 64-digit form, so short and
+/// long forms of the same package compare equal. Serialized as `<package>::<module>`.
+/// Both parts are interned, so paths of the same module share their strings.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MoveModulePath {
+    /// Package ID, canonical form
+    pub package: Arc<str>,
+    /// Module name
+    pub module: Arc<str>,
+}
+
+impl MoveModulePath {
+    /// Creates a module path, normalizing the package ID
+    pub fn new(package: &str, module: &str) -> Result<Self, ConversionError> {
+        let package = ObjectID::from_str(package).map_err(|e| ConversionError::InvalidField {
+            field: "package".to_string(),
+            reason: e.to_string(),
+        })?;
+        Ok(Self {
+            package: intern_id(package),
+            module: intern(module),
+        })
+    }
+}
+
+impl fmt::Display for MoveModulePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.package, self.module)
+    }
+}
+
+impl FromStr for MoveModulePath {
+    type Err = ConversionError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let (package, module) =
+            path.split_once("::")
+                .ok_or_else(|| ConversionError::InvalidField {
+                    field: "module".to_string(),
+                    reason: format!("{} is not of the form <package>::<module>", path),
+                })?;
+        Self::new(package, module)
+    }
+}
+
+/// Move struct type, e.g. the type of an event
+///
+/// Parsed from and serialized as its canonical string,
+/// `<package>::<module>::<name><type params>`, with every address in long form.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MoveTypeTag {
+    /// Module defining the type
+    pub module: MoveModulePath,
+    /// Struct name, interned
+    pub name: Arc<str>,
+    /// Type parameters, canonical form, interned once formatted
+    pub type_params: Vec<Arc<str>>,
+}
+
+impl MoveTypeTag {
+    /// Returns the type without its type parameters, e.g. to match every `Pool<T>`
+    pub fn base(&self) -> String {
+        format!("{}::{}", self.module, self.name)
+    }
+}
+
+impl fmt::Display for MoveTypeTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.module, self.name)?;
+        if !self.type_params.is_empty() {
+            write!(f, "<{}>", self.type_params.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MoveTypeTag {
+    type Err = ConversionError;
+
+    fn from_str(type_tag: &str) -> Result<Self, Self::Err> {
+        let tag = parse_sui_struct_tag(type_tag).map_err(|e| ConversionError::InvalidField {
+            field: "type".to_string(),
+            reason: e.to_string(),
+        })?;
+        Ok(Self {
+            module: MoveModulePath {
+                package: intern_id(ObjectID::from(tag.address)),
+                module: intern(tag.module.as_str()),
+            },
+            name: intern(tag.name.as_str()),
+            type_params: tag
+                .type_params
+                .iter()
+                .map(|param| intern(&param.to_canonical_string(true)))
+                .collect(),
+        })
+    }
+}
+
+/// Serializes a value as its display string and parses it back
+macro_rules! string_serde {
+    ($type:ty) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+string_serde!(MoveModulePath);
+string_serde!(MoveTypeTag);
@@ -0,0 +1,93 @@
+use crate::object::ChainObject;
+use crate::transaction::SuiEvent;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, PoisonError};
+use sui_sdk::types::base_types::SuiAddress;
+
+type ObjectCallback = Box<dyn Fn(&ChainObject) + Send + Sync>;
+type TransferCallback = Box<dyn Fn(&SuiEvent) + Send + Sync>;
+
+/// Callbacks registered so far
+#[derive(Default)]
+struct Callbacks {
+    /// Object callbacks by owner address
+    objects: Vec<(SuiAddress, ObjectCallback)>,
+    /// Transfer callbacks by address
+    transfers: Vec<(SuiAddress, TransferCallback)>,
+}
+
+/// Per-address callbacks invoked by sources alongside the records they emit
+///
+/// Meant for lightweight side effects such as push notifications. Clones share
+/// the same callbacks, so an application can keep registering after handing a
+/// clone to a source. Callbacks run synchronously within `next`, before
+/// redaction, so they see full addresses and should return quickly.
+#[derive(Clone, Default)]
+pub struct NotificationRegistry {
+    callbacks: Arc<Mutex<Callbacks>>,
+}
+
+impl NotificationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `callback` for every new version of an object owned by `address`
+    pub fn on_object_change<F>(&self, address: SuiAddress, callback: F)
+    where
+        F: Fn(&ChainObject) + Send + Sync + 'static,
+    {
+        self.lock().objects.push((address, Box::new(callback)));
+    }
+
+    /// Calls `callback` for every transaction changing a balance of `address`
+    pub fn on_transfer<F>(&self, address: SuiAddress, callback: F)
+    where
+        F: Fn(&SuiEvent) + Send + Sync + 'static,
+    {
+        self.lock().transfers.push((address, Box::new(callback)));
+    }
+
+    pub(crate) fn notify_objects(&self, objects: &[ChainObject]) {
+        let callbacks = self.lock();
+        if callbacks.objects.is_empty() {
+            return;
+        }
+        for object in objects {
+            let Ok(owner) = SuiAddress::from_str(&object.owner) else {
+                continue;
+            };
+            for (address, callback) in &callbacks.objects {
+                if *address == owner {
+                    callback(object);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn notify_transfers(&self, transactions: &[SuiEvent]) {
+        let callbacks = self.lock();
+        if callbacks.transfers.is_empty() {
+            return;
+        }
+        for transaction in transactions {
+            let owners: Vec<SuiAddress> = transaction
+                .balance_changes
+                .iter()
+                .filter(|change| change.amount != 0)
+                .filter_map(|change| SuiAddress::from_str(&change.owner).ok())
+                .collect();
+            for (address, callback) in &callbacks.transfers {
+                if owners.contains(address) {
+                    callback(transaction);
+                }
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Callbacks> {
+        self.callbacks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
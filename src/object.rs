@@ -1,12 +1,41 @@
+use crate::batch::BatchSplitter;
+use crate::cache::TtlCache;
+use crate::chain::{ChainMetadata, network_name};
+use crate::connection::SuiConnection;
+use crate::control::{ControlEvent, ControlEvents, ControlQueue, ControlledSource};
+use crate::conversion::{ConversionError, DeadLetter, DeadLetterSender, route_dead_letter};
+use crate::cursor::{AsyncCursorStore, CursorSlot, CursorStore};
+use crate::diff::{ContentCache, FieldChange, content_diff};
+use crate::enrich::{Enricher, EnrichmentChain};
+use crate::error::is_transient;
+use crate::item_result::{ItemErrorQueue, ItemErrors, ItemResultSource, SourceItemError};
+use crate::jitter::StartDelay;
+use crate::notify::NotificationRegistry;
+use crate::object_change::SuiObjectChangeSource;
+use crate::object_summary::SuiObjectSummarySource;
+use crate::policy::{ErrorPolicy, Recovery, RetryConfig};
+use crate::pool::EndpointPool;
+use crate::profile::{Phase, PhaseTimer};
+use crate::redaction::{Redact, RedactionConfig};
+use crate::rpc;
+use crate::scheduler::{Priority, SuiScheduler};
+use crate::stats::{SourceStats, StatsRecorder};
+use crate::taxonomy::{ObjectCategory, ObjectClassifier};
 use async_trait::async_trait;
 use fluxus::sources::Source;
 use fluxus::utils::models::{Record, StreamError, StreamResult};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::str::FromStr;
-use std::time::Duration;
-use sui_sdk::rpc_types::{SuiObjectData, SuiObjectDataOptions, SuiObjectResponseQuery};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sui_sdk::rpc_types::{
+    ObjectsPage, SuiObjectData, SuiObjectDataOptions, SuiObjectResponseQuery,
+    SuiTransactionBlockResponseOptions,
+};
 use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::object::Owner;
 use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
 use tokio::time::sleep;
 
@@ -16,16 +45,54 @@ pub struct ChainObject {
     pub id: String,
     /// Object type
     pub object_type: String,
-    /// Owner address
+    /// Owner address, the owning object's ID for objects owned by objects
     pub owner: String,
+    /// Owner as reported by the node: address, object, shared or immutable
+    pub owner_kind: Option<Owner>,
     /// Object version
     pub version: u64,
     /// Object data
     pub data: SuiObjectData,
     /// Last transaction digest
     pub last_transaction_digest: String,
+    /// Timestamp of the object version (milliseconds), as selected by the source's
+    /// `ObjectTimestamp`
+    pub timestamp: u64,
+    /// Network the object was read from
+    pub chain: Option<ChainMetadata>,
+    /// Content changes since the previously seen version, None unless diffs are
+    /// enabled and a previous version was seen
+    pub content_diff: Option<Vec<FieldChange>>,
+    /// Values attached by the source's enrichers, by enricher name
+    pub enrichments: BTreeMap<String, serde_json::Value>,
+    /// Category derived from the object type by the source's classifier
+    pub category: ObjectCategory,
 }
 
+/// How long the object source keeps polling
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SnapshotMode {
+    /// Polls for new object versions until closed
+    #[default]
+    Continuous,
+    /// Emits every owned object once, reading all pages, then ends the stream
+    Once,
+}
+
+/// Time an object version is stamped with, and its batch with the newest of its objects
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectTimestamp {
+    /// Local time of the poll that fetched the object
+    #[default]
+    FetchTime,
+    /// Checkpoint timestamp of the transaction that last changed the object, the
+    /// fetch time if it cannot be looked up
+    PreviousTransaction,
+}
+
+/// Time looked-up transaction timestamps stay cached
+const TRANSACTION_TIMESTAMP_TTL: Duration = Duration::from_secs(3600);
+
 /// Sui blockchain data source for fetching object data from the Sui network
 pub struct SuiObjectSource {
     /// Sui RPC endpoint URL
@@ -46,6 +113,60 @@ pub struct SuiObjectSource {
     cursor: Option<ObjectID>,
     /// Maximum number of objects to fetch
     max_objects: usize,
+    /// Largest page the node serves, checked against `max_objects` at init
+    node_page_limit: usize,
+    /// Channel receiving objects that fail conversion
+    dead_letters: Option<DeadLetterSender>,
+    /// Shared request scheduler and this source's priority class
+    scheduler: Option<(SuiScheduler, Priority)>,
+    /// Shared connection reused instead of building a client
+    connection: Option<SuiConnection>,
+    /// Network name override, derived from the RPC URL when None
+    network: Option<String>,
+    /// Chain metadata fetched at init
+    chain: Option<ChainMetadata>,
+    /// Delay applied before the first poll
+    start_delay: StartDelay,
+    /// Field redaction applied before emission, disabled when None
+    redaction: Option<RedactionConfig>,
+    /// Runtime counters
+    stats: StatsRecorder,
+    /// Endpoints polls are spread across, using the source's own client when None
+    pool: Option<EndpointPool>,
+    /// Control events pending emission
+    control: ControlQueue,
+    /// Failed items pending emission as error records
+    item_errors: ItemErrorQueue,
+    /// Handling of items that fail conversion
+    decode_error_policy: ErrorPolicy,
+    /// Handling of failed RPC requests
+    rpc_error_policy: ErrorPolicy,
+    /// Backoff of transient RPC failures, disabled when None
+    retry: Option<RetryConfig>,
+    /// Enrichers run over records before emission
+    enrichers: EnrichmentChain<ChainObject>,
+    /// Per-address callbacks, disabled when None
+    notifications: Option<NotificationRegistry>,
+    /// Byte budget of emitted batches and the batches held back by it
+    batches: BatchSplitter<ChainObject>,
+    /// Previous content of objects, content diffs disabled when None
+    previous_contents: Option<ContentCache>,
+    /// Maps object types to categories
+    classifier: ObjectClassifier,
+    /// Whether the IDs of complete pages are recorded for the change adapter
+    track_listing: bool,
+    /// IDs of the last complete page, None if not taken or the page was truncated
+    listing: Option<HashSet<String>>,
+    /// Store the version map is saved to and resumed from, disabled when None
+    cursor_store: Option<CursorSlot>,
+    /// Whether the source polls continuously or takes one snapshot
+    snapshot_mode: SnapshotMode,
+    /// Whether the snapshot of `SnapshotMode::Once` was read
+    snapshot_taken: bool,
+    /// Time objects are stamped with
+    object_timestamp: ObjectTimestamp,
+    /// Checkpoint timestamps of previous transactions by digest
+    transaction_timestamps: TtlCache<String, u64>,
 }
 
 impl SuiObjectSource {
@@ -73,6 +194,33 @@ impl SuiObjectSource {
             query: Some(query),
             cursor: None,
             max_objects,
+            node_page_limit: rpc::QUERY_MAX_RESULT_LIMIT,
+            dead_letters: None,
+            scheduler: None,
+            connection: None,
+            network: None,
+            chain: None,
+            start_delay: StartDelay::default(),
+            redaction: None,
+            stats: StatsRecorder::default(),
+            pool: None,
+            control: ControlQueue::default(),
+            item_errors: ItemErrorQueue::default(),
+            decode_error_policy: ErrorPolicy::SkipAndLog,
+            rpc_error_policy: ErrorPolicy::FailFast,
+            retry: None,
+            enrichers: EnrichmentChain::default(),
+            notifications: None,
+            batches: BatchSplitter::default(),
+            previous_contents: None,
+            classifier: ObjectClassifier::default(),
+            track_listing: false,
+            listing: None,
+            cursor_store: None,
+            snapshot_mode: SnapshotMode::default(),
+            snapshot_taken: false,
+            object_timestamp: ObjectTimestamp::default(),
+            transaction_timestamps: TtlCache::new(),
         }
     }
 
@@ -98,35 +246,331 @@ impl SuiObjectSource {
         self
     }
 
+    /// Sets the channel receiving objects that fail conversion
+    pub fn with_dead_letter_channel(mut self, sender: DeadLetterSender) -> Self {
+        self.dead_letters = Some(sender);
+        self
+    }
+
+    /// Registers the source with a shared request scheduler
+    pub fn with_scheduler(mut self, scheduler: SuiScheduler, priority: Priority) -> Self {
+        self.scheduler = Some((scheduler, priority));
+        self
+    }
+
+    /// Saves the processed object versions to a cursor store and resumes from them at init
+    ///
+    /// The version map is saved as JSON after every poll emitting objects, so a
+    /// restarted source only emits objects that changed while it was down.
+    pub fn with_cursor_store(
+        mut self,
+        store: Arc<dyn CursorStore>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.cursor_store = Some(CursorSlot::new(store, name.into()));
+        self
+    }
+
+    /// Saves the versions to an asynchronous cursor store, see `with_cursor_store`
+    pub fn with_async_cursor_store(
+        mut self,
+        store: Arc<dyn AsyncCursorStore>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.cursor_store = Some(CursorSlot::new_async(store, name.into()));
+        self
+    }
+
+    /// Reuses a shared connection instead of building a client in `init`
+    ///
+    /// The connection's endpoint takes precedence over the RPC URL of the source.
+    pub fn with_connection(mut self, connection: SuiConnection) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Sets the network name stamped on records, e.g. for custom endpoints
+    pub fn with_network(mut self, network: impl Into<String>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
+    /// Returns the chain metadata fetched at init
+    pub fn chain_metadata(&self) -> Option<&ChainMetadata> {
+        self.chain.as_ref()
+    }
+
+    /// Delays the first poll by a random duration of up to `max_jitter`
+    pub fn with_start_jitter(mut self, max_jitter: Duration) -> Self {
+        self.start_delay.jitter = max_jitter;
+        self
+    }
+
+    /// Delays the first poll by a fixed offset, e.g. `replica * interval / replicas`
+    pub fn with_phase_offset(mut self, offset: Duration) -> Self {
+        self.start_delay.phase_offset = offset;
+        self
+    }
+
+    /// Redacts sender, recipient and metadata fields of records before emission
+    pub fn with_redaction(mut self, config: RedactionConfig) -> Self {
+        self.redaction = Some(config);
+        self
+    }
+
+    /// Spreads polls across the endpoints of a pool instead of one RPC URL
+    pub fn with_endpoint_pool(mut self, pool: EndpointPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Attaches a field-level diff of the decoded content to every new object version
+    ///
+    /// The previous content of up to `max_cached_objects` objects is kept, evicting
+    /// the least recently added; the first version seen of an object has no diff.
+    pub fn with_content_diff(mut self, max_cached_objects: usize) -> Self {
+        self.previous_contents = Some(ContentCache::new(max_cached_objects));
+        self
+    }
+
+    /// Sets whether the source polls continuously or takes one snapshot
+    ///
+    /// With `SnapshotMode::Once`, the first call to `next` pages through every
+    /// object owned by the address without waiting for the polling interval and
+    /// emits them together, split by the byte budget if one is set. Later calls
+    /// return None, ending the stream, including when the snapshot was empty.
+    /// A snapshot skipped after RPC failures returns None once the polling
+    /// interval elapsed, and the next call takes it again.
+    pub fn with_snapshot_mode(mut self, snapshot_mode: SnapshotMode) -> Self {
+        self.snapshot_mode = snapshot_mode;
+        self
+    }
+
+    /// Sets the time objects and records are stamped with, the fetch time by default
+    ///
+    /// `ObjectTimestamp::PreviousTransaction` suits event-time windows: the
+    /// timestamps of previous transactions are looked up in one multi-get per
+    /// poll and cached, so objects changed by the same transaction share a lookup.
+    pub fn with_object_timestamp(mut self, object_timestamp: ObjectTimestamp) -> Self {
+        self.object_timestamp = object_timestamp;
+        self
+    }
+
+    /// Appends an enricher run over records before emission, after those added before
+    pub fn with_enricher(mut self, enricher: impl Enricher<ChainObject> + 'static) -> Self {
+        self.enrichers.push(enricher);
+        self
+    }
+
+    /// Sets the maximum number of lookups of one enricher in flight at once, 8 by default
+    pub fn with_enrichment_concurrency(mut self, concurrency: usize) -> Self {
+        self.enrichers.set_concurrency(concurrency);
+        self
+    }
+
+    /// Bounds the time enrichers may delay a poll, cancelling the lookups still in flight
+    pub fn with_enrichment_deadline(mut self, deadline: Duration) -> Self {
+        self.enrichers.set_deadline(deadline);
+        self
+    }
+
+    /// Splits emitted batches whose JSON encoding exceeds `max_bytes`
+    ///
+    /// The batches split off are emitted by the following calls to `next` before
+    /// the source polls again. A single record above the budget is emitted alone.
+    /// A cursor store saves the position of the poll once its last batch is emitted.
+    pub fn with_max_batch_bytes(mut self, max_bytes: usize) -> Self {
+        self.batches.set_max_bytes(max_bytes);
+        self
+    }
+
+    /// Sets how per-item decode errors are handled, `SkipAndLog` by default
+    pub fn with_decode_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.decode_error_policy = policy;
+        self
+    }
+
+    /// Sets how per-poll RPC errors are handled, `FailFast` by default
+    pub fn with_rpc_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.rpc_error_policy = policy;
+        self
+    }
+
+    /// Retries transient RPC failures with exponential backoff, disabled by default
+    ///
+    /// Rate limiting, server errors and timeouts are retried within the poll, up to
+    /// `max_attempts` requests. Other failures, and transient ones still failing
+    /// after the last attempt, are handled by the RPC error policy.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Replaces the classifier setting `category`, the built-in rules by default
+    pub fn with_classifier(mut self, classifier: ObjectClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Streams `ObjectBatch` records telling created, updated and removed objects apart
+    pub fn changes(mut self) -> SuiObjectChangeSource {
+        self.track_listing = true;
+        SuiObjectChangeSource::new(self)
+    }
+
+    /// Takes the IDs of the last page read, if it listed every owned object
+    pub(crate) fn take_listing(&mut self) -> Option<HashSet<String>> {
+        self.listing.take()
+    }
+
+    /// Streams lightweight `(id, version, type)` summaries, fetching content on demand
+    pub fn summaries(self) -> SuiObjectSummarySource {
+        SuiObjectSummarySource::new(self)
+    }
+
+    /// Replaces the data fetched per object, keeping the query filter
+    pub(crate) fn with_data_options(mut self, options: SuiObjectDataOptions) -> Self {
+        self.query = Some(match self.query.take() {
+            Some(mut query) => {
+                query.options = Some(options);
+                query
+            }
+            None => SuiObjectResponseQuery::new_with_options(options),
+        });
+        self
+    }
+
+    /// Returns the client built at init
+    pub(crate) fn client(&self) -> Option<&SuiClient> {
+        self.client.as_ref()
+    }
+
+    /// Invokes the callbacks of a registry for emitted records, before redaction
+    pub fn with_notifications(mut self, registry: NotificationRegistry) -> Self {
+        self.notifications = Some(registry);
+        self
+    }
+
+    /// Interleaves lifecycle control events with the emitted records
+    pub fn controlled(self) -> ControlledSource<Self> {
+        ControlledSource::new(self)
+    }
+
+    /// Emits failed items and polls as error records instead of only logging them
+    pub fn item_results(self) -> ItemResultSource<Self> {
+        ItemResultSource::new(self)
+    }
+
+    /// Returns a snapshot of the runtime counters
+    pub fn stats(&self) -> SourceStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets whether `bytes_fetched` is counted, disabled by default
+    pub fn with_byte_stats(mut self, byte_stats: bool) -> Self {
+        self.stats.count_bytes(byte_stats);
+        self
+    }
+
+    /// Sets the largest page the node serves, 50 by default as on standard fullnodes
+    pub fn with_node_page_limit(mut self, limit: usize) -> Self {
+        self.node_page_limit = limit;
+        self
+    }
+
     pub fn is_initialized(&self) -> bool {
         self.initialized
     }
 }
 
-#[async_trait]
-impl Source<Vec<ChainObject>> for SuiObjectSource {
-    async fn init(&mut self) -> StreamResult<()> {
-        if self.initialized {
-            return Ok(());
-        }
+impl ControlEvents for SuiObjectSource {
+    fn enable_control_events(&mut self) {
+        self.control.enable();
+    }
 
-        // Initialize Sui client
-        let client = SuiClientBuilder::default()
-            .build(self.rpc_url.as_str())
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to initialize Sui client: {}", e);
-                StreamError::Runtime(format!("Failed to initialize Sui client: {}", e))
-            })?;
+    fn drain_control_events(&mut self) -> Vec<ControlEvent> {
+        self.control.drain()
+    }
+}
 
-        self.client = Some(client);
-        self.initialized = true;
-        tracing::info!("SuiObjectSource initialized with RPC URL: {}", self.rpc_url);
+impl ItemErrors for SuiObjectSource {
+    fn source_name(&self) -> &'static str {
+        "SuiObjectSource"
+    }
 
-        Ok(())
+    fn enable_item_errors(&mut self) {
+        self.item_errors.enable();
     }
 
-    async fn next(&mut self) -> StreamResult<Option<Record<Vec<ChainObject>>>> {
+    fn drain_item_errors(&mut self) -> Vec<SourceItemError> {
+        self.item_errors.drain()
+    }
+}
+
+/// Wraps a batch in a record stamped with the newest timestamp of its objects
+fn stamped(batch: Vec<ChainObject>) -> Record<Vec<ChainObject>> {
+    match batch.iter().map(|object| object.timestamp).max() {
+        Some(newest) => Record::with_timestamp(batch, newest as i64),
+        None => Record::new(batch),
+    }
+}
+
+/// Stamps objects with the checkpoint timestamp of their previous transaction
+///
+/// Timestamps missing from the cache are fetched in pages of the node's query
+/// limit. Objects whose transaction is not found keep their fetch time.
+async fn stamp_previous_transactions(
+    client: &SuiClient,
+    cache: &mut TtlCache<String, u64>,
+    objects: &mut [ChainObject],
+) -> StreamResult<()> {
+    let mut missing: Vec<TransactionDigest> = objects
+        .iter()
+        .filter(|object| {
+            cache
+                .get(&object.last_transaction_digest, TRANSACTION_TIMESTAMP_TTL)
+                .is_none()
+        })
+        .filter_map(|object| TransactionDigest::from_str(&object.last_transaction_digest).ok())
+        .collect();
+    missing.sort();
+    missing.dedup();
+
+    for page in missing.chunks(rpc::QUERY_MAX_RESULT_LIMIT) {
+        let transactions = rpc::call(
+            "SuiObjectSource",
+            "sui_multiGetTransactionBlocks",
+            "fetch previous transaction timestamps",
+            client.read_api().multi_get_transactions_with_options(
+                page.to_vec(),
+                SuiTransactionBlockResponseOptions::new(),
+            ),
+        )
+        .await?;
+        for transaction in transactions {
+            if let Some(timestamp) = transaction.timestamp_ms {
+                cache.insert(
+                    transaction.digest.to_string(),
+                    timestamp,
+                    TRANSACTION_TIMESTAMP_TTL,
+                );
+            }
+        }
+    }
+
+    for object in objects {
+        if let Some(timestamp) =
+            cache.get(&object.last_transaction_digest, TRANSACTION_TIMESTAMP_TTL)
+        {
+            object.timestamp = timestamp;
+        }
+    }
+    Ok(())
+}
+
+impl SuiObjectSource {
+    /// Polls once, drawing every request of the poll from the scheduler budget
+    async fn poll(&mut self) -> StreamResult<Option<Record<Vec<ChainObject>>>> {
         // Ensure initialized
         if !self.initialized || self.client.is_none() {
             return Err(StreamError::Runtime(
@@ -134,30 +578,149 @@ impl Source<Vec<ChainObject>> for SuiObjectSource {
             ));
         }
 
-        // Polling interval
-        sleep(self.interval).await;
+        // Emit batches held back by the byte budget before polling again
+        if let Some(batch) = self.batches.next_pending() {
+            if !self.batches.has_pending()
+                && let Some(slot) = &mut self.cursor_store
+                && !slot.save_deferred().await
+            {
+                self.stats.error("cursor_store");
+            }
+            self.stats.emitted(batch.len());
+            return Ok(Some(stamped(batch)));
+        }
 
-        let client = self.client.as_ref().ok_or_else(|| {
-            StreamError::Runtime("SuiObjectSource client not available".to_string())
-        })?;
+        // End the stream once the snapshot was emitted
+        let snapshot = self.snapshot_mode == SnapshotMode::Once;
+        if snapshot && self.snapshot_taken {
+            return Ok(None);
+        }
+
+        // Spread the first poll of replicas started together
+        self.start_delay.wait().await;
+
+        // Polling interval, snapshots are read right away
+        if !snapshot {
+            sleep(self.interval).await;
+        }
+
+        // Mark the shared connection as active
+        if let Some(connection) = &self.connection {
+            connection.touch();
+        }
+
+        self.stats.poll();
+
+        // Pick the endpoint of this poll when spreading polls across a pool
+        let picked = match &self.pool {
+            Some(pool) => Some(pool.pick().await?),
+            None => None,
+        };
+        if let Some(endpoint) = &picked {
+            self.control.observe_endpoint(&endpoint.rpc_url);
+        }
+        let client = match &picked {
+            Some(endpoint) => &endpoint.client,
+            None => self.client.as_ref().ok_or_else(|| {
+                StreamError::Runtime("SuiObjectSource client not available".to_string())
+            })?,
+        };
 
         // Query objects owned by the target address
-        let objects = client
-            .read_api()
-            .get_owned_objects(
-                SuiAddress::from_str(&self.target_address).map_err(|e| {
-                    tracing::error!("Invalid target address: {}", e);
-                    StreamError::Runtime(format!("Invalid target address: {}", e))
-                })?,
-                self.query.clone(),
-                self.cursor,
-                Some(self.max_objects),
-            )
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch objects: {}", e);
-                StreamError::Runtime(format!("Failed to fetch objects: {}", e))
-            })?;
+        let address = SuiAddress::from_str(&self.target_address).map_err(|e| {
+            tracing::error!("Invalid target address: {}", e);
+            StreamError::Runtime(format!("Invalid target address: {}", e))
+        })?;
+        let mut fetch = PhaseTimer::start("SuiObjectSource", Phase::Fetch);
+        let mut failures = 0;
+        let mut retries = 0;
+        let mut page_cursor = self.cursor;
+        let mut listed = Vec::new();
+        let objects = loop {
+            let page = loop {
+                match rpc::call(
+                    "SuiObjectSource",
+                    "suix_getOwnedObjects",
+                    "fetch objects",
+                    client.read_api().get_owned_objects(
+                        address,
+                        self.query.clone(),
+                        page_cursor,
+                        Some(self.max_objects),
+                    ),
+                )
+                .await
+                {
+                    Ok(page) => break page,
+                    Err(StreamError::Runtime(message))
+                        if is_transient(&message)
+                            && self.retry.is_some_and(|retry| retry.allows(retries)) =>
+                    {
+                        self.stats.error("transient");
+                        retries += 1;
+                        let delay = self
+                            .retry
+                            .map_or(Duration::ZERO, |retry| retry.delay(retries));
+                        tracing::warn!(
+                            "Transient RPC failure, retrying in {:?}: {}",
+                            delay,
+                            message
+                        );
+                        sleep(delay).await;
+                    }
+                    Err(e) => {
+                        self.stats.error("rpc");
+                        if let Some(endpoint) = &picked {
+                            endpoint.mark_failed();
+                        }
+                        failures += 1;
+                        match self.rpc_error_policy.recovery(failures) {
+                            Recovery::Fail => return Err(e),
+                            Recovery::Skip => {
+                                tracing::warn!("Skipping poll after {} failed attempts", failures);
+                                self.item_errors.fetch_failed("SuiObjectSource", &e);
+                                // Snapshots skip the interval, so back off before the next attempt
+                                if snapshot {
+                                    sleep(self.interval).await;
+                                }
+                                return Ok(None);
+                            }
+                            Recovery::Retry => sleep(self.interval).await,
+                        }
+                    }
+                }
+            };
+            listed.extend(page.data);
+
+            // Snapshots read every page before emitting
+            if snapshot && page.has_next_page && page.next_cursor.is_some() {
+                page_cursor = page.next_cursor;
+                continue;
+            }
+            break ObjectsPage {
+                data: listed,
+                next_cursor: page.next_cursor,
+                has_next_page: page.has_next_page,
+            };
+        };
+        self.snapshot_taken = snapshot;
+        self.stats.fetched(&objects);
+        fetch.items(objects.data.len());
+        drop(fetch);
+        self.stats
+            .set_cursor(objects.next_cursor.map(|cursor| cursor.to_string()));
+
+        // Record the complete listing, from which the change adapter detects removals
+        if self.track_listing {
+            self.listing = (!objects.has_next_page).then(|| {
+                objects
+                    .data
+                    .iter()
+                    .filter_map(|object| object.data.as_ref())
+                    .map(|data| data.object_id.to_string())
+                    .collect()
+            });
+        }
 
         // Return None if no objects found
         if objects.data.is_empty() {
@@ -166,12 +729,32 @@ impl Source<Vec<ChainObject>> for SuiObjectSource {
         }
 
         // Process objects with new versions
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let mut decode = PhaseTimer::start("SuiObjectSource", Phase::Decode);
         let mut chain_objects = Vec::new();
         for object in objects.data {
-            let object_data = object.data.ok_or_else(|| {
-                tracing::error!("Object data is missing");
-                StreamError::Runtime("Object data is missing".to_string())
-            })?;
+            // Objects without data carry no ID to refetch, so retries skip them
+            let Some(object_data) = object.data else {
+                self.stats.error("conversion");
+                if self.decode_error_policy == ErrorPolicy::FailFast {
+                    return Err(StreamError::Runtime(format!(
+                        "Failed to convert object: {:?}",
+                        object.error
+                    )));
+                }
+                let letter = DeadLetter {
+                    source: "SuiObjectSource".to_string(),
+                    item_id: "unknown".to_string(),
+                    error: ConversionError::MissingField("data".to_string()),
+                    raw: format!("{:?}", object.error),
+                };
+                self.item_errors.decode_failed(&letter);
+                route_dead_letter(self.dead_letters.as_ref(), letter);
+                continue;
+            };
 
             let object_id = object_data.object_id.to_string();
             let current_version = object_data.version.value();
@@ -187,22 +770,41 @@ impl Source<Vec<ChainObject>> for SuiObjectSource {
             self.last_processed_versions
                 .insert(object_id.clone(), current_version);
 
+            // Diff the decoded content against the previously seen version
+            let diff = self.previous_contents.as_mut().and_then(|cache| {
+                let content = serde_json::to_value(&object_data.content).ok()?;
+                let previous = cache.replace(&object_id, content.clone())?;
+                Some(content_diff(&previous, &content))
+            });
+
             // Convert to chain object
-            let chain_object = ChainObject {
+            let mut chain_object = ChainObject {
                 id: object_id.clone(),
                 object_type: object_data
                     .clone()
                     .type_
                     .map(|t| t.to_string())
                     .unwrap_or_else(|| "Unknown".to_string()),
-                owner: self.target_address.clone(),
+                owner: object_data
+                    .owner
+                    .as_ref()
+                    .and_then(|owner| owner.get_owner_address().ok())
+                    .map(|address| address.to_string())
+                    .unwrap_or_else(|| self.target_address.clone()),
+                owner_kind: object_data.owner.clone(),
                 version: current_version,
                 data: object_data.clone(),
                 last_transaction_digest: object_data
                     .previous_transaction
                     .map(|t| t.to_string())
                     .unwrap_or_default(),
+                timestamp: fetched_at,
+                chain: self.chain.clone(),
+                content_diff: diff,
+                enrichments: BTreeMap::new(),
+                category: ObjectCategory::Unknown,
             };
+            chain_object.category = self.classifier.classify(&chain_object);
 
             tracing::debug!(
                 "Processed Sui object: {} version: {} owner: {}",
@@ -213,6 +815,19 @@ impl Source<Vec<ChainObject>> for SuiObjectSource {
 
             chain_objects.push(chain_object);
         }
+        if self.object_timestamp == ObjectTimestamp::PreviousTransaction
+            && let Err(e) = stamp_previous_transactions(
+                client,
+                &mut self.transaction_timestamps,
+                &mut chain_objects,
+            )
+            .await
+        {
+            self.stats.error("timestamp");
+            tracing::warn!("Stamping objects with their fetch time: {}", e);
+        }
+        decode.items(chain_objects.len());
+        drop(decode);
 
         // Return None if no new object versions found
         if chain_objects.is_empty() {
@@ -223,7 +838,99 @@ impl Source<Vec<ChainObject>> for SuiObjectSource {
             return Ok(None);
         }
 
-        Ok(Some(Record::new(chain_objects)))
+        let mut emit = PhaseTimer::start("SuiObjectSource", Phase::Emit);
+        let failures = self.enrichers.apply(&mut chain_objects).await;
+        self.stats.errors("enrichment", failures);
+
+        if let Some(notifications) = &self.notifications {
+            notifications.notify_objects(&chain_objects);
+        }
+
+        if let Some(redaction) = &self.redaction {
+            for item in &mut chain_objects {
+                item.redact(redaction);
+            }
+        }
+
+        let chain_objects = self.batches.split(chain_objects);
+        if let Some(slot) = &mut self.cursor_store
+            && let Ok(saved) = serde_json::to_string(&self.last_processed_versions)
+            && !slot.save_after(saved, self.batches.has_pending()).await
+        {
+            self.stats.error("cursor_store");
+        }
+        self.stats.emitted(chain_objects.len());
+        emit.items(chain_objects.len());
+        Ok(Some(stamped(chain_objects)))
+    }
+}
+
+#[async_trait]
+impl Source<Vec<ChainObject>> for SuiObjectSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client, reusing the endpoint pool or shared connection if set
+        let client = match (&self.pool, &self.connection) {
+            (Some(pool), _) => {
+                pool.connect("SuiObjectSource").await?;
+                pool.pick().await?.client
+            }
+            (None, Some(connection)) => connection.client().clone(),
+            (None, None) => {
+                rpc::call(
+                    "SuiObjectSource",
+                    "rpc.discover",
+                    "initialize Sui client",
+                    SuiClientBuilder::default().build(self.rpc_url.as_str()),
+                )
+                .await?
+            }
+        };
+
+        // Resume from the versions saved by a previous run
+        if let Some(slot) = &self.cursor_store
+            && let Some(saved) = slot.load().await?
+        {
+            self.last_processed_versions = serde_json::from_str(&saved)
+                .map_err(|e| StreamError::Runtime(format!("Invalid saved versions: {}", e)))?;
+            tracing::info!(
+                "SuiObjectSource resuming with {} known objects",
+                self.last_processed_versions.len()
+            );
+        }
+
+        // Identify the chain stamped on every record
+        let rpc_url = self
+            .connection
+            .as_ref()
+            .map_or(self.rpc_url.as_str(), SuiConnection::rpc_url);
+        let network = self
+            .network
+            .clone()
+            .unwrap_or_else(|| network_name(rpc_url));
+        self.chain = Some(ChainMetadata::fetch("SuiObjectSource", &client, network).await?);
+
+        // Keep pages within the node limit instead of failing at runtime
+        self.max_objects = rpc::check_page_size(
+            "SuiObjectSource",
+            "max_objects",
+            self.max_objects,
+            self.node_page_limit,
+        )?;
+
+        self.client = Some(client);
+        self.initialized = true;
+        self.stats.start();
+        tracing::info!("SuiObjectSource initialized with RPC URL: {}", self.rpc_url);
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<ChainObject>>>> {
+        rpc::with_budget(self.scheduler.clone(), self.poll()).await
     }
 
     async fn close(&mut self) -> StreamResult<()> {
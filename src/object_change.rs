@@ -0,0 +1,115 @@
+use crate::object::{ChainObject, SuiObjectSource};
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Change of an object owned by the watched address
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ObjectChange {
+    /// The object was not owned by the address before
+    Created(ChainObject),
+    /// A new version of an object already seen
+    Updated {
+        /// Version emitted before
+        old_version: u64,
+        /// Version of `object`
+        new_version: u64,
+        /// The object at its new version
+        object: ChainObject,
+    },
+    /// The object is no longer owned by the address: transferred, wrapped or deleted
+    Removed {
+        /// Object ID
+        id: String,
+        /// Last version emitted
+        last_version: u64,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectBatch {
+    /// Whether the batch is the first one, listing the objects owned at start as created
+    pub snapshot: bool,
+    /// Changes observed by one poll
+    pub changes: Vec<ObjectChange>,
+}
+
+/// Object source emitting typed changes instead of bare objects
+///
+/// Created and updated objects are told apart by the versions emitted before.
+/// Removals are detected only when a poll lists every owned object, i.e. when
+/// the owned objects fit within the inner source's `max_objects`; polls of
+/// larger sets report creations and updates only.
+pub struct SuiObjectChangeSource {
+    /// Underlying object source, recording complete listings
+    inner: SuiObjectSource,
+    /// Last emitted version by object ID
+    versions: HashMap<String, u64>,
+    /// Whether a batch was emitted
+    started: bool,
+}
+
+impl SuiObjectChangeSource {
+    pub(crate) fn new(inner: SuiObjectSource) -> Self {
+        Self {
+            inner,
+            versions: HashMap::new(),
+            started: false,
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl Source<ObjectBatch> for SuiObjectChangeSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<ObjectBatch>>> {
+        let record = self.inner.next().await?;
+
+        let mut changes = Vec::new();
+        for object in record.map(|record| record.data).unwrap_or_default() {
+            match self.versions.insert(object.id.clone(), object.version) {
+                Some(old_version) => changes.push(ObjectChange::Updated {
+                    old_version,
+                    new_version: object.version,
+                    object,
+                }),
+                None => changes.push(ObjectChange::Created(object)),
+            }
+        }
+
+        // Objects missing from a complete listing left the address
+        if let Some(listing) = self.inner.take_listing() {
+            let removed: Vec<String> = self
+                .versions
+                .keys()
+                .filter(|id| !listing.contains(*id))
+                .cloned()
+                .collect();
+            for id in removed {
+                if let Some(last_version) = self.versions.remove(&id) {
+                    changes.push(ObjectChange::Removed { id, last_version });
+                }
+            }
+        }
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+        let snapshot = !self.started;
+        self.started = true;
+        Ok(Some(Record::new(ObjectBatch { snapshot, changes })))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
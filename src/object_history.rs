@@ -0,0 +1,367 @@
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use sui_sdk::rpc_types::{
+    SuiObjectData, SuiObjectDataOptions, SuiPastObjectResponse, SuiTransactionBlockEffectsAPI,
+    SuiTransactionBlockResponseOptions,
+};
+use sui_sdk::types::base_types::{ObjectID, SequenceNumber};
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectVersion {
+    /// Object ID
+    pub object_id: String,
+    /// Object version
+    pub version: u64,
+    /// Version this one was derived from, None if the object was created here
+    pub previous_version: Option<u64>,
+    /// Object type
+    pub object_type: String,
+    /// Digest of the transaction that produced this version
+    pub transaction_digest: String,
+    /// Object data at this version
+    pub data: SuiObjectData,
+}
+
+/// Sui blockchain data source emitting the version lineage of configured objects
+///
+/// For every new head version the source walks back through past versions using
+/// the past object API, following the `modified_at_versions` of each producing
+/// transaction, until it reaches the last version it already emitted, the
+/// object's creation, or the configured depth limit.
+///
+/// The depth limit bounds how far back the first walk of an object reaches.
+/// When a later walk is cut short before the last emitted version, the
+/// truncation point is kept and the walk resumes from it on the next poll
+/// ahead of any new head versions, so the versions in between are emitted
+/// late rather than skipped.
+pub struct SuiObjectHistorySource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Objects to track
+    object_ids: Vec<String>,
+    /// Last emitted head version map (object_id -> version)
+    last_emitted_versions: HashMap<String, u64>,
+    /// Maximum number of versions walked per object per poll
+    max_depth: usize,
+    /// Truncated walks to resume (object_id -> (next version, last emitted version))
+    resume: HashMap<String, (u64, u64)>,
+}
+
+impl SuiObjectHistorySource {
+    /// Creates a new SuiObjectHistorySource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `object_ids` - IDs of the objects whose history should be walked
+    pub fn new(rpc_url: String, interval_ms: u64, object_ids: Vec<String>) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            object_ids,
+            last_emitted_versions: HashMap::new(),
+            max_depth: 16,
+            resume: HashMap::new(),
+        }
+    }
+
+    /// Creates a new SuiObjectHistorySource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64, object_ids: Vec<String>) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms, object_ids)
+    }
+
+    /// Sets the maximum number of versions walked per object per poll
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Finds the version an object had before the given transaction modified it
+    async fn previous_version(
+        client: &SuiClient,
+        object_id: ObjectID,
+        digest: TransactionDigest,
+    ) -> StreamResult<Option<u64>> {
+        let transaction = rpc::call(
+            "SuiObjectHistorySource",
+            "sui_getTransactionBlock",
+            &format!("fetch transaction {}", digest),
+            client.read_api().get_transaction_with_options(
+                digest,
+                SuiTransactionBlockResponseOptions::new().with_effects(),
+            ),
+        )
+        .await?;
+
+        Ok(transaction.effects.and_then(|effects| {
+            effects
+                .modified_at_versions()
+                .into_iter()
+                .find(|(id, _)| *id == object_id)
+                .map(|(_, version)| version.value())
+        }))
+    }
+
+    /// Fetches one past version of an object, None once its history ends
+    async fn past_object(
+        client: &SuiClient,
+        object_id: ObjectID,
+        version: u64,
+    ) -> StreamResult<Option<SuiObjectData>> {
+        let past = rpc::call(
+            "SuiObjectHistorySource",
+            "sui_tryGetPastObject",
+            &format!("fetch past object {}", object_id),
+            client.read_api().try_get_parsed_past_object(
+                object_id,
+                SequenceNumber::from_u64(version),
+                SuiObjectDataOptions::full_content(),
+            ),
+        )
+        .await?;
+
+        match past {
+            SuiPastObjectResponse::VersionFound(data) => Ok(Some(data)),
+            other => {
+                tracing::debug!(
+                    "History of object {} ends at version {}: {:?}",
+                    object_id,
+                    version,
+                    other
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Walks the lineage of one object from the given version down to the last emitted version
+    ///
+    /// Returns the versions oldest first, along with the next version to walk
+    /// when the depth limit cut the walk short.
+    async fn walk_history(
+        client: &SuiClient,
+        object_id: ObjectID,
+        mut current: SuiObjectData,
+        last_emitted: Option<u64>,
+        max_depth: usize,
+    ) -> StreamResult<(Vec<ObjectVersion>, Option<u64>)> {
+        let mut lineage = Vec::new();
+        let mut truncated = None;
+        while lineage.len() < max_depth {
+            let version = current.version.value();
+            if last_emitted.is_some_and(|last| version <= last) {
+                break;
+            }
+
+            let digest = current.previous_transaction;
+            let previous_version = match digest {
+                Some(digest) => Self::previous_version(client, object_id, digest).await?,
+                None => None,
+            };
+
+            lineage.push(ObjectVersion {
+                object_id: object_id.to_string(),
+                version,
+                previous_version,
+                object_type: current
+                    .type_
+                    .as_ref()
+                    .map(|t| t.to_string())
+                    .unwrap_or_else(|| "Unknown".to_string()),
+                transaction_digest: digest.map(|d| d.to_string()).unwrap_or_default(),
+                data: current.clone(),
+            });
+
+            // Stop at creation or once the already emitted version is reached
+            let Some(previous_version) = previous_version else {
+                break;
+            };
+            if last_emitted.is_some_and(|last| previous_version <= last) {
+                break;
+            }
+
+            // Keep the truncation point when the depth limit is reached
+            if lineage.len() == max_depth {
+                truncated = Some(previous_version);
+                break;
+            }
+
+            current = match Self::past_object(client, object_id, previous_version).await? {
+                Some(data) => data,
+                None => break,
+            };
+        }
+
+        // Emit oldest version first
+        lineage.reverse();
+        Ok((lineage, truncated))
+    }
+
+    /// Walks one object, resuming a truncated walk before following new head versions
+    async fn walk_object(
+        &mut self,
+        id: &str,
+        object_id: ObjectID,
+    ) -> StreamResult<Vec<ObjectVersion>> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiObjectHistorySource client not available".to_string())
+        })?;
+
+        let mut versions = Vec::new();
+        if let Some((next, last_emitted)) = self.resume.remove(id) {
+            if let Some(current) = Self::past_object(client, object_id, next).await? {
+                let (lineage, truncated) = Self::walk_history(
+                    client,
+                    object_id,
+                    current,
+                    Some(last_emitted),
+                    self.max_depth,
+                )
+                .await?;
+                if let Some(next) = truncated {
+                    self.resume.insert(id.to_string(), (next, last_emitted));
+                }
+                versions = lineage;
+            }
+
+            // New head versions wait until the gap is closed
+            if versions.len() >= self.max_depth {
+                return Ok(versions);
+            }
+        }
+
+        let head = rpc::call(
+            "SuiObjectHistorySource",
+            "sui_getObject",
+            &format!("fetch object {}", object_id),
+            client
+                .read_api()
+                .get_object_with_options(object_id, SuiObjectDataOptions::full_content()),
+        )
+        .await?;
+
+        let Some(current) = head.data else {
+            tracing::debug!("Object {} not available, skipping", object_id);
+            return Ok(versions);
+        };
+
+        let last_emitted = self.last_emitted_versions.get(id).copied();
+        let (lineage, truncated) = Self::walk_history(
+            client,
+            object_id,
+            current,
+            last_emitted,
+            self.max_depth - versions.len(),
+        )
+        .await?;
+
+        // A truncated first walk only bounds how far back the history starts
+        if let (Some(next), Some(last_emitted)) = (truncated, last_emitted) {
+            tracing::warn!(
+                "Walk of object {} truncated at version {}, resuming next poll",
+                id,
+                next
+            );
+            self.resume.insert(id.to_string(), (next, last_emitted));
+        }
+
+        // Update last emitted head version
+        if let Some(head) = lineage.last() {
+            self.last_emitted_versions
+                .insert(id.to_string(), head.version);
+            tracing::debug!(
+                "Walked {} versions of Sui object: {} head: {}",
+                lineage.len(),
+                id,
+                head.version
+            );
+        }
+        versions.extend(lineage);
+        Ok(versions)
+    }
+}
+
+#[async_trait]
+impl Source<Vec<ObjectVersion>> for SuiObjectHistorySource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client
+        let client = rpc::call(
+            "SuiObjectHistorySource",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(self.rpc_url.as_str()),
+        )
+        .await?;
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiObjectHistorySource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<ObjectVersion>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiObjectHistorySource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        let mut versions = Vec::new();
+        for id in self.object_ids.clone() {
+            let object_id = ObjectID::from_str(&id).map_err(|e| {
+                tracing::error!("Invalid object ID: {}", e);
+                StreamError::Runtime(format!("Invalid object ID: {}", e))
+            })?;
+
+            versions.extend(self.walk_object(&id, object_id).await?);
+        }
+
+        // Return None if no new versions found
+        if versions.is_empty() {
+            tracing::info!("No new object versions found");
+            return Ok(None);
+        }
+
+        Ok(Some(Record::new(versions)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiObjectHistorySource closed");
+        Ok(())
+    }
+}
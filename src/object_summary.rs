@@ -0,0 +1,171 @@
+use crate::connection::SuiConnection;
+use crate::object::{ChainObject, SuiObjectSource};
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{SuiObjectData, SuiObjectDataOptions};
+use sui_sdk::types::base_types::ObjectID;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectSummary {
+    /// Object ID
+    pub id: String,
+    /// Object version
+    pub version: u64,
+    /// Object type
+    pub object_type: String,
+    /// Full object data, None unless selected for hydration
+    pub hydrated: Option<SuiObjectData>,
+}
+
+impl From<ChainObject> for ObjectSummary {
+    fn from(object: ChainObject) -> Self {
+        ObjectSummary {
+            id: object.id,
+            version: object.version,
+            object_type: object.object_type,
+            hydrated: None,
+        }
+    }
+}
+
+/// Fetches the full data of objects selected from lightweight summaries
+pub struct ObjectHydrator {
+    /// Sui client
+    client: SuiClient,
+    /// Data fetched per object
+    options: SuiObjectDataOptions,
+}
+
+impl ObjectHydrator {
+    /// Creates a hydrator fetching full content through a shared connection
+    pub fn new(connection: &SuiConnection) -> Self {
+        Self::from_client(connection.client().clone())
+    }
+
+    fn from_client(client: SuiClient) -> Self {
+        Self {
+            client,
+            options: SuiObjectDataOptions::full_content(),
+        }
+    }
+
+    /// Sets the data fetched per object, full content by default
+    pub fn with_options(mut self, options: SuiObjectDataOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Fetches the current data of objects, in pages of the node's query limit
+    ///
+    /// Objects that no longer exist are left out of the result.
+    pub async fn hydrate(&self, ids: &[ObjectID]) -> StreamResult<Vec<SuiObjectData>> {
+        let mut objects = Vec::with_capacity(ids.len());
+        for page in ids.chunks(rpc::QUERY_MAX_RESULT_LIMIT) {
+            let responses = rpc::call(
+                "ObjectHydrator",
+                "sui_multiGetObjects",
+                "hydrate objects",
+                self.client
+                    .read_api()
+                    .multi_get_object_with_options(page.to_vec(), self.options.clone()),
+            )
+            .await?;
+            objects.extend(responses.into_iter().filter_map(|response| response.data));
+        }
+        Ok(objects)
+    }
+}
+
+type Selector = Box<dyn Fn(&ObjectSummary) -> bool + Send + Sync>;
+
+/// Object source streaming `(id, version, type)` summaries instead of full content
+///
+/// The inner source fetches only object types, so polls stay cheap. Content is
+/// fetched on demand, either with an `ObjectHydrator` or inline for the summaries
+/// a predicate selects, see `with_hydration`.
+pub struct SuiObjectSummarySource {
+    /// Underlying object source, fetching types only
+    inner: SuiObjectSource,
+    /// Predicate selecting summaries to hydrate and the maximum hydrated per poll
+    hydration: Option<(Selector, usize)>,
+    /// Hydrator built from the inner client at init
+    hydrator: Option<ObjectHydrator>,
+}
+
+impl SuiObjectSummarySource {
+    /// Creates a new SuiObjectSummarySource wrapping an object source
+    pub fn new(inner: SuiObjectSource) -> Self {
+        Self {
+            inner: inner.with_data_options(SuiObjectDataOptions::new().with_type()),
+            hydration: None,
+            hydrator: None,
+        }
+    }
+
+    /// Hydrates up to `budget` summaries per poll that `predicate` selects
+    ///
+    /// Selected summaries are emitted with `hydrated` set; those beyond the budget
+    /// are emitted as plain summaries.
+    pub fn with_hydration<F>(mut self, predicate: F, budget: usize) -> Self
+    where
+        F: Fn(&ObjectSummary) -> bool + Send + Sync + 'static,
+    {
+        self.hydration = Some((Box::new(predicate), budget));
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl Source<Vec<ObjectSummary>> for SuiObjectSummarySource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await?;
+        if self.hydration.is_some() {
+            let client = self.inner.client().cloned().ok_or_else(|| {
+                StreamError::Runtime("SuiObjectSource client not available".to_string())
+            })?;
+            self.hydrator = Some(ObjectHydrator::from_client(client));
+        }
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<ObjectSummary>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+        let mut summaries: Vec<ObjectSummary> =
+            record.data.into_iter().map(ObjectSummary::from).collect();
+
+        // Fetch the content of the selected summaries within the budget
+        if let (Some((predicate, budget)), Some(hydrator)) = (&self.hydration, &self.hydrator) {
+            let selected: Vec<ObjectID> = summaries
+                .iter()
+                .filter(|summary| predicate(summary))
+                .filter_map(|summary| ObjectID::from_str(&summary.id).ok())
+                .take(*budget)
+                .collect();
+            if !selected.is_empty() {
+                for data in hydrator.hydrate(&selected).await? {
+                    let id = data.object_id.to_string();
+                    if let Some(summary) = summaries.iter_mut().find(|summary| summary.id == id) {
+                        summary.hydrated = Some(data);
+                    }
+                }
+            }
+        }
+
+        Ok(Some(Record::new(summaries)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
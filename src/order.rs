@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+
+/// Order in which the items of an emitted batch are delivered
+///
+/// The guarantee applies to every batch a source emits: items are arranged by
+/// their chain position (checkpoint, then timestamp), and items sharing a
+/// position keep the relative order the node returned them in, so events of one
+/// transaction stay in `event_seq` order. Batches assembled from several pages,
+/// or from a page fetched again after a retry, are arranged as a whole.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmitOrder {
+    /// Oldest items first
+    ChainAscending,
+    /// Newest items first
+    ChainDescending,
+    /// Items in the order the node returned them
+    #[default]
+    ArrivalOrder,
+}
+
+impl EmitOrder {
+    /// Arranges a batch according to this order
+    ///
+    /// # Parameters
+    /// * `items` - Items in the order they were returned by the node
+    /// * `descending_query` - Whether the node was queried in descending order
+    /// * `key` - Chain position of an item
+    pub fn arrange<T, K, F>(self, items: &mut [T], descending_query: bool, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        match self {
+            EmitOrder::ArrivalOrder => {}
+            EmitOrder::ChainAscending => {
+                // Restore chain order for ties before the stable sort
+                if descending_query {
+                    items.reverse();
+                }
+                items.sort_by_key(|item| key(item));
+            }
+            EmitOrder::ChainDescending => {
+                if !descending_query {
+                    items.reverse();
+                }
+                items.sort_by_key(|item| Reverse(key(item)));
+            }
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use sui_sdk::rpc_types::{
+    ObjectChange, SuiCommand, SuiTransactionBlockDataAPI, SuiTransactionBlockKind,
+    SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+    SuiTransactionBlockResponseQuery, TransactionFilter,
+};
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+/// Transactions fetched per page of a checkpoint
+const PAGE_SIZE: usize = 50;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackagePublish {
+    /// ID of the published package
+    pub package_id: String,
+    /// Package version, 1 unless the package upgrades an earlier one
+    pub version: u64,
+    /// Address that sent the publish transaction
+    pub publisher: String,
+    /// Names of the package's modules
+    pub modules: Vec<String>,
+    /// IDs of the packages the package links against
+    pub dependencies: Vec<String>,
+    /// Package upgraded by this one, None for fresh publishes
+    pub upgraded_from: Option<String>,
+    /// Digest of the publish transaction
+    pub transaction_digest: String,
+    /// Checkpoint of the publish transaction
+    pub checkpoint: u64,
+    /// Timestamp of the publish transaction (milliseconds)
+    pub timestamp: u64,
+}
+
+/// Sui blockchain data source emitting newly published packages
+///
+/// The node cannot filter transactions by command, so every poll reads the
+/// transactions of the checkpoints produced since the previous poll, up to
+/// `max_checkpoints`, and keeps those with publish or upgrade commands. Reading
+/// starts at the checkpoint following init unless a start checkpoint is set.
+/// Packages are paired with the commands publishing them in order; failed
+/// publishes are skipped.
+pub struct SuiPackageSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Next checkpoint to read, set at init unless configured
+    next_checkpoint: Option<u64>,
+    /// Maximum number of checkpoints read per poll
+    max_checkpoints: u64,
+    /// Whether package upgrades are emitted along with fresh publishes
+    include_upgrades: bool,
+}
+
+impl SuiPackageSource {
+    /// Creates a new SuiPackageSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    pub fn new(rpc_url: String, interval_ms: u64) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            next_checkpoint: None,
+            max_checkpoints: 20,
+            include_upgrades: true,
+        }
+    }
+
+    /// Creates a new SuiPackageSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms)
+    }
+
+    /// Starts reading at a checkpoint instead of the one following init
+    pub fn with_start_checkpoint(mut self, checkpoint: u64) -> Self {
+        self.next_checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Sets the maximum number of checkpoints read per poll, 20 by default
+    pub fn with_max_checkpoints(mut self, max_checkpoints: u64) -> Self {
+        self.max_checkpoints = max_checkpoints.max(1);
+        self
+    }
+
+    /// Sets whether package upgrades are emitted, true by default
+    pub fn with_upgrades(mut self, include_upgrades: bool) -> Self {
+        self.include_upgrades = include_upgrades;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Returns the next checkpoint to read, None before init without a start checkpoint
+    pub fn next_checkpoint(&self) -> Option<u64> {
+        self.next_checkpoint
+    }
+
+    /// Fetches every transaction of a checkpoint with its input and object changes
+    async fn fetch_checkpoint(
+        client: &SuiClient,
+        checkpoint: u64,
+    ) -> StreamResult<Vec<SuiTransactionBlockResponse>> {
+        let query = SuiTransactionBlockResponseQuery::new(
+            Some(TransactionFilter::Checkpoint(checkpoint)),
+            Some(
+                SuiTransactionBlockResponseOptions::new()
+                    .with_input()
+                    .with_object_changes(),
+            ),
+        );
+        let mut transactions = Vec::new();
+        let mut cursor: Option<TransactionDigest> = None;
+        loop {
+            let page = rpc::call(
+                "SuiPackageSource",
+                "suix_queryTransactionBlocks",
+                &format!("fetch transactions of checkpoint {}", checkpoint),
+                client.read_api().query_transaction_blocks(
+                    query.clone(),
+                    cursor,
+                    Some(PAGE_SIZE),
+                    false,
+                ),
+            )
+            .await?;
+            transactions.extend(page.data);
+
+            if !page.has_next_page || page.next_cursor.is_none() {
+                return Ok(transactions);
+            }
+            cursor = page.next_cursor;
+        }
+    }
+}
+
+/// Packages published by a transaction, paired with their publish or upgrade command
+fn published_packages(
+    transaction: &SuiTransactionBlockResponse,
+    checkpoint: u64,
+    include_upgrades: bool,
+) -> Vec<PackagePublish> {
+    let Some(block) = &transaction.transaction else {
+        return Vec::new();
+    };
+    let SuiTransactionBlockKind::ProgrammableTransaction(programmable) = block.data.transaction()
+    else {
+        return Vec::new();
+    };
+
+    // Dependencies and upgraded package of each command publishing a package
+    let commands = programmable
+        .commands
+        .iter()
+        .filter_map(|command| match command {
+            SuiCommand::Publish(dependencies) => Some((dependencies, None)),
+            SuiCommand::Upgrade(dependencies, previous, _) => Some((dependencies, Some(previous))),
+            _ => None,
+        });
+    // Failed transactions publish nothing, so they have no published changes
+    let packages = transaction
+        .object_changes
+        .iter()
+        .flatten()
+        .filter_map(|change| match change {
+            ObjectChange::Published {
+                package_id,
+                version,
+                modules,
+                ..
+            } => Some((package_id, version, modules)),
+            _ => None,
+        });
+
+    packages
+        .zip(commands)
+        .filter(|(_, (_, previous))| include_upgrades || previous.is_none())
+        .map(
+            |((package_id, version, modules), (dependencies, previous))| PackagePublish {
+                package_id: package_id.to_string(),
+                version: version.value(),
+                publisher: block.data.sender().to_string(),
+                modules: modules.clone(),
+                dependencies: dependencies.iter().map(ToString::to_string).collect(),
+                upgraded_from: previous.map(ToString::to_string),
+                transaction_digest: transaction.digest.to_string(),
+                checkpoint,
+                timestamp: transaction.timestamp_ms.unwrap_or(0),
+            },
+        )
+        .collect()
+}
+
+async fn latest_checkpoint(client: &SuiClient) -> StreamResult<u64> {
+    rpc::call(
+        "SuiPackageSource",
+        "sui_getLatestCheckpointSequenceNumber",
+        "fetch latest checkpoint",
+        client.read_api().get_latest_checkpoint_sequence_number(),
+    )
+    .await
+}
+
+#[async_trait]
+impl Source<Vec<PackagePublish>> for SuiPackageSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client
+        let client = rpc::call(
+            "SuiPackageSource",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(self.rpc_url.as_str()),
+        )
+        .await?;
+
+        // Start after the newest checkpoint unless configured
+        if self.next_checkpoint.is_none() {
+            self.next_checkpoint = Some(latest_checkpoint(&client).await? + 1);
+        }
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiPackageSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<PackagePublish>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiPackageSource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiPackageSource client not available".to_string())
+        })?;
+        let start = self.next_checkpoint.ok_or_else(|| {
+            StreamError::Runtime("SuiPackageSource checkpoint not available".to_string())
+        })?;
+
+        // Return None until a new checkpoint exists
+        let latest = latest_checkpoint(client).await?;
+        if latest < start {
+            tracing::info!("No new checkpoints to scan for packages");
+            return Ok(None);
+        }
+        let end = latest.min(start + self.max_checkpoints - 1);
+
+        // Read the checkpoints in order, advancing past each one fully read
+        let mut packages = Vec::new();
+        for checkpoint in start..=end {
+            let transactions = match Self::fetch_checkpoint(client, checkpoint).await {
+                Ok(transactions) => transactions,
+                Err(e) if checkpoint == start => return Err(e),
+                // Emit the checkpoints already read, the failed one is retried next poll
+                Err(e) => {
+                    tracing::warn!("Stopped scanning at checkpoint {}: {}", checkpoint, e);
+                    break;
+                }
+            };
+            packages.extend(transactions.iter().flat_map(|transaction| {
+                published_packages(transaction, checkpoint, self.include_upgrades)
+            }));
+            self.next_checkpoint = Some(checkpoint + 1);
+        }
+
+        // Return None if no package was published
+        if packages.is_empty() {
+            tracing::info!("No packages published in checkpoints {} to {}", start, end);
+            return Ok(None);
+        }
+        for package in &packages {
+            tracing::debug!(
+                "Package {} published by {} with {} modules",
+                package.package_id,
+                package.publisher,
+                package.modules.len()
+            );
+        }
+
+        Ok(Some(Record::new(packages)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiPackageSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Handle suspending and resuming the polls of a source
+///
+/// Clones control the same source. While paused, `next` waits before issuing any
+/// request; the client and cursors are kept, so polling resumes where it stopped.
+#[derive(Clone, Debug)]
+pub struct PauseHandle {
+    paused: Arc<watch::Sender<bool>>,
+}
+
+impl PauseHandle {
+    pub(crate) fn new() -> Self {
+        Self {
+            paused: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Suspends polling after the poll in progress, if any
+    pub fn pause(&self) {
+        if !self.paused.send_replace(true) {
+            tracing::info!("Polling paused");
+        }
+    }
+
+    /// Resumes polling, waking a source waiting in `next`
+    pub fn resume(&self) {
+        if self.paused.send_replace(false) {
+            tracing::info!("Polling resumed");
+        }
+    }
+
+    /// Returns whether polling is paused
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Waits until polling is not paused
+    pub(crate) async fn wait_resumed(&self) {
+        // The sender lives as long as self, so waiting cannot fail
+        let _ = self.paused.subscribe().wait_for(|paused| !paused).await;
+    }
+}
+
+impl Default for PauseHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
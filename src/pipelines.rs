@@ -0,0 +1,272 @@
+//! Ready-made pipelines wiring a source to a tumbling window and an aggregation
+//!
+//! Each function initializes its source and returns the aggregated stream, ready
+//! for a sink:
+//!
+//! ```rust,no_run
+//! use fluxus::api::io::CollectionSink;
+//! use fluxus_source_sui::pipelines::{PipelineConfig, transfer_volume_pipeline};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let sink = CollectionSink::new();
+//!     transfer_volume_pipeline(&PipelineConfig::default())
+//!         .await
+//!         .expect("Failed to initialize pipeline")
+//!         .sink(sink.clone())
+//!         .await
+//!         .expect("Stream processing failed");
+//! }
+//! ```
+//!
+//! Transaction pipelines read the head with gap backfill and drop the transactions
+//! already emitted, so overlapping pages and bursts between polls count each
+//! transaction once.
+
+use crate::event::SuiEventSource;
+use crate::object::SuiObjectSource;
+use crate::transaction::{SuiEvent, SuiTransactionSource};
+use async_trait::async_trait;
+use fluxus::api::DataStream;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use fluxus::utils::window::WindowConfig;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+use sui_sdk::SUI_MAINNET_URL;
+
+/// Pages of backfill allowed per poll of the transaction pipelines
+const BACKFILL_PAGES: usize = 10;
+
+/// Number of items per key within a window
+pub type TypeCounts = HashMap<String, u32>;
+
+/// Sum of the absolute balance changes per coin type within a window, in the coin's smallest unit
+pub type TransferVolumes = HashMap<String, u128>;
+
+/// Settings shared by the ready-made pipelines
+#[derive(Clone, Debug)]
+pub struct PipelineConfig {
+    /// Sui RPC endpoint URL
+    pub rpc_url: String,
+    /// Polling interval of the source (milliseconds)
+    pub interval_ms: u64,
+    /// Maximum number of items fetched per poll
+    pub batch_size: usize,
+    /// Length of the tumbling window
+    pub window: Duration,
+    /// Parallelism of the stream
+    pub parallelism: usize,
+}
+
+impl Default for PipelineConfig {
+    /// Mainnet, polling every 500ms for 10 items, over 10-second windows with a parallelism of 2
+    fn default() -> Self {
+        Self {
+            rpc_url: SUI_MAINNET_URL.to_string(),
+            interval_ms: 500,
+            batch_size: 10,
+            window: Duration::from_secs(10),
+            parallelism: 2,
+        }
+    }
+}
+
+impl PipelineConfig {
+    /// Sets the Sui RPC endpoint URL
+    pub fn with_rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.rpc_url = rpc_url.into();
+        self
+    }
+
+    /// Sets the polling interval of the source
+    pub fn with_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.interval_ms = interval_ms;
+        self
+    }
+
+    /// Sets the maximum number of items fetched per poll
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets the length of the tumbling window
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Sets the parallelism of the stream
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+}
+
+/// Transaction source dropping transactions it already emitted
+///
+/// Digests are remembered over a bounded window of recent transactions, so a head
+/// page overlapping the previous polls only yields the transactions not seen yet.
+pub struct DistinctTransactions<S> {
+    /// Underlying transaction source
+    inner: S,
+    /// Recent digests, oldest first
+    order: VecDeque<String>,
+    /// Recent digests, for lookups
+    seen: HashSet<String>,
+    /// Number of recent digests remembered
+    capacity: usize,
+}
+
+impl<S> DistinctTransactions<S> {
+    /// Wraps a source, remembering the last `capacity` digests emitted
+    pub fn new(inner: S, capacity: usize) -> Self {
+        Self {
+            inner,
+            order: VecDeque::new(),
+            seen: HashSet::new(),
+            capacity: capacity.max(1),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> Source<Vec<SuiEvent>> for DistinctTransactions<S>
+where
+    S: Source<Vec<SuiEvent>> + Send,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<SuiEvent>>>> {
+        let Some(mut record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+        record
+            .data
+            .retain(|transaction| self.seen.insert(transaction.transaction_digest.clone()));
+        for transaction in &record.data {
+            self.order.push_back(transaction.transaction_digest.clone());
+        }
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        if record.data.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(record))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
+
+/// Initializes a head transaction source filling gaps and dropping repeats
+async fn distinct_transactions(
+    config: &PipelineConfig,
+) -> StreamResult<DistinctTransactions<SuiTransactionSource>> {
+    let depth = config.batch_size.saturating_mul(BACKFILL_PAGES);
+    let mut source = DistinctTransactions::new(
+        SuiTransactionSource::new(
+            config.rpc_url.clone(),
+            config.interval_ms,
+            config.batch_size,
+        )
+        .with_gap_backfill(depth),
+        depth.saturating_add(config.batch_size).saturating_mul(2),
+    );
+    source.init().await?;
+    Ok(source)
+}
+
+/// Counts transactions per transaction type within each window
+pub async fn transaction_type_pipeline(
+    config: &PipelineConfig,
+) -> StreamResult<DataStream<TypeCounts>> {
+    let source = distinct_transactions(config).await?;
+
+    Ok(DataStream::new(source)
+        .parallel(config.parallelism)
+        .window(WindowConfig::tumbling(config.window))
+        .aggregate(HashMap::new(), |mut counts: TypeCounts, transactions| {
+            for transaction in transactions {
+                *counts.entry(transaction.transaction_type).or_insert(0) += 1;
+            }
+            counts
+        }))
+}
+
+/// Counts events per Move event type within each window
+pub async fn event_type_pipeline(config: &PipelineConfig) -> StreamResult<DataStream<TypeCounts>> {
+    let mut source = SuiEventSource::new(
+        config.rpc_url.clone(),
+        config.interval_ms,
+        config.batch_size,
+    );
+    source.init().await?;
+
+    Ok(DataStream::new(source)
+        .parallel(config.parallelism)
+        .window(WindowConfig::tumbling(config.window))
+        .aggregate(HashMap::new(), |mut counts: TypeCounts, events| {
+            for event in events {
+                *counts.entry(event.event_type.to_string()).or_insert(0) += 1;
+            }
+            counts
+        }))
+}
+
+/// Counts the objects owned by an address per object type within each window
+pub async fn object_type_pipeline(
+    config: &PipelineConfig,
+    address: String,
+) -> StreamResult<DataStream<TypeCounts>> {
+    let mut source = SuiObjectSource::new(
+        config.rpc_url.clone(),
+        config.interval_ms,
+        address,
+        config.batch_size,
+    );
+    source.init().await?;
+
+    Ok(DataStream::new(source)
+        .parallel(config.parallelism)
+        .window(WindowConfig::tumbling(config.window))
+        .aggregate(HashMap::new(), |mut counts: TypeCounts, objects| {
+            for object in objects {
+                *counts.entry(object.object_type).or_insert(0) += 1;
+            }
+            counts
+        }))
+}
+
+/// Sums the absolute balance changes per coin type within each window
+///
+/// A transfer counts both the amount sent and the amount received, and gas
+/// payments count towards the SUI volume, as in `SuiVolumeSource`.
+pub async fn transfer_volume_pipeline(
+    config: &PipelineConfig,
+) -> StreamResult<DataStream<TransferVolumes>> {
+    let source = distinct_transactions(config).await?;
+
+    Ok(DataStream::new(source)
+        .parallel(config.parallelism)
+        .window(WindowConfig::tumbling(config.window))
+        .aggregate(
+            HashMap::new(),
+            |mut volumes: TransferVolumes, transactions| {
+                for transaction in transactions {
+                    for change in transaction.balance_changes {
+                        *volumes.entry(change.coin_type).or_insert(0) +=
+                            change.amount.unsigned_abs();
+                    }
+                }
+                volumes
+            },
+        ))
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How a source reacts to a failed item or request
+///
+/// Sources apply one policy to per-item decode errors and another to per-poll
+/// RPC errors. Decode errors default to `SkipAndLog`, routing the item to the
+/// dead-letter channel, and RPC errors default to `FailFast`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ErrorPolicy {
+    /// The error is returned from `next`
+    FailFast,
+    /// The item or poll is skipped and the error logged
+    SkipAndLog,
+    /// The item is refetched or the request reissued up to `retries` times, one
+    /// polling interval apart, then skipped
+    RetryThenSkip { retries: u32 },
+}
+
+/// Reaction to one failure under a policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Recovery {
+    Fail,
+    Skip,
+    Retry,
+}
+
+impl ErrorPolicy {
+    /// Reaction to a failure, given the failures of the same item or request so far
+    pub(crate) fn recovery(&self, failures: u32) -> Recovery {
+        match self {
+            ErrorPolicy::FailFast => Recovery::Fail,
+            ErrorPolicy::SkipAndLog => Recovery::Skip,
+            ErrorPolicy::RetryThenSkip { retries } if failures <= *retries => Recovery::Retry,
+            ErrorPolicy::RetryThenSkip { .. } => Recovery::Skip,
+        }
+    }
+}
+
+/// Exponential backoff for transient RPC failures such as rate limiting
+///
+/// The delay starts at `base_delay` and doubles with every retry, up to
+/// `max_delay`. Retries happen within the poll, before the RPC error policy sees
+/// the failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Requests per poll, the first one included
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound of the delay between retries
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Creates a backoff of up to `max_attempts` requests per poll
+    pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    /// Returns whether another request is allowed after `retries` retries
+    pub(crate) fn allows(&self, retries: u32) -> bool {
+        retries.saturating_add(1) < self.max_attempts
+    }
+
+    /// Delay before retry number `retry`, counted from one
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
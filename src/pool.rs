@@ -0,0 +1,236 @@
+use crate::rpc;
+use crate::scheduler::{Priority, SuiScheduler};
+use fluxus::utils::models::{StreamError, StreamResult};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use sui_sdk::{SuiClient, SuiClientBuilder};
+
+/// RPC endpoint of a pool
+struct Endpoint {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Relative share of polls
+    weight: i64,
+    /// Smooth weighted round-robin counter
+    current: i64,
+    /// Per-endpoint rate limit
+    limiter: Option<SuiScheduler>,
+    /// Sui client, None until connected
+    client: Option<SuiClient>,
+    /// Time until which the endpoint is skipped after a failure
+    unhealthy_until: Option<Instant>,
+}
+
+impl Endpoint {
+    fn is_healthy(&self, now: Instant) -> bool {
+        self.client.is_some() && self.unhealthy_until.is_none_or(|until| until <= now)
+    }
+}
+
+/// Endpoints spreading polls by weight, shared by every handle of a pool
+///
+/// Polls are assigned with smooth weighted round-robin, so an endpoint of weight 2
+/// serves two polls for every poll of an endpoint of weight 1, interleaved rather
+/// than in bursts. An endpoint whose request fails, or that cannot be connected, is
+/// skipped for a cooldown period, after which the next pick reconnects it if needed;
+/// when every endpoint is unhealthy all connected endpoints are used again. Cloning a pool yields a handle to the same endpoints.
+#[derive(Clone)]
+pub struct EndpointPool {
+    endpoints: Arc<Mutex<Vec<Endpoint>>>,
+    /// Time an endpoint is skipped after a failure
+    cooldown: Duration,
+}
+
+/// Endpoint picked for one poll
+pub(crate) struct PickedEndpoint {
+    /// Client of the endpoint
+    pub(crate) client: SuiClient,
+    /// Sui RPC endpoint URL
+    pub(crate) rpc_url: String,
+    /// Index of the endpoint within the pool
+    index: usize,
+    /// Pool the endpoint belongs to
+    pool: EndpointPool,
+}
+
+impl PickedEndpoint {
+    /// Marks the endpoint unhealthy after a failed request
+    pub(crate) fn mark_failed(&self) {
+        let mut endpoints = self.pool.lock();
+        let endpoint = &mut endpoints[self.index];
+        tracing::warn!(
+            "Endpoint {} failed, skipping it for {:?}",
+            endpoint.rpc_url,
+            self.pool.cooldown
+        );
+        endpoint.unhealthy_until = Some(Instant::now() + self.pool.cooldown);
+    }
+}
+
+impl EndpointPool {
+    /// Creates an empty pool skipping failed endpoints for 30 seconds
+    pub fn new() -> Self {
+        Self {
+            endpoints: Arc::new(Mutex::new(Vec::new())),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+
+    /// Adds an endpoint receiving `weight` shares of the polls
+    pub fn with_endpoint(self, rpc_url: impl Into<String>, weight: u32) -> Self {
+        self.push(rpc_url.into(), weight, None)
+    }
+
+    /// Adds an endpoint with its own rate limit
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `weight` - Relative share of the polls
+    /// * `requests_per_second` - Sustained request rate allowed by the endpoint
+    /// * `burst` - Maximum number of requests that can be issued back to back
+    pub fn with_rate_limited_endpoint(
+        self,
+        rpc_url: impl Into<String>,
+        weight: u32,
+        requests_per_second: f64,
+        burst: usize,
+    ) -> Self {
+        let limiter = SuiScheduler::new(requests_per_second, burst);
+        self.push(rpc_url.into(), weight, Some(limiter))
+    }
+
+    /// Sets how long a failed endpoint is skipped
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    fn push(self, rpc_url: String, weight: u32, limiter: Option<SuiScheduler>) -> Self {
+        self.lock().push(Endpoint {
+            rpc_url,
+            weight: i64::from(weight.max(1)),
+            current: 0,
+            limiter,
+            client: None,
+            unhealthy_until: None,
+        });
+        self
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Endpoint>> {
+        self.endpoints
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Connects every endpoint not connected yet, failing only if none is reachable
+    pub(crate) async fn connect(&self, source: &str) -> StreamResult<()> {
+        self.connect_pending(source).await;
+        if self.lock().iter().any(|endpoint| endpoint.client.is_some()) {
+            Ok(())
+        } else {
+            Err(StreamError::Runtime(format!(
+                "{} could not connect to any pool endpoint",
+                source
+            )))
+        }
+    }
+
+    /// Connects the endpoints without a client whose cooldown has expired
+    async fn connect_pending(&self, source: &str) {
+        let now = Instant::now();
+        let pending: Vec<(usize, String)> = self
+            .lock()
+            .iter()
+            .enumerate()
+            .filter(|(_, endpoint)| {
+                endpoint.client.is_none()
+                    && endpoint.unhealthy_until.is_none_or(|until| until <= now)
+            })
+            .map(|(index, endpoint)| (index, endpoint.rpc_url.clone()))
+            .collect();
+
+        for (index, rpc_url) in pending {
+            let result = rpc::call(
+                source,
+                "rpc.discover",
+                "initialize Sui client",
+                SuiClientBuilder::default().build(rpc_url.as_str()),
+            )
+            .await;
+            let mut endpoints = self.lock();
+            match result {
+                Ok(client) => {
+                    endpoints[index].client = Some(client);
+                    endpoints[index].unhealthy_until = None;
+                }
+                Err(_) => endpoints[index].unhealthy_until = Some(Instant::now() + self.cooldown),
+            }
+        }
+    }
+
+    /// Picks the next endpoint and waits for its rate limit
+    ///
+    /// Endpoints that could not be connected are reconnected once their cooldown expires.
+    pub(crate) async fn pick(&self) -> StreamResult<PickedEndpoint> {
+        self.connect_pending("EndpointPool").await;
+        let (index, client, rpc_url, limiter) = {
+            let mut endpoints = self.lock();
+            let now = Instant::now();
+            let any_healthy = endpoints.iter().any(|endpoint| endpoint.is_healthy(now));
+            let candidates: Vec<usize> = (0..endpoints.len())
+                .filter(|&index| {
+                    let endpoint = &endpoints[index];
+                    endpoint.client.is_some() && (!any_healthy || endpoint.is_healthy(now))
+                })
+                .collect();
+
+            // Smooth weighted round-robin over the candidates
+            let total: i64 = candidates
+                .iter()
+                .map(|&index| endpoints[index].weight)
+                .sum();
+            let mut chosen = None;
+            for &index in &candidates {
+                let endpoint = &mut endpoints[index];
+                endpoint.current += endpoint.weight;
+                if chosen.is_none_or(|(_, current)| endpoint.current > current) {
+                    chosen = Some((index, endpoint.current));
+                }
+            }
+            let (index, _) = chosen.ok_or_else(|| {
+                StreamError::Runtime("No connected endpoint in the pool".to_string())
+            })?;
+
+            let endpoint = &mut endpoints[index];
+            endpoint.current -= total;
+            let client = endpoint
+                .client
+                .clone()
+                .ok_or_else(|| StreamError::Runtime("Endpoint not connected".to_string()))?;
+            (
+                index,
+                client,
+                endpoint.rpc_url.clone(),
+                endpoint.limiter.clone(),
+            )
+        };
+
+        if let Some(limiter) = limiter {
+            limiter.acquire(Priority::High).await;
+        }
+
+        Ok(PickedEndpoint {
+            client,
+            rpc_url,
+            index,
+            pool: self.clone(),
+        })
+    }
+}
+
+impl Default for EndpointPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
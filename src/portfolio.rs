@@ -0,0 +1,273 @@
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use futures::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sui_sdk::rpc_types::{SuiObjectDataOptions, SuiObjectResponseQuery};
+use sui_sdk::types::base_types::{ObjectID, SuiAddress};
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+/// Move type prefix of coin objects, which are reported as balances rather than NFTs
+const COIN_TYPE_PREFIX: &str = "0x2::coin::Coin<";
+/// Move type of staked SUI objects, which are reported as stakes rather than NFTs
+const STAKED_SUI_TYPE: &str = "0x3::staking_pool::StakedSui";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PortfolioSnapshot {
+    /// Wallet address
+    pub address: String,
+    /// Coin balances (coin type -> total balance)
+    pub coin_balances: HashMap<String, u128>,
+    /// Staked SUI principal per validator (validator address -> amount in MIST)
+    pub staked_sui: HashMap<String, u64>,
+    /// NFT count by collection (object type -> count)
+    pub nft_counts: HashMap<String, usize>,
+    /// Timestamp
+    pub timestamp: u64,
+}
+
+/// Sui blockchain data source emitting consolidated portfolio snapshots for watched addresses
+pub struct SuiPortfolioSource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Addresses to watch
+    addresses: Vec<String>,
+    /// Maximum number of owned objects to scan per address
+    max_objects: usize,
+}
+
+impl SuiPortfolioSource {
+    /// Creates a new SuiPortfolioSource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `addresses` - Addresses to build portfolio snapshots for
+    pub fn new(rpc_url: String, interval_ms: u64, addresses: Vec<String>) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            addresses,
+            max_objects: 500,
+        }
+    }
+
+    /// Creates a new SuiPortfolioSource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64, addresses: Vec<String>) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms, addresses)
+    }
+
+    /// Sets the maximum number of owned objects scanned per address when counting NFTs
+    pub fn with_max_objects(mut self, max_objects: usize) -> Self {
+        self.max_objects = max_objects;
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Builds the portfolio snapshot of a single address
+    async fn fetch_snapshot(
+        client: &SuiClient,
+        address: &str,
+        max_objects: usize,
+    ) -> StreamResult<PortfolioSnapshot> {
+        let owner = SuiAddress::from_str(address).map_err(|e| {
+            tracing::error!("Invalid watched address: {}", e);
+            StreamError::Runtime(format!("Invalid watched address: {}", e))
+        })?;
+
+        let (balances, stakes, nft_counts) = tokio::try_join!(
+            Self::fetch_balances(client, owner),
+            Self::fetch_stakes(client, owner),
+            Self::fetch_nft_counts(client, owner, max_objects),
+        )?;
+
+        Ok(PortfolioSnapshot {
+            address: address.to_string(),
+            coin_balances: balances,
+            staked_sui: stakes,
+            nft_counts,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+        })
+    }
+
+    async fn fetch_balances(
+        client: &SuiClient,
+        owner: SuiAddress,
+    ) -> StreamResult<HashMap<String, u128>> {
+        let balances = rpc::call(
+            "SuiPortfolioSource",
+            "suix_getAllBalances",
+            "fetch balances",
+            client.coin_read_api().get_all_balances(owner),
+        )
+        .await?;
+
+        Ok(balances
+            .into_iter()
+            .map(|balance| (balance.coin_type, balance.total_balance))
+            .collect())
+    }
+
+    async fn fetch_stakes(
+        client: &SuiClient,
+        owner: SuiAddress,
+    ) -> StreamResult<HashMap<String, u64>> {
+        let delegated = rpc::call(
+            "SuiPortfolioSource",
+            "suix_getStakes",
+            "fetch stakes",
+            client.governance_api().get_stakes(owner),
+        )
+        .await?;
+
+        let mut stakes = HashMap::new();
+        for delegation in delegated {
+            let principal: u64 = delegation.stakes.iter().map(|stake| stake.principal).sum();
+            *stakes
+                .entry(delegation.validator_address.to_string())
+                .or_insert(0) += principal;
+        }
+        Ok(stakes)
+    }
+
+    async fn fetch_nft_counts(
+        client: &SuiClient,
+        owner: SuiAddress,
+        max_objects: usize,
+    ) -> StreamResult<HashMap<String, usize>> {
+        let query =
+            SuiObjectResponseQuery::new_with_options(SuiObjectDataOptions::new().with_type());
+        let mut counts = HashMap::new();
+        let mut cursor: Option<ObjectID> = None;
+        let mut scanned = 0;
+
+        // Paginate owned objects until exhausted or the scan budget is spent
+        while scanned < max_objects {
+            let page = rpc::call(
+                "SuiPortfolioSource",
+                "suix_getOwnedObjects",
+                "fetch objects",
+                client.read_api().get_owned_objects(
+                    owner,
+                    Some(query.clone()),
+                    cursor,
+                    Some(max_objects - scanned),
+                ),
+            )
+            .await?;
+
+            scanned += page.data.len();
+            for object in page.data.into_iter().filter_map(|object| object.data) {
+                let Some(object_type) = object.type_.map(|t| t.to_string()) else {
+                    continue;
+                };
+                if object_type.starts_with(COIN_TYPE_PREFIX) || object_type == STAKED_SUI_TYPE {
+                    continue;
+                }
+                *counts.entry(object_type).or_insert(0) += 1;
+            }
+
+            if !page.has_next_page || page.next_cursor.is_none() {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        Ok(counts)
+    }
+}
+
+#[async_trait]
+impl Source<Vec<PortfolioSnapshot>> for SuiPortfolioSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client
+        let client = rpc::call(
+            "SuiPortfolioSource",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(self.rpc_url.as_str()),
+        )
+        .await?;
+
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiPortfolioSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<PortfolioSnapshot>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiPortfolioSource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        let client = self.client.as_ref().ok_or_else(|| {
+            StreamError::Runtime("SuiPortfolioSource client not available".to_string())
+        })?;
+
+        // Return None if no addresses are watched
+        if self.addresses.is_empty() {
+            tracing::info!("No addresses to build portfolios for");
+            return Ok(None);
+        }
+
+        // Assemble all snapshots concurrently
+        let snapshots = try_join_all(
+            self.addresses
+                .iter()
+                .map(|address| Self::fetch_snapshot(client, address, self.max_objects)),
+        )
+        .await?;
+
+        for snapshot in &snapshots {
+            tracing::debug!(
+                "Built Sui portfolio for: {} coins: {} validators: {} collections: {}",
+                snapshot.address,
+                snapshot.coin_balances.len(),
+                snapshot.staked_sui.len(),
+                snapshot.nft_counts.len()
+            );
+        }
+
+        Ok(Some(Record::new(snapshots)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiPortfolioSource closed");
+        Ok(())
+    }
+}
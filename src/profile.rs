@@ -0,0 +1,70 @@
+use std::time::Instant;
+
+/// Phase of a poll timed by the `profiling` feature
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Phase {
+    /// RPC requests of the poll, including retries
+    Fetch,
+    /// Conversion of the fetched items into records
+    Decode,
+    /// Ordering, enrichment, redaction and batching of the records
+    Emit,
+}
+
+impl Phase {
+    fn name(self) -> &'static str {
+        match self {
+            Phase::Fetch => "fetch",
+            Phase::Decode => "decode",
+            Phase::Emit => "emit",
+        }
+    }
+}
+
+/// Times a poll phase, reporting it when dropped
+///
+/// Timings are logged at debug level under the `fluxus_source_sui::profile`
+/// target, with the source, phase, item count and elapsed microseconds as fields.
+/// Phases cut short by an early return are reported too. Without the `profiling`
+/// feature the timer does nothing.
+pub(crate) struct PhaseTimer {
+    /// Source the phase belongs to
+    source: &'static str,
+    phase: Phase,
+    /// Items handled by the phase
+    items: usize,
+    /// Start of the phase, None when profiling is disabled
+    started: Option<Instant>,
+}
+
+impl PhaseTimer {
+    pub(crate) fn start(source: &'static str, phase: Phase) -> Self {
+        Self {
+            source,
+            phase,
+            items: 0,
+            started: cfg!(feature = "profiling").then(Instant::now),
+        }
+    }
+
+    /// Sets the number of items handled, reported with the timing
+    pub(crate) fn items(&mut self, items: usize) {
+        self.items = items;
+    }
+}
+
+impl Drop for PhaseTimer {
+    fn drop(&mut self) {
+        let Some(started) = self.started else {
+            return;
+        };
+        tracing::debug!(
+            target: "fluxus_source_sui::profile",
+            source = self.source,
+            phase = self.phase.name(),
+            items = self.items,
+            elapsed_us = started.elapsed().as_micros() as u64,
+            "Poll phase finished"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::event::ChainEvent;
+use crate::object::ChainObject;
+use crate::transaction::SuiEvent;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// How a field is redacted before emission
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Redaction {
+    /// The field is emitted unchanged
+    #[default]
+    Keep,
+    /// Addresses are replaced by a salted SHA-256, so equal inputs still join
+    Hash,
+    /// Addresses keep only their first and last characters
+    Mask,
+}
+
+/// Per-field redaction applied to records before emission
+///
+/// - `sender`: transaction and event senders
+/// - `recipient`: object owners and balance change owners
+/// - `metadata`: structured payloads that embed addresses (transaction input,
+///   effects, raw BCS, event JSON, object content and enrichments). These cannot
+///   be rewritten field by field, so `Hash` replaces event data with its digest and
+///   anything other than `Keep` removes the structured payloads.
+#[derive(Clone, Debug, Default)]
+pub struct RedactionConfig {
+    /// Redaction of sender addresses
+    pub sender: Redaction,
+    /// Redaction of recipient and owner addresses
+    pub recipient: Redaction,
+    /// Redaction of structured payloads
+    pub metadata: Redaction,
+    /// Salt mixed into hashes so they cannot be reversed with a table of known addresses
+    pub salt: String,
+}
+
+impl RedactionConfig {
+    /// Redacts a single value according to the given mode
+    pub fn redact_value(&self, mode: Redaction, value: &str) -> String {
+        match mode {
+            Redaction::Keep => value.to_string(),
+            Redaction::Hash => {
+                let mut hasher = Sha256::new();
+                hasher.update(self.salt.as_bytes());
+                hasher.update(value.as_bytes());
+                format!("{:x}", hasher.finalize())
+            }
+            Redaction::Mask => {
+                let chars: Vec<char> = value.chars().collect();
+                if chars.len() <= 10 {
+                    "*".repeat(chars.len())
+                } else {
+                    let head: String = chars[..6].iter().collect();
+                    let tail: String = chars[chars.len() - 4..].iter().collect();
+                    format!("{}...{}", head, tail)
+                }
+            }
+        }
+    }
+}
+
+/// Records whose identifying fields can be redacted
+pub trait Redact {
+    /// Rewrites the record in place according to the configuration
+    fn redact(&mut self, config: &RedactionConfig);
+}
+
+impl Redact for SuiEvent {
+    fn redact(&mut self, config: &RedactionConfig) {
+        self.sender = config.redact_value(config.sender, &self.sender);
+        if config.metadata != Redaction::Keep {
+            self.metadata = None;
+            self.effects = None;
+            self.raw_transaction = None;
+            self.raw_effects = None;
+            self.enrichments.clear();
+        }
+        for change in &mut self.balance_changes {
+            change.owner = config.redact_value(config.recipient, &change.owner);
+        }
+        for event in &mut self.events {
+            event.redact(config);
+        }
+    }
+}
+
+impl Redact for ChainEvent {
+    fn redact(&mut self, config: &RedactionConfig) {
+        self.sender = config.redact_value(config.sender, &self.sender);
+        if config.metadata != Redaction::Keep {
+            self.data = config.redact_value(config.metadata, &self.data);
+            self.parsed_json = serde_json::Value::Null;
+            self.enrichments.clear();
+        }
+    }
+}
+
+impl Redact for ChainObject {
+    fn redact(&mut self, config: &RedactionConfig) {
+        self.owner = config.redact_value(config.recipient, &self.owner);
+        if config.recipient != Redaction::Keep {
+            self.owner_kind = None;
+            self.data.owner = None;
+        }
+        if config.metadata != Redaction::Keep {
+            self.data.content = None;
+            self.data.bcs = None;
+            self.data.display = None;
+            self.content_diff = None;
+            self.enrichments.clear();
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::event::{ChainEvent, SuiEventSource};
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use sui_sdk::types::parse_sui_struct_tag;
+
+type Decoder<E> = Box<dyn Fn(&serde_json::Value) -> Result<E, serde_json::Error> + Send + Sync>;
+
+/// Registry mapping Move event type tags to Rust types
+///
+/// Each registered type tag decodes the event's parsed JSON into a caller-supplied
+/// struct and wraps it into `E`, typically an enum with one variant per event type:
+///
+/// ```rust,no_run
+/// use fluxus_source_sui::{MoveEventRegistry, SuiEventSource};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct SwapEvent {
+///     amount_in: String,
+///     amount_out: String,
+/// }
+///
+/// enum PoolEvent {
+///     Swap(SwapEvent),
+/// }
+///
+/// let registry = MoveEventRegistry::new().register("0xabc::pool::SwapEvent", PoolEvent::Swap);
+/// let source = SuiEventSource::new_with_mainnet(1000, 50).decoded(registry);
+/// ```
+///
+/// Type tags are matched on their normalized form first, then without type
+/// parameters, so registering `0xabc::pool::Pool` also matches `0xabc::pool::Pool<T>`.
+pub struct MoveEventRegistry<E> {
+    decoders: HashMap<String, Decoder<E>>,
+}
+
+impl<E> MoveEventRegistry<E> {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registers a Move event type decoded into `T` and wrapped by `wrap`
+    pub fn register<T, F>(mut self, event_type: &str, wrap: F) -> Self
+    where
+        T: DeserializeOwned,
+        F: Fn(T) -> E + Send + Sync + 'static,
+    {
+        self.decoders.insert(
+            normalize_type_tag(event_type),
+            Box::new(move |json| serde_json::from_value(json.clone()).map(&wrap)),
+        );
+        self
+    }
+
+    /// Returns whether a decoder is registered for the event type
+    pub fn is_registered(&self, event_type: &str) -> bool {
+        self.decoder(event_type).is_some()
+    }
+
+    /// Decodes an event, returning None if its type is not registered
+    pub fn decode(&self, event: &ChainEvent) -> Option<Result<E, serde_json::Error>> {
+        self.decoder(&event.event_type.to_string())
+            .map(|decoder| decoder(&event.parsed_json))
+    }
+
+    fn decoder(&self, event_type: &str) -> Option<&Decoder<E>> {
+        let normalized = normalize_type_tag(event_type);
+        self.decoders.get(&normalized).or_else(|| {
+            normalized
+                .split_once('<')
+                .and_then(|(base, _)| self.decoders.get(base))
+        })
+    }
+}
+
+impl<E> Default for MoveEventRegistry<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Normalizes a type tag so short and long address forms compare equal
+fn normalize_type_tag(event_type: &str) -> String {
+    parse_sui_struct_tag(event_type)
+        .map(|tag| tag.to_string())
+        .unwrap_or_else(|_| event_type.to_string())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecodedEvent<E> {
+    /// Raw chain event
+    pub event: ChainEvent,
+    /// Decoded event, None if the type is not registered or failed to decode
+    pub decoded: Option<E>,
+}
+
+/// Event source decoding events of registered Move types
+pub struct DecodedEventSource<E> {
+    /// Underlying event source
+    inner: SuiEventSource,
+    /// Registered decoders
+    registry: MoveEventRegistry<E>,
+}
+
+impl<E> DecodedEventSource<E> {
+    /// Creates a new DecodedEventSource wrapping an event source
+    pub fn new(inner: SuiEventSource, registry: MoveEventRegistry<E>) -> Self {
+        Self { inner, registry }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl<E> Source<Vec<DecodedEvent<E>>> for DecodedEventSource<E>
+where
+    E: Send + Sync + 'static,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<DecodedEvent<E>>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let events = record
+            .data
+            .into_iter()
+            .map(|event| {
+                let decoded = match self.registry.decode(&event) {
+                    Some(Ok(decoded)) => Some(decoded),
+                    Some(Err(e)) => {
+                        tracing::warn!(
+                            "Failed to decode event {} of type {}: {}",
+                            event.id.tx_digest,
+                            event.event_type,
+                            e
+                        );
+                        None
+                    }
+                    None => None,
+                };
+                DecodedEvent { event, decoded }
+            })
+            .collect();
+
+        Ok(Some(Record::new(events)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,117 @@
+use crate::scheduler::{Priority, SuiScheduler};
+use fluxus::utils::models::{StreamError, StreamResult};
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::Instrument;
+
+/// Largest page standard fullnodes return for query methods
+///
+/// Fullnodes enforce this limit without publishing it over JSON-RPC, and reject
+/// larger requests, so it cannot be probed. Sources assume it unless configured
+/// with the limit of their node through `with_node_page_limit`.
+pub(crate) const QUERY_MAX_RESULT_LIMIT: usize = 50;
+
+/// Checks a configured page size against the node limit, logging the effective value
+///
+/// A page size or limit of zero is rejected, and a page size above the limit is
+/// lowered to it.
+pub(crate) fn check_page_size(
+    source: &str,
+    name: &str,
+    configured: usize,
+    limit: usize,
+) -> StreamResult<usize> {
+    if configured == 0 {
+        return Err(StreamError::Runtime(format!(
+            "{} {} must be at least 1",
+            source, name
+        )));
+    }
+    if limit == 0 {
+        return Err(StreamError::Runtime(format!(
+            "{} node page limit must be at least 1",
+            source
+        )));
+    }
+    let effective = configured.min(limit);
+    if effective != configured {
+        tracing::warn!(
+            "{} {} of {} is above the node limit of {}, using {}",
+            source,
+            name,
+            configured,
+            limit,
+            effective
+        );
+    } else {
+        tracing::info!("{} {}: {}", source, name, effective);
+    }
+    Ok(effective)
+}
+
+tokio::task_local! {
+    /// Request budget of the source whose poll is running on the task
+    static BUDGET: Option<(SuiScheduler, Priority)>;
+}
+
+/// Runs a poll whose every `call` first waits for the source's request budget
+///
+/// A poll may issue many requests, e.g. pagination, retries and enrichment, so
+/// the budget is drawn from per request rather than once per poll.
+pub(crate) async fn with_budget<F: Future>(
+    budget: Option<(SuiScheduler, Priority)>,
+    poll: F,
+) -> F::Output {
+    BUDGET.scope(budget, poll).await
+}
+
+/// Counter shared by all sources so correlation IDs are unique within the process
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Returns a process-unique correlation ID for an outgoing RPC call
+///
+/// IDs have the form `<source>-<sequence>`, e.g. `SuiEventSource-42`.
+pub fn next_correlation_id(source: &str) -> String {
+    format!(
+        "{}-{}",
+        source,
+        NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Runs an RPC call inside a `sui_rpc` span tagged with a correlation ID
+///
+/// The span carries `source`, `method` and `correlation_id` fields, and a failure
+/// is returned as a `StreamError` whose message includes the same ID, so an error
+/// can be matched with the span's logs. The ID is not sent to the node. Failures
+/// are only logged at debug level, since callers handle many of them, such as
+/// probes and retried requests, and log the rest at their own level.
+pub(crate) async fn call<T, E, F>(
+    source: &str,
+    method: &str,
+    action: &str,
+    request: F,
+) -> StreamResult<T>
+where
+    E: Display,
+    F: Future<Output = Result<T, E>>,
+{
+    // Wait for the budget of the polling source, if it shares one
+    if let Some((scheduler, priority)) = BUDGET.try_with(Clone::clone).ok().flatten() {
+        scheduler.acquire(priority).await;
+    }
+
+    let correlation_id = next_correlation_id(source);
+    let span = tracing::info_span!(
+        "sui_rpc",
+        source,
+        method,
+        correlation_id = %correlation_id
+    );
+
+    request.instrument(span).await.map_err(|e| {
+        tracing::debug!("Failed to {} [{}]: {}", action, correlation_id, e);
+        StreamError::Runtime(format!("Failed to {} [{}]: {}", action, correlation_id, e))
+    })
+}
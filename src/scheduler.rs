@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+/// Priority class of a source registered with a `SuiScheduler`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Priority {
+    /// May use the whole request budget
+    High,
+    /// Leaves a quarter of the burst capacity to high priority sources
+    #[default]
+    Normal,
+    /// Only polls while at least half of the burst capacity is available
+    Low,
+}
+
+impl Priority {
+    /// Fraction of the burst capacity this class must leave untouched
+    fn reserve(self) -> f64 {
+        match self {
+            Priority::High => 0.0,
+            Priority::Normal => 0.25,
+            Priority::Low => 0.5,
+        }
+    }
+
+    /// Position of the class's queue in `Budget::queues`
+    fn index(self) -> usize {
+        match self {
+            Priority::High => 0,
+            Priority::Normal => 1,
+            Priority::Low => 2,
+        }
+    }
+}
+
+/// Tickets of the requests waiting in one priority class, served in order
+#[derive(Default)]
+struct Queue {
+    /// Ticket handed to the next request
+    next: u64,
+    /// Ticket of the request served next
+    serving: u64,
+    /// Tickets of requests that stopped waiting before their turn
+    abandoned: BTreeSet<u64>,
+}
+
+impl Queue {
+    /// Moves to the next ticket still waiting
+    fn advance(&mut self) {
+        self.serving += 1;
+        while self.abandoned.remove(&self.serving) {
+            self.serving += 1;
+        }
+    }
+}
+
+/// Request budget state shared by every handle of a scheduler
+struct Budget {
+    /// Requests replenished per second
+    requests_per_second: f64,
+    /// Maximum number of requests that can be issued back to back
+    burst: f64,
+    /// Available requests
+    tokens: f64,
+    /// Last time tokens were replenished
+    last_refill: Instant,
+    /// Waiting requests by priority class
+    queues: [Queue; 3],
+}
+
+impl Budget {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.requests_per_second).min(self.burst);
+        self.last_refill = now;
+    }
+}
+
+/// Process-wide coordinator of request budgets across sources sharing one provider
+///
+/// Every source configured with the same scheduler draws from one token bucket
+/// before each request, so a fleet of sources never exceeds the provider's rate
+/// limit. Lower priority classes keep a reserve of the bucket free for higher ones,
+/// requests within a class are served in the order they arrive, so a busy source
+/// cannot starve the others, and a source waiting for budget sleeps instead of
+/// issuing requests that would be rejected with 429s. Cloning a scheduler yields a handle to the same budget.
+#[derive(Clone)]
+pub struct SuiScheduler {
+    budget: Arc<Mutex<Budget>>,
+}
+
+impl SuiScheduler {
+    /// Creates a new SuiScheduler
+    ///
+    /// # Parameters
+    /// * `requests_per_second` - Sustained request rate shared by all sources
+    /// * `burst` - Maximum number of requests that can be issued back to back
+    pub fn new(requests_per_second: f64, burst: usize) -> Self {
+        let burst = burst.max(1) as f64;
+        Self {
+            budget: Arc::new(Mutex::new(Budget {
+                requests_per_second: requests_per_second.max(f64::MIN_POSITIVE),
+                burst,
+                tokens: burst,
+                last_refill: Instant::now(),
+                queues: Default::default(),
+            })),
+        }
+    }
+
+    /// Returns the process-wide scheduler (10 requests per second, burst of 20)
+    pub fn global() -> Self {
+        static GLOBAL: OnceLock<SuiScheduler> = OnceLock::new();
+        GLOBAL.get_or_init(|| SuiScheduler::new(10.0, 20)).clone()
+    }
+
+    /// Waits until the budget allows one request for the given priority class
+    ///
+    /// Requests of a class are granted in the order they call `acquire`.
+    pub async fn acquire(&self, priority: Priority) {
+        let mut ticket = Ticket {
+            scheduler: self,
+            priority,
+            number: {
+                let mut budget = self.lock();
+                let queue = &mut budget.queues[priority.index()];
+                queue.next += 1;
+                queue.next - 1
+            },
+            served: false,
+        };
+
+        loop {
+            let wait = {
+                let mut budget = self.lock();
+                budget.refill();
+
+                let required = (1.0 + priority.reserve() * budget.burst).min(budget.burst);
+                let ahead = ticket.number - budget.queues[priority.index()].serving;
+                if ahead == 0 && budget.tokens >= required {
+                    budget.tokens -= 1.0;
+                    budget.queues[priority.index()].advance();
+                    ticket.served = true;
+                    return;
+                }
+                // Requests ahead in the class are served first
+                let needed = required + ahead as f64 - budget.tokens;
+                Duration::from_secs_f64(needed.max(0.0) / budget.requests_per_second)
+                    .max(Duration::from_millis(1))
+            };
+
+            tracing::debug!("Request budget exhausted, waiting {:?}", wait);
+            sleep(wait).await;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Budget> {
+        self.budget.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the number of requests currently available
+    pub fn available(&self) -> f64 {
+        let mut budget = self.lock();
+        budget.refill();
+        budget.tokens
+    }
+}
+
+/// Place of an `acquire` call in its class's queue, given up if the call is dropped
+struct Ticket<'a> {
+    /// Scheduler the ticket was taken from
+    scheduler: &'a SuiScheduler,
+    /// Class whose queue holds the ticket
+    priority: Priority,
+    /// Position in the queue
+    number: u64,
+    /// Whether the request was granted
+    served: bool,
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        if self.served {
+            return;
+        }
+        let mut budget = self.scheduler.lock();
+        let queue = &mut budget.queues[self.priority.index()];
+        if queue.serving == self.number {
+            queue.advance();
+        } else {
+            queue.abandoned.insert(self.number);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::policy::RetryConfig;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, oneshot};
+use tokio::time::sleep;
+
+/// Polling task, stopped once the shared source and every subscription are dropped
+///
+/// Dropping the sender wakes the task, which closes the wrapped source and exits.
+struct PollTask {
+    /// Sender whose drop stops the task
+    _stop: oneshot::Sender<()>,
+}
+
+/// Source polled once and fanned out to several subscribers
+///
+/// A background task polls the wrapped source and broadcasts every record to the
+/// subscriptions returned by `subscribe`, so several pipelines watching the same
+/// data issue one set of queries. Each subscription buffers up to `buffer` records;
+/// a subscriber falling further behind skips the oldest records and logs a warning.
+/// Subscriptions only receive records polled after they were created.
+///
+/// A failed poll is broadcast as an error and the next one waits for the delay
+/// of the retry config, growing with consecutive failures. Once dropped, the
+/// task closes the wrapped source.
+///
+/// ```rust,no_run
+/// use fluxus_source_sui::{SharedSuiSource, SuiEventSource};
+///
+/// # async fn run() -> fluxus::utils::models::StreamResult<()> {
+/// let shared = SharedSuiSource::start(SuiEventSource::new_with_mainnet(1000, 50), 64).await?;
+/// let alerts = shared.subscribe();
+/// let analytics = shared.subscribe();
+/// # Ok(())
+/// # }
+/// ```
+pub struct SharedSuiSource<T> {
+    /// Sender of polled records, or of the message of a failed poll
+    sender: broadcast::Sender<Result<T, String>>,
+    /// Background polling task
+    task: Arc<PollTask>,
+}
+
+impl<T> SharedSuiSource<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Initializes the source and starts polling it in the background
+    ///
+    /// # Parameters
+    /// * `source` - Source polled on behalf of every subscriber
+    /// * `buffer` - Records buffered per subscription
+    pub async fn start<S>(source: S, buffer: usize) -> StreamResult<Self>
+    where
+        S: Source<T> + Send + 'static,
+    {
+        Self::start_with_retry(source, buffer, RetryConfig::default()).await
+    }
+
+    /// Initializes the source and starts polling it, backing off failed polls by `retry`
+    ///
+    /// Only the delays are used, since the task keeps polling until it is dropped.
+    pub async fn start_with_retry<S>(
+        mut source: S,
+        buffer: usize,
+        retry: RetryConfig,
+    ) -> StreamResult<Self>
+    where
+        S: Source<T> + Send + 'static,
+    {
+        source.init().await?;
+        let (sender, _) = broadcast::channel(buffer.max(1));
+        let (stop, stopped) = oneshot::channel();
+        tokio::spawn(poll(source, sender.clone(), retry, stopped));
+
+        Ok(Self {
+            sender,
+            task: Arc::new(PollTask { _stop: stop }),
+        })
+    }
+
+    /// Returns a new subscription receiving the records polled from now on
+    pub fn subscribe(&self) -> SharedSubscription<T> {
+        SharedSubscription {
+            receiver: self.sender.subscribe(),
+            _task: self.task.clone(),
+        }
+    }
+
+    /// Returns the number of live subscriptions
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+/// Polls the source until the task is stopped, broadcasting every result, then closes it
+async fn poll<T, S>(
+    mut source: S,
+    sender: broadcast::Sender<Result<T, String>>,
+    retry: RetryConfig,
+    mut stopped: oneshot::Receiver<()>,
+) where
+    S: Source<T>,
+{
+    let mut failures = 0;
+    loop {
+        let result = tokio::select! {
+            result = source.next() => result,
+            _ = &mut stopped => break,
+        };
+        let result = match result {
+            Ok(Some(record)) => Ok(record.data),
+            Ok(None) => {
+                failures = 0;
+                continue;
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        let failed = result.is_err();
+        // Nobody is subscribed yet or anymore, the result is dropped
+        let _ = sender.send(result);
+        if !failed {
+            failures = 0;
+            continue;
+        }
+
+        failures += 1;
+        let delay = retry.delay(failures);
+        tracing::warn!(
+            "Shared source poll failed {} times in a row, retrying in {:?}",
+            failures,
+            delay
+        );
+        tokio::select! {
+            _ = sleep(delay) => {}
+            _ = &mut stopped => break,
+        }
+    }
+
+    if let Err(e) = source.close().await {
+        tracing::warn!("Closing shared source failed: {}", e);
+    }
+}
+
+/// Subscriber of a `SharedSuiSource`, itself a Fluxus source
+pub struct SharedSubscription<T> {
+    /// Receiver of the broadcast records
+    receiver: broadcast::Receiver<Result<T, String>>,
+    /// Keeps the polling task alive while subscribed
+    _task: Arc<PollTask>,
+}
+
+#[async_trait]
+impl<T> Source<T> for SharedSubscription<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<T>>> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(Ok(data)) => return Ok(Some(Record::new(data))),
+                Ok(Err(message)) => return Err(StreamError::Runtime(message)),
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(
+                        "Shared source subscriber lagged, skipped {} records",
+                        skipped
+                    );
+                }
+                Err(RecvError::Closed) => {
+                    return Err(StreamError::Runtime(
+                        "Shared source stopped polling".to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+/// Runtime counters of a source, serializable for health endpoints
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceStats {
+    /// Number of polls issued
+    pub polls: u64,
+    /// Number of records emitted
+    pub records_emitted: u64,
+    /// Approximate JSON-encoded size of the fetched pages
+    ///
+    /// Counting re-serializes every page, so it stays zero unless enabled with
+    /// `with_byte_stats` on the source.
+    pub bytes_fetched: u64,
+    /// Items dropped as duplicates of already emitted ones
+    pub duplicates_dropped: u64,
+    /// Errors by kind, e.g. `rpc` or `conversion`
+    pub errors: BTreeMap<String, u64>,
+    /// Position of the last emitted item, e.g. a transaction digest
+    pub cursor: Option<String>,
+    /// Time since initialization (milliseconds)
+    pub uptime_ms: u64,
+}
+
+/// Accumulates the stats of one source
+#[derive(Default)]
+pub(crate) struct StatsRecorder {
+    /// Initialization time
+    started: Option<Instant>,
+    /// Counters so far
+    stats: SourceStats,
+    /// Whether fetched pages are serialized to count their size
+    count_bytes: bool,
+}
+
+impl StatsRecorder {
+    pub(crate) fn start(&mut self) {
+        self.started = Some(Instant::now());
+    }
+
+    pub(crate) fn poll(&mut self) {
+        self.stats.polls += 1;
+    }
+
+    pub(crate) fn count_bytes(&mut self, enabled: bool) {
+        self.count_bytes = enabled;
+    }
+
+    /// Adds the encoded size of a fetched page when byte counting is enabled
+    pub(crate) fn fetched<T: Serialize>(&mut self, page: &T) {
+        if !self.count_bytes {
+            return;
+        }
+        if let Ok(bytes) = serde_json::to_vec(page) {
+            self.stats.bytes_fetched += bytes.len() as u64;
+        }
+    }
+
+    pub(crate) fn emitted(&mut self, records: usize) {
+        self.stats.records_emitted += records as u64;
+    }
+
+    pub(crate) fn duplicates(&mut self, dropped: usize) {
+        self.stats.duplicates_dropped += dropped as u64;
+    }
+
+    pub(crate) fn error(&mut self, kind: &str) {
+        self.errors(kind, 1);
+    }
+
+    pub(crate) fn errors(&mut self, kind: &str, count: usize) {
+        if count > 0 {
+            *self.stats.errors.entry(kind.to_string()).or_insert(0) += count as u64;
+        }
+    }
+
+    pub(crate) fn set_cursor(&mut self, cursor: Option<String>) {
+        self.stats.cursor = cursor;
+    }
+
+    pub(crate) fn snapshot(&self) -> SourceStats {
+        SourceStats {
+            uptime_ms: self
+                .started
+                .map(|started| started.elapsed().as_millis() as u64)
+                .unwrap_or_default(),
+            ..self.stats.clone()
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::connection::SuiConnection;
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::{
+    SuiExecutionStatus, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponseOptions,
+};
+use sui_sdk::types::crypto::Signature;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::quorum_driver_types::ExecuteTransactionRequestType;
+use sui_sdk::types::transaction::{Transaction, TransactionData};
+use tokio::time::sleep;
+
+/// Stage reached by a submitted transaction, emitted in this order
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LifecycleState {
+    /// The signed transaction was accepted for execution
+    Submitted {
+        /// Transaction digest
+        digest: String,
+    },
+    /// Validators certified the effects, so the outcome can no longer change
+    Executed {
+        /// Transaction digest
+        digest: String,
+        /// Whether execution succeeded
+        success: bool,
+        /// Execution error, None on success
+        error: Option<String>,
+    },
+    /// The transaction was included in a checkpoint
+    Checkpointed {
+        /// Transaction digest
+        digest: String,
+        /// Checkpoint sequence number
+        checkpoint: u64,
+    },
+    /// The connected fullnode executed the checkpoint, so its reads reflect the transaction
+    Finalized {
+        /// Transaction digest
+        digest: String,
+        /// Checkpoint sequence number
+        checkpoint: u64,
+    },
+}
+
+/// Produces the signature authorizing a transaction
+#[async_trait]
+pub trait TransactionSigner: Send + Sync {
+    /// Signs the intent message of the transaction data on behalf of its sender
+    async fn sign(&self, data: &TransactionData) -> StreamResult<Signature>;
+}
+
+/// Signs and submits a transaction, returning a source emitting its lifecycle
+///
+/// Returns once validators certified the effects, with `Submitted` and
+/// `Executed` already pending. The returned source then polls every
+/// `interval_ms` until the transaction is checkpointed and finalized, and
+/// yields None from then on.
+///
+/// # Parameters
+/// * `connection` - Connection the transaction is submitted and tracked through
+/// * `data` - Unsigned transaction
+/// * `signer` - Signer of the transaction sender
+/// * `interval_ms` - Polling interval in milliseconds
+pub async fn submit(
+    connection: &SuiConnection,
+    data: TransactionData,
+    signer: &dyn TransactionSigner,
+    interval_ms: u64,
+) -> StreamResult<SuiSubmissionSource> {
+    let signature = signer.sign(&data).await?;
+    let transaction = Transaction::from_data(data, vec![signature]);
+    let digest = *transaction.digest();
+
+    let response = rpc::call(
+        "SuiSubmissionSource",
+        "sui_executeTransactionBlock",
+        "execute transaction",
+        connection
+            .client()
+            .quorum_driver_api()
+            .execute_transaction_block(
+                transaction,
+                SuiTransactionBlockResponseOptions::new().with_effects(),
+                Some(ExecuteTransactionRequestType::WaitForEffectsCert),
+            ),
+    )
+    .await?;
+    let effects = response.effects.as_ref().ok_or_else(|| {
+        StreamError::Runtime(format!("Transaction {} executed without effects", digest))
+    })?;
+    let (success, error) = match effects.status() {
+        SuiExecutionStatus::Success => (true, None),
+        SuiExecutionStatus::Failure { error } => (false, Some(error.clone())),
+    };
+    tracing::info!("Submitted Sui transaction: {} success: {}", digest, success);
+
+    let mut source = SuiSubmissionSource {
+        client: connection.client().clone(),
+        interval: Duration::from_millis(interval_ms),
+        digest,
+        pending: vec![
+            LifecycleState::Submitted {
+                digest: digest.to_string(),
+            },
+            LifecycleState::Executed {
+                digest: digest.to_string(),
+                success,
+                error,
+            },
+        ],
+        checkpoint: None,
+        finalized: false,
+    };
+    if let Some(checkpoint) = response.checkpoint {
+        source.checkpointed(checkpoint);
+    }
+    Ok(source)
+}
+
+/// Source emitting the lifecycle of a transaction submitted with `submit`
+pub struct SuiSubmissionSource {
+    /// Sui client
+    client: SuiClient,
+    /// Polling interval
+    interval: Duration,
+    /// Digest of the submitted transaction
+    digest: TransactionDigest,
+    /// States not emitted yet
+    pending: Vec<LifecycleState>,
+    /// Checkpoint including the transaction, once known
+    checkpoint: Option<u64>,
+    /// Whether `Finalized` was reached
+    finalized: bool,
+}
+
+impl SuiSubmissionSource {
+    /// Returns the digest of the submitted transaction
+    pub fn digest(&self) -> TransactionDigest {
+        self.digest
+    }
+
+    /// Returns whether the transaction reached `Finalized`
+    pub fn is_finalized(&self) -> bool {
+        self.finalized
+    }
+
+    fn checkpointed(&mut self, checkpoint: u64) {
+        self.checkpoint = Some(checkpoint);
+        self.pending.push(LifecycleState::Checkpointed {
+            digest: self.digest.to_string(),
+            checkpoint,
+        });
+    }
+}
+
+#[async_trait]
+impl Source<Vec<LifecycleState>> for SuiSubmissionSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<LifecycleState>>>> {
+        // Emit the states reached during submission first
+        if !self.pending.is_empty() {
+            return Ok(Some(Record::new(std::mem::take(&mut self.pending))));
+        }
+        if self.finalized {
+            return Ok(None);
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        // Wait for the transaction to be checkpointed
+        if self.checkpoint.is_none() {
+            let response = match rpc::call(
+                "SuiSubmissionSource",
+                "sui_getTransactionBlock",
+                "fetch submitted transaction",
+                self.client.read_api().get_transaction_with_options(
+                    self.digest,
+                    SuiTransactionBlockResponseOptions::new(),
+                ),
+            )
+            .await
+            {
+                Ok(response) => response,
+                Err(e) => {
+                    // The fullnode may not have executed the transaction yet
+                    tracing::debug!("Transaction {} not available yet: {}", self.digest, e);
+                    return Ok(None);
+                }
+            };
+            if let Some(checkpoint) = response.checkpoint {
+                self.checkpointed(checkpoint);
+            }
+        }
+
+        // Wait for the fullnode to execute the checkpoint
+        if let Some(checkpoint) = self.checkpoint {
+            let latest = rpc::call(
+                "SuiSubmissionSource",
+                "sui_getLatestCheckpointSequenceNumber",
+                "fetch latest checkpoint",
+                self.client
+                    .read_api()
+                    .get_latest_checkpoint_sequence_number(),
+            )
+            .await?;
+            if latest >= checkpoint {
+                self.finalized = true;
+                self.pending.push(LifecycleState::Finalized {
+                    digest: self.digest.to_string(),
+                    checkpoint,
+                });
+            }
+        }
+
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Record::new(std::mem::take(&mut self.pending))))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        tracing::info!("SuiSubmissionSource closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use crate::error::is_subscription_unsupported;
+use crate::rpc;
+use futures::{FutureExt, Stream, StreamExt};
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+use sui_sdk::error::SuiRpcResult;
+use sui_sdk::rpc_types::{EventFilter, SuiEvent};
+use sui_sdk::{SuiClient, SuiClientBuilder};
+
+/// Longest wait between reconnection attempts
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// Most query pages read to bridge the gap before a new subscription
+const MAX_BRIDGE_PAGES: u32 = 10;
+
+type EventStream = Pin<Box<dyn Stream<Item = SuiRpcResult<SuiEvent>> + Send>>;
+
+/// WebSocket event subscription of the event source, reconnecting when dropped
+pub(crate) struct EventSubscription {
+    /// WebSocket endpoint of the node
+    ws_url: String,
+    /// Client holding the WebSocket connection, rebuilt after a disconnect
+    client: Option<SuiClient>,
+    /// Open subscription, None while disconnected
+    stream: Option<EventStream>,
+    /// Earliest time of the next connection attempt
+    retry_at: Option<Instant>,
+    /// Connection failures since the last successful subscribe
+    failures: u32,
+    /// Whether the node supports subscriptions, cleared once it rejects them
+    supported: bool,
+    /// Query pages left to read before the new subscription's stream
+    bridge_pages: u32,
+}
+
+impl EventSubscription {
+    pub(crate) fn new(ws_url: String) -> Self {
+        Self {
+            ws_url,
+            client: None,
+            stream: None,
+            retry_at: None,
+            failures: 0,
+            supported: true,
+            bridge_pages: 0,
+        }
+    }
+
+    /// Waits up to `wait` for pushed events, returning at most `max` of them
+    ///
+    /// Returns None when no subscription is open, so the caller polls instead.
+    /// Lost connections are reopened with exponential backoff, and a node that
+    /// rejects subscriptions is not asked again. With `resume` set, a new
+    /// subscription also returns None until `bridged` reports the caller's
+    /// queries caught up, so events between its cursor and the subscription
+    /// start are read first.
+    pub(crate) async fn receive(
+        &mut self,
+        rpc_url: &str,
+        filter: &EventFilter,
+        max: usize,
+        wait: Duration,
+        resume: bool,
+    ) -> Option<Vec<SuiEvent>> {
+        if self.stream.is_none() {
+            self.connect(rpc_url, filter).await;
+            if self.stream.is_some() && resume {
+                self.bridge_pages = MAX_BRIDGE_PAGES;
+            }
+        }
+        if self.bridging() {
+            return None;
+        }
+        let stream = self.stream.as_mut()?;
+
+        // Wait for the first event, then take those already buffered
+        let mut events = Vec::new();
+        let mut next = match tokio::time::timeout(wait, stream.next()).await {
+            Ok(next) => next,
+            Err(_) => return Some(events),
+        };
+        loop {
+            match next {
+                Some(Ok(event)) => events.push(event),
+                Some(Err(e)) => tracing::warn!("SuiEventSource subscription error: {}", e),
+                None => {
+                    tracing::warn!("SuiEventSource subscription closed, reconnecting");
+                    self.disconnect();
+                    return (!events.is_empty()).then_some(events);
+                }
+            }
+            if events.len() >= max {
+                break;
+            }
+            match stream.next().now_or_never() {
+                Some(ready) => next = ready,
+                None => break,
+            }
+        }
+        Some(events)
+    }
+
+    /// Whether a new subscription waits for queries to catch up
+    pub(crate) fn bridging(&self) -> bool {
+        self.bridge_pages > 0
+    }
+
+    /// Records a query page read while bridging, ending the bridge on its last page
+    ///
+    /// Events the subscription pushes are buffered meanwhile, and the bridge ends
+    /// after `MAX_BRIDGE_PAGES` so the buffer is read before it overflows.
+    pub(crate) fn bridged(&mut self, has_next_page: bool) {
+        if !self.bridging() {
+            return;
+        }
+        self.bridge_pages -= 1;
+        if !has_next_page {
+            self.bridge_pages = 0;
+        } else if self.bridge_pages == 0 {
+            tracing::warn!(
+                "SuiEventSource subscription resumed after {} query pages, older events may be missed",
+                MAX_BRIDGE_PAGES
+            );
+        }
+    }
+
+    /// Drops the subscription and its connection
+    pub(crate) fn close(&mut self) {
+        self.stream = None;
+        self.client = None;
+        self.retry_at = None;
+        self.failures = 0;
+        self.bridge_pages = 0;
+    }
+
+    /// Opens the subscription unless unsupported or backing off
+    async fn connect(&mut self, rpc_url: &str, filter: &EventFilter) {
+        if !self.supported
+            || self
+                .retry_at
+                .is_some_and(|retry_at| Instant::now() < retry_at)
+        {
+            return;
+        }
+
+        if self.client.is_none() {
+            match rpc::call(
+                "SuiEventSource",
+                "rpc.discover",
+                "connect event subscription",
+                SuiClientBuilder::default()
+                    .ws_url(self.ws_url.as_str())
+                    .build(rpc_url),
+            )
+            .await
+            {
+                Ok(client) => self.client = Some(client),
+                Err(e) => {
+                    self.backoff(&e.to_string());
+                    return;
+                }
+            }
+        }
+        let Some(client) = &self.client else {
+            return;
+        };
+
+        match rpc::call(
+            "SuiEventSource",
+            "suix_subscribeEvent",
+            "subscribe to events",
+            client.event_api().subscribe_event(filter.clone()),
+        )
+        .await
+        {
+            Ok(stream) => {
+                tracing::info!("SuiEventSource subscribed through {}", self.ws_url);
+                self.stream = Some(Box::pin(stream));
+                self.retry_at = None;
+                self.failures = 0;
+            }
+            Err(e) if is_subscription_unsupported(&e.to_string()) => {
+                tracing::warn!(
+                    "Node at {} does not support event subscriptions, falling back to polling",
+                    self.ws_url
+                );
+                self.supported = false;
+                self.client = None;
+            }
+            Err(e) => {
+                self.client = None;
+                self.backoff(&e.to_string());
+            }
+        }
+    }
+
+    /// Schedules the next reconnection after a lost subscription
+    fn disconnect(&mut self) {
+        self.stream = None;
+        self.client = None;
+        self.bridge_pages = 0;
+        self.backoff("connection closed");
+    }
+
+    fn backoff(&mut self, reason: &str) {
+        let delay = Duration::from_secs(1)
+            .saturating_mul(1 << self.failures.min(5))
+            .min(MAX_RECONNECT_DELAY);
+        self.failures += 1;
+        self.retry_at = Some(Instant::now() + delay);
+        tracing::warn!(
+            "SuiEventSource subscription unavailable ({}), polling and retrying in {:?}",
+            reason,
+            delay
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use crate::event::{ChainEvent, SuiEventSource};
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use sui_sdk::SuiClient;
+use sui_sdk::rpc_types::SuiObjectDataOptions;
+use sui_sdk::types::base_types::ObjectID;
+use sui_sdk::types::parse_sui_struct_tag;
+
+/// AMM protocol whose swap events are decoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SwapProtocol {
+    /// Cetus CLMM, `pool::SwapEvent`
+    Cetus,
+    /// Turbos CLMM, `pool::SwapEvent`
+    Turbos,
+    /// Aftermath AMM, `events::SwapEventV2` and `events::SwapEvent`
+    Aftermath,
+}
+
+impl SwapProtocol {
+    /// Every supported protocol
+    pub const ALL: [SwapProtocol; 3] = [
+        SwapProtocol::Cetus,
+        SwapProtocol::Turbos,
+        SwapProtocol::Aftermath,
+    ];
+
+    /// Mainnet package defining the protocol's swap events
+    pub fn package(&self) -> &'static str {
+        match self {
+            SwapProtocol::Cetus => {
+                "0x1eabed72c53feb3805120a081dc15963c204dc8d091542592abaf7a35689b2fb"
+            }
+            SwapProtocol::Turbos => {
+                "0x91bfbc386a41afcfd9b2533058d7e915a1d3829089cc268ff4333d54d6339ca1"
+            }
+            SwapProtocol::Aftermath => {
+                "0xefe170ec0be4d762196bedecd7a065816576198a6527c99282a2551aaa7da38c"
+            }
+        }
+    }
+
+    /// Move event types of the protocol's swaps, e.g. for an event filter
+    pub fn event_types(&self) -> Vec<String> {
+        self.event_names()
+            .iter()
+            .map(|(module, name)| format!("{}::{}::{}", self.package(), module, name))
+            .collect()
+    }
+
+    /// Modules and struct names of the protocol's swap events
+    fn event_names(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            SwapProtocol::Cetus | SwapProtocol::Turbos => &[("pool", "SwapEvent")],
+            SwapProtocol::Aftermath => &[("events", "SwapEventV2"), ("events", "SwapEvent")],
+        }
+    }
+
+    /// Protocol emitting an event, None if it is not a swap of a supported protocol
+    fn of(event: &ChainEvent) -> Option<Self> {
+        let package = ObjectID::from_str(&event.event_type.module.package).ok()?;
+        let protocol = Self::ALL.into_iter().find(|protocol| {
+            ObjectID::from_str(protocol.package()).is_ok_and(|id| id == package)
+        })?;
+        protocol
+            .event_names()
+            .iter()
+            .any(|(module, name)| {
+                *module == &*event.event_type.module.module && *name == &*event.event_type.name
+            })
+            .then_some(protocol)
+    }
+}
+
+/// Swap normalized across protocols
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Swap {
+    /// Protocol of the pool
+    pub protocol: SwapProtocol,
+    /// Pool object ID
+    pub pool: String,
+    /// Coin type sold, None if the pool's coin types could not be resolved
+    pub coin_in: Option<String>,
+    /// Coin type bought, None if the pool's coin types could not be resolved
+    pub coin_out: Option<String>,
+    /// Amount sold, in the smallest unit of `coin_in`
+    pub amount_in: u128,
+    /// Amount bought, in the smallest unit of `coin_out`
+    pub amount_out: u128,
+    /// Sender of the swap transaction
+    pub sender: String,
+    /// Digest of the swap transaction
+    pub transaction_digest: String,
+    /// Timestamp (milliseconds)
+    pub timestamp: u64,
+}
+
+/// Decoded swap and, for pools whose events only name the direction, whether it
+/// sold the pool's first coin type
+struct DecodedSwap {
+    swap: Swap,
+    a_to_b: Option<bool>,
+}
+
+impl Swap {
+    /// Normalizes a swap event, None if it is not a swap of a supported protocol
+    ///
+    /// Cetus and Turbos events only carry the swap direction, so their coin types
+    /// are None here; `SuiSwapSource` resolves them from the pool's type. Aftermath
+    /// events swapping several coins at once report the first coin in and out.
+    pub fn from_event(event: &ChainEvent) -> Option<Self> {
+        decode(event).map(|decoded| decoded.swap)
+    }
+}
+
+fn decode(event: &ChainEvent) -> Option<DecodedSwap> {
+    let protocol = SwapProtocol::of(event)?;
+    let json = &event.parsed_json;
+    let swap = |pool, coin_in, coin_out, amount_in, amount_out| Swap {
+        protocol,
+        pool,
+        coin_in,
+        coin_out,
+        amount_in,
+        amount_out,
+        sender: event.sender.clone(),
+        transaction_digest: event.id.tx_digest.to_string(),
+        timestamp: event.timestamp,
+    };
+
+    match protocol {
+        SwapProtocol::Cetus => {
+            let a_to_b = json.get("atob")?.as_bool()?;
+            Some(DecodedSwap {
+                swap: swap(
+                    string_field(json, "pool")?,
+                    None,
+                    None,
+                    amount_field(json, "amount_in")?,
+                    amount_field(json, "amount_out")?,
+                ),
+                a_to_b: Some(a_to_b),
+            })
+        }
+        SwapProtocol::Turbos => {
+            let a_to_b = json.get("a_to_b")?.as_bool()?;
+            let amount_a = amount_field(json, "amount_a")?;
+            let amount_b = amount_field(json, "amount_b")?;
+            let (amount_in, amount_out) = if a_to_b {
+                (amount_a, amount_b)
+            } else {
+                (amount_b, amount_a)
+            };
+            Some(DecodedSwap {
+                swap: swap(
+                    string_field(json, "pool")?,
+                    None,
+                    None,
+                    amount_in,
+                    amount_out,
+                ),
+                a_to_b: Some(a_to_b),
+            })
+        }
+        SwapProtocol::Aftermath => {
+            let first = |name: &str| json.get(name)?.as_array()?.first().cloned();
+            Some(DecodedSwap {
+                swap: swap(
+                    string_field(json, "pool_id")?,
+                    Some(normalize_coin_type(first("types_in")?.as_str()?)),
+                    Some(normalize_coin_type(first("types_out")?.as_str()?)),
+                    amount_value(&first("amounts_in")?)?,
+                    amount_value(&first("amounts_out")?)?,
+                ),
+                a_to_b: None,
+            })
+        }
+    }
+}
+
+fn string_field(json: &Value, name: &str) -> Option<String> {
+    json.get(name)?.as_str().map(str::to_string)
+}
+
+fn amount_field(json: &Value, name: &str) -> Option<u128> {
+    amount_value(json.get(name)?)
+}
+
+/// Reads an amount encoded as a JSON string, as Move integers are, or a number
+fn amount_value(value: &Value) -> Option<u128> {
+    match value {
+        Value::String(amount) => amount.parse().ok(),
+        Value::Number(amount) => amount.as_u64().map(u128::from),
+        _ => None,
+    }
+}
+
+/// Canonical coin type with a `0x` prefix, as Move type names are reported without one
+pub(crate) fn normalize_coin_type(coin_type: &str) -> String {
+    let prefixed = if coin_type.starts_with("0x") {
+        coin_type.to_string()
+    } else {
+        format!("0x{}", coin_type)
+    };
+    parse_sui_struct_tag(&prefixed)
+        .map(|tag| tag.to_canonical_string(true))
+        .unwrap_or(prefixed)
+}
+
+/// Source emitting normalized swaps of the enabled AMM protocols
+///
+/// Events of the inner source are kept when they are swaps of an enabled
+/// protocol; restrict the inner source with a `with_query` filter on the
+/// protocols' `event_types` to avoid fetching unrelated events. Coin types of
+/// Cetus and Turbos pools are read from the pool object's type on first sight
+/// and cached, as a pool's coin types never change.
+pub struct SuiSwapSource {
+    /// Underlying event source
+    inner: SuiEventSource,
+    /// Protocols whose swaps are emitted
+    protocols: HashSet<SwapProtocol>,
+    /// Coin type parameters of pools by pool ID
+    pool_coins: HashMap<String, (String, String)>,
+}
+
+impl SuiSwapSource {
+    /// Creates a new SuiSwapSource decoding every supported protocol
+    pub fn new(inner: SuiEventSource) -> Self {
+        Self {
+            inner,
+            protocols: SwapProtocol::ALL.into_iter().collect(),
+            pool_coins: HashMap::new(),
+        }
+    }
+
+    /// Restricts the emitted swaps to the given protocols
+    pub fn with_protocols(mut self, protocols: impl IntoIterator<Item = SwapProtocol>) -> Self {
+        self.protocols = protocols.into_iter().collect();
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+
+    /// Reads the coin types of pools not cached yet from their object types
+    async fn resolve_pools(
+        client: &SuiClient,
+        pool_coins: &mut HashMap<String, (String, String)>,
+        pools: Vec<ObjectID>,
+    ) -> StreamResult<()> {
+        for page in pools.chunks(rpc::QUERY_MAX_RESULT_LIMIT) {
+            let responses = rpc::call(
+                "SuiSwapSource",
+                "sui_multiGetObjects",
+                "fetch pool types",
+                client.read_api().multi_get_object_with_options(
+                    page.to_vec(),
+                    SuiObjectDataOptions::new().with_type(),
+                ),
+            )
+            .await?;
+            for data in responses.into_iter().filter_map(|response| response.data) {
+                let Some(pool_type) = data.type_ else {
+                    continue;
+                };
+                let Ok(tag) = parse_sui_struct_tag(&pool_type.to_string()) else {
+                    continue;
+                };
+                if let [coin_a, coin_b, ..] = tag.type_params.as_slice() {
+                    pool_coins.insert(
+                        data.object_id.to_string(),
+                        (
+                            coin_a.to_canonical_string(true),
+                            coin_b.to_canonical_string(true),
+                        ),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Source<Vec<Swap>> for SuiSwapSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<Swap>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let decoded: Vec<DecodedSwap> = record
+            .data
+            .iter()
+            .filter_map(decode)
+            .filter(|decoded| self.protocols.contains(&decoded.swap.protocol))
+            .collect();
+        if decoded.is_empty() {
+            tracing::info!("No swap events found");
+            return Ok(None);
+        }
+
+        // Resolve the coin types of pools seen for the first time
+        let mut unresolved: Vec<ObjectID> = decoded
+            .iter()
+            .filter(|decoded| {
+                decoded.a_to_b.is_some() && !self.pool_coins.contains_key(&decoded.swap.pool)
+            })
+            .filter_map(|decoded| ObjectID::from_str(&decoded.swap.pool).ok())
+            .collect();
+        unresolved.sort();
+        unresolved.dedup();
+        if !unresolved.is_empty() {
+            let client = self.inner.client().ok_or_else(|| {
+                StreamError::Runtime("SuiEventSource client not available".to_string())
+            })?;
+            if let Err(e) = Self::resolve_pools(client, &mut self.pool_coins, unresolved).await {
+                tracing::warn!("Emitting swaps without coin types: {}", e);
+            }
+        }
+
+        let swaps = decoded
+            .into_iter()
+            .map(|DecodedSwap { mut swap, a_to_b }| {
+                if let Some(a_to_b) = a_to_b
+                    && let Some((coin_a, coin_b)) = self.pool_coins.get(&swap.pool)
+                {
+                    let (coin_in, coin_out) = if a_to_b {
+                        (coin_a, coin_b)
+                    } else {
+                        (coin_b, coin_a)
+                    };
+                    swap.coin_in = Some(coin_in.clone());
+                    swap.coin_out = Some(coin_out.clone());
+                }
+                swap
+            })
+            .collect();
+
+        Ok(Some(Record::new(swaps)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,188 @@
+use crate::conversion::ConversionError;
+use crate::move_type::MoveTypeTag;
+use crate::object::ChainObject;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use sui_sdk::types::base_types::ObjectID;
+use sui_sdk::types::{SUI_FRAMEWORK_PACKAGE_ID, SUI_SYSTEM_PACKAGE_ID};
+
+/// High-level category of an object, derived from its Move type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ObjectCategory {
+    /// `0x2::coin::Coin<T>` of any coin type
+    Coin,
+    /// Object with a Display, not matched by a more specific rule
+    Nft,
+    /// Kiosk or kiosk owner capability
+    Kiosk,
+    /// Liquidity position of an AMM
+    LpPosition,
+    /// `0x3::staking_pool::StakedSui`
+    StakedSui,
+    /// No rule matched
+    #[default]
+    Unknown,
+}
+
+/// Built-in rules as package, any package when None, module, name and category,
+/// checked after the user rules
+const BUILTIN_RULES: &[(Option<ObjectID>, &str, &str, ObjectCategory)] = &[
+    (
+        Some(SUI_FRAMEWORK_PACKAGE_ID),
+        "coin",
+        "Coin",
+        ObjectCategory::Coin,
+    ),
+    (
+        Some(SUI_SYSTEM_PACKAGE_ID),
+        "staking_pool",
+        "StakedSui",
+        ObjectCategory::StakedSui,
+    ),
+    (
+        Some(SUI_FRAMEWORK_PACKAGE_ID),
+        "kiosk",
+        "Kiosk",
+        ObjectCategory::Kiosk,
+    ),
+    (
+        Some(SUI_FRAMEWORK_PACKAGE_ID),
+        "kiosk",
+        "KioskOwnerCap",
+        ObjectCategory::Kiosk,
+    ),
+    (None, "position", "Position", ObjectCategory::LpPosition),
+    (
+        None,
+        "position_nft",
+        "TurbosPositionNFT",
+        ObjectCategory::LpPosition,
+    ),
+];
+
+/// Type pattern of a rule, `<package>::<module>::<name>` with `*` for any package
+#[derive(Clone, Debug)]
+struct TypeRule {
+    /// Package ID, canonical form, any package when None
+    package: Option<String>,
+    /// Module name
+    module: String,
+    /// Struct name, type parameters are ignored
+    name: String,
+    /// Category assigned to matching types
+    category: ObjectCategory,
+}
+
+impl TypeRule {
+    fn parse(pattern: &str, category: ObjectCategory) -> Result<Self, ConversionError> {
+        let invalid = || ConversionError::InvalidField {
+            field: "pattern".to_string(),
+            reason: format!("{} is not of the form <package>::<module>::<name>", pattern),
+        };
+        let mut parts = pattern.splitn(3, "::");
+        let (Some(package), Some(module), Some(name)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        let package = match package {
+            "*" => None,
+            package => Some(
+                ObjectID::from_str(package)
+                    .map_err(|e| ConversionError::InvalidField {
+                        field: "pattern".to_string(),
+                        reason: e.to_string(),
+                    })?
+                    .to_string(),
+            ),
+        };
+        Ok(Self {
+            package,
+            module: module.to_string(),
+            name: name.to_string(),
+            category,
+        })
+    }
+
+    fn matches(&self, object_type: &MoveTypeTag) -> bool {
+        self.package
+            .as_ref()
+            .is_none_or(|package| **package == *object_type.module.package)
+            && *self.module == *object_type.module.module
+            && *self.name == *object_type.name
+    }
+}
+
+/// Maps raw Move types of objects to an `ObjectCategory`
+///
+/// Rules match the type without its type parameters and are checked in order:
+/// user rules first, most recently added first, then the built-in rules. Objects
+/// matching no rule are `Nft` when they have a Display and `Unknown` otherwise,
+/// so NFT detection needs the source to fetch Display data.
+#[derive(Clone, Debug)]
+pub struct ObjectClassifier {
+    /// Rules in the order they are checked
+    rules: Vec<TypeRule>,
+}
+
+impl Default for ObjectClassifier {
+    fn default() -> Self {
+        Self {
+            rules: BUILTIN_RULES
+                .iter()
+                .map(|(package, module, name, category)| TypeRule {
+                    package: package.map(|package| package.to_string()),
+                    module: module.to_string(),
+                    name: name.to_string(),
+                    category: *category,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ObjectClassifier {
+    /// Creates a classifier with the built-in rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule taking precedence over the rules added before and the built-in ones
+    ///
+    /// `pattern` is `<package>::<module>::<name>`, with `*` as the package to match
+    /// the type in any package, e.g. `*::pool::LpToken`.
+    pub fn with_rule(
+        mut self,
+        pattern: &str,
+        category: ObjectCategory,
+    ) -> Result<Self, ConversionError> {
+        self.rules.insert(0, TypeRule::parse(pattern, category)?);
+        Ok(self)
+    }
+
+    /// Returns the category of the first rule matching the type, `Unknown` if none does
+    pub fn classify_type(&self, object_type: &str) -> ObjectCategory {
+        let Ok(object_type) = object_type.parse::<MoveTypeTag>() else {
+            return ObjectCategory::Unknown;
+        };
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(&object_type))
+            .map_or(ObjectCategory::Unknown, |rule| rule.category)
+    }
+
+    /// Returns the category of an object, falling back to its Display for NFTs
+    pub fn classify(&self, object: &ChainObject) -> ObjectCategory {
+        match self.classify_type(&object.object_type) {
+            ObjectCategory::Unknown
+                if object
+                    .data
+                    .display
+                    .as_ref()
+                    .is_some_and(|display| display.data.is_some()) =>
+            {
+                ObjectCategory::Nft
+            }
+            category => category,
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use crate::rpc;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use sui_sdk::types::sui_serde::BigInt;
+use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
+use tokio::time::sleep;
+
+/// Maximum number of checkpoints the node returns per page
+const CHECKPOINT_PAGE_LIMIT: usize = 100;
+/// Maximum number of pages read per poll, so a far-behind source still yields
+const MAX_PAGES_PER_POLL: usize = 10;
+/// Default transactions per checkpoint counted as full, the mainnet protocol limit
+const DEFAULT_CHECKPOINT_CAPACITY: u64 = 10_000;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkTelemetry {
+    /// Window start, checkpoint time (milliseconds)
+    pub window_start: u64,
+    /// Window end, checkpoint time (milliseconds)
+    pub window_end: u64,
+    /// First checkpoint within the window
+    pub first_checkpoint: u64,
+    /// Last checkpoint within the window
+    pub last_checkpoint: u64,
+    /// Number of checkpoints within the window
+    pub checkpoints: u64,
+    /// Number of transactions within the window
+    pub transactions: u64,
+    /// Transactions per second
+    pub tps: f64,
+    /// Checkpoints per second
+    pub checkpoints_per_second: f64,
+    /// Average transactions per checkpoint divided by the checkpoint capacity
+    pub fullness: f64,
+}
+
+/// Checkpoints accumulated in the current window
+struct Accumulated {
+    /// Window start, checkpoint time (milliseconds)
+    start: u64,
+    first_checkpoint: u64,
+    last_checkpoint: u64,
+    checkpoints: u64,
+    transactions: u64,
+}
+
+/// Sui data source emitting network-level throughput per window
+///
+/// Reads only checkpoint headers, starting at the head at init, and groups them
+/// into tumbling windows of checkpoint time, so the rates do not depend on local
+/// clock skew or polling delays. A window is emitted once a checkpoint past its
+/// end is read; windows without any checkpoint are not emitted. Fullness compares
+/// the average number of transactions per checkpoint with a capacity that is not
+/// part of the checkpoint headers, see `with_checkpoint_capacity`.
+pub struct SuiTelemetrySource {
+    /// Sui RPC endpoint URL
+    rpc_url: String,
+    /// Polling interval (milliseconds)
+    interval: Duration,
+    /// Whether initialized
+    initialized: bool,
+    /// Sui client
+    client: Option<SuiClient>,
+    /// Window length
+    window: Duration,
+    /// Transactions per checkpoint counted as full
+    checkpoint_capacity: u64,
+    /// Last checkpoint read
+    cursor: Option<BigInt<u64>>,
+    /// Window being accumulated
+    current: Option<Accumulated>,
+}
+
+impl SuiTelemetrySource {
+    /// Creates a new SuiTelemetrySource instance
+    ///
+    /// # Parameters
+    /// * `rpc_url` - Sui RPC endpoint URL
+    /// * `interval_ms` - Polling interval in milliseconds
+    /// * `window` - Length of the aggregation window
+    pub fn new(rpc_url: String, interval_ms: u64, window: Duration) -> Self {
+        Self {
+            rpc_url,
+            interval: Duration::from_millis(interval_ms),
+            initialized: false,
+            client: None,
+            window,
+            checkpoint_capacity: DEFAULT_CHECKPOINT_CAPACITY,
+            cursor: None,
+            current: None,
+        }
+    }
+
+    /// Creates a new SuiTelemetrySource instance using the default Sui Mainnet RPC endpoint
+    pub fn new_with_mainnet(interval_ms: u64, window: Duration) -> Self {
+        Self::new(SUI_MAINNET_URL.to_string(), interval_ms, window)
+    }
+
+    /// Sets the number of transactions per checkpoint counted as full, 10,000 by default
+    pub fn with_checkpoint_capacity(mut self, capacity: u64) -> Self {
+        self.checkpoint_capacity = capacity.max(1);
+        self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Adds a checkpoint, returning the window it closes
+    fn observe(
+        &mut self,
+        sequence: u64,
+        timestamp_ms: u64,
+        transactions: u64,
+    ) -> Option<NetworkTelemetry> {
+        let length = (self.window.as_millis() as u64).max(1);
+        let mut closed = None;
+        if let Some(current) = &self.current
+            && timestamp_ms >= current.start + length
+        {
+            closed = Some(self.telemetry(current, length));
+            // Align the next window with the first, skipping windows without checkpoints
+            let start = current.start + (timestamp_ms - current.start) / length * length;
+            self.current = Some(Accumulated {
+                start,
+                first_checkpoint: sequence,
+                last_checkpoint: sequence,
+                checkpoints: 0,
+                transactions: 0,
+            });
+        }
+        let current = self.current.get_or_insert(Accumulated {
+            start: timestamp_ms,
+            first_checkpoint: sequence,
+            last_checkpoint: sequence,
+            checkpoints: 0,
+            transactions: 0,
+        });
+        current.last_checkpoint = sequence;
+        current.checkpoints += 1;
+        current.transactions += transactions;
+        closed
+    }
+
+    fn telemetry(&self, window: &Accumulated, length: u64) -> NetworkTelemetry {
+        let seconds = length as f64 / 1000.0;
+        NetworkTelemetry {
+            window_start: window.start,
+            window_end: window.start + length,
+            first_checkpoint: window.first_checkpoint,
+            last_checkpoint: window.last_checkpoint,
+            checkpoints: window.checkpoints,
+            transactions: window.transactions,
+            tps: window.transactions as f64 / seconds,
+            checkpoints_per_second: window.checkpoints as f64 / seconds,
+            fullness: window.transactions as f64
+                / window.checkpoints.max(1) as f64
+                / self.checkpoint_capacity as f64,
+        }
+    }
+}
+
+#[async_trait]
+impl Source<Vec<NetworkTelemetry>> for SuiTelemetrySource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client
+        let client = rpc::call(
+            "SuiTelemetrySource",
+            "rpc.discover",
+            "initialize Sui client",
+            SuiClientBuilder::default().build(self.rpc_url.as_str()),
+        )
+        .await?;
+
+        // Start at the head rather than replaying history
+        let latest = rpc::call(
+            "SuiTelemetrySource",
+            "sui_getLatestCheckpointSequenceNumber",
+            "fetch latest checkpoint",
+            client.read_api().get_latest_checkpoint_sequence_number(),
+        )
+        .await?;
+
+        self.cursor = Some(BigInt::from(latest));
+        self.client = Some(client);
+        self.initialized = true;
+        tracing::info!(
+            "SuiTelemetrySource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<NetworkTelemetry>>>> {
+        // Ensure initialized
+        if !self.initialized || self.client.is_none() {
+            return Err(StreamError::Runtime(
+                "SuiTelemetrySource not initialized".to_string(),
+            ));
+        }
+
+        // Polling interval
+        sleep(self.interval).await;
+
+        let client = self.client.clone().ok_or_else(|| {
+            StreamError::Runtime("SuiTelemetrySource client not available".to_string())
+        })?;
+
+        // Read the checkpoint headers produced since the last poll
+        let mut windows = Vec::new();
+        for _ in 0..MAX_PAGES_PER_POLL {
+            let page = rpc::call(
+                "SuiTelemetrySource",
+                "sui_getCheckpoints",
+                "fetch checkpoints",
+                client
+                    .read_api()
+                    .get_checkpoints(self.cursor, Some(CHECKPOINT_PAGE_LIMIT), false),
+            )
+            .await?;
+
+            for checkpoint in &page.data {
+                windows.extend(self.observe(
+                    checkpoint.sequence_number,
+                    checkpoint.timestamp_ms,
+                    checkpoint.transactions.len() as u64,
+                ));
+            }
+            if page.next_cursor.is_some() {
+                self.cursor = page.next_cursor;
+            }
+            if !page.has_next_page {
+                break;
+            }
+        }
+
+        // Keep accumulating until a window closes
+        if windows.is_empty() {
+            return Ok(None);
+        }
+        for window in &windows {
+            tracing::debug!(
+                "Sui network telemetry: checkpoints {}..={} tps: {:.1} checkpoints/s: {:.2}",
+                window.first_checkpoint,
+                window.last_checkpoint,
+                window.tps,
+                window.checkpoints_per_second
+            );
+        }
+
+        Ok(Some(Record::new(windows)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.initialized = false;
+        self.client = None;
+        tracing::info!("SuiTelemetrySource closed");
+        Ok(())
+    }
+}
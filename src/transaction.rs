@@ -1,18 +1,58 @@
+use crate::abort::MoveAbort;
+use crate::balance::{BalanceChange, balance_changes};
+use crate::bloom::{DigestFilter, PersistedFilter};
+use crate::chain::{ChainMetadata, network_name};
+use crate::compact::CompactSuiTransactionSource;
+use crate::connection::SuiConnection;
+use crate::control::{ControlEvent, ControlEvents, ControlQueue, ControlledSource};
+use crate::conversion::{ConversionError, DeadLetter, DeadLetterSender, route_dead_letter};
+use crate::cursor::{AsyncCursorStore, CursorSlot, CursorStore};
+use crate::enrich::{Enricher, EnrichmentChain};
+use crate::error::{is_address_filter_unsupported, is_transient};
+use crate::event::{ChainEvent, embedded_events};
+use crate::health::{HealthMonitor, NodeHealth};
+use crate::heuristics::{HeuristicTag, HeuristicsConfig};
+use crate::item_result::{ItemErrorQueue, ItemErrors, ItemResultSource, SourceItemError};
+use crate::jitter::StartDelay;
+use crate::notify::NotificationRegistry;
+use crate::order::EmitOrder;
+use crate::policy::{ErrorPolicy, Recovery, RetryConfig};
+use crate::pool::EndpointPool;
+use crate::profile::{Phase, PhaseTimer};
+use crate::redaction::{Redact, RedactionConfig};
+use crate::rpc;
+use crate::scheduler::{Priority, SuiScheduler};
+use crate::stats::{SourceStats, StatsRecorder};
+use crate::units::{CoinDecimals, format_amount};
 use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use fluxus::sources::Source;
 use fluxus::utils::models::{Record, StreamError, StreamResult};
+use futures::Stream;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 use sui_sdk::rpc_types::{
-    SuiTransactionBlockData, SuiTransactionBlockDataAPI, SuiTransactionBlockResponseOptions,
+    SuiExecutionStatus, SuiTransactionBlockData, SuiTransactionBlockDataAPI,
+    SuiTransactionBlockEffects, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponseOptions,
+};
+use sui_sdk::rpc_types::{
+    SuiTransactionBlockResponse, SuiTransactionBlockResponseQuery, TransactionBlocksPage,
+    TransactionFilter,
 };
-use sui_sdk::rpc_types::{SuiTransactionBlockResponse, SuiTransactionBlockResponseQuery};
 use sui_sdk::types::base_types::SuiAddress;
 use sui_sdk::types::digests::TransactionDigest;
 use sui_sdk::types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_sdk::{SUI_MAINNET_URL, SuiClient, SuiClientBuilder};
 use tokio::time::sleep;
 
+/// Wait between polls for range checkpoints past the chain head, without an interval
+const RANGE_HEAD_WAIT: Duration = Duration::from_secs(1);
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SuiEvent {
     /// Transaction ID
@@ -21,10 +61,279 @@ pub struct SuiEvent {
     pub transaction_type: String,
     /// Timestamp
     pub timestamp: u64,
+    /// Checkpoint sequence number
+    pub checkpoint: Option<u64>,
     /// Sender address
     pub sender: String,
-    /// Transaction metadata
+    /// Transaction metadata, None in effects-only mode
     pub metadata: Option<SuiTransactionBlockData>,
+    /// Transaction effects: execution status, gas and touched objects
+    pub effects: Option<SuiTransactionBlockEffects>,
+    /// Decoded Move abort, None unless the transaction aborted in Move code
+    pub abort: Option<MoveAbort>,
+    /// Events emitted by the transaction, empty in effects-only mode
+    pub events: Vec<ChainEvent>,
+    /// Signed balance deltas, empty in effects-only mode or when disabled
+    pub balance_changes: Vec<BalanceChange>,
+    /// Network the transaction was read from
+    pub chain: Option<ChainMetadata>,
+    /// Anti-spam heuristics, empty unless enabled on the source
+    pub tags: Vec<HeuristicTag>,
+    /// Base64 BCS bytes of the signed transaction, None unless raw BCS is enabled
+    pub raw_transaction: Option<String>,
+    /// Base64 BCS bytes of the effects, None unless raw BCS is enabled
+    pub raw_effects: Option<String>,
+    /// Values attached by the source's enrichers, by enricher name
+    pub enrichments: BTreeMap<String, serde_json::Value>,
+}
+
+impl TryFrom<&SuiTransactionBlockResponse> for SuiEvent {
+    type Error = ConversionError;
+
+    fn try_from(transaction: &SuiTransactionBlockResponse) -> Result<Self, Self::Error> {
+        full_event(transaction, true)
+    }
+}
+
+/// Converts a transaction fetched with input, cloning the input into `metadata` if requested
+fn full_event(
+    transaction: &SuiTransactionBlockResponse,
+    include_metadata: bool,
+) -> Result<SuiEvent, ConversionError> {
+    let timestamp = transaction
+        .timestamp_ms
+        .ok_or_else(|| ConversionError::MissingField("timestamp_ms".to_string()))?;
+    let block = transaction
+        .transaction
+        .as_ref()
+        .ok_or_else(|| ConversionError::MissingField("transaction".to_string()))?;
+
+    Ok(SuiEvent {
+        transaction_digest: transaction.digest.to_string(),
+        transaction_type: block.data.transaction().name().to_string(),
+        timestamp,
+        checkpoint: transaction.checkpoint,
+        sender: block.data.sender().to_string(),
+        metadata: include_metadata.then(|| block.data.clone()),
+        effects: transaction.effects.clone(),
+        abort: move_abort(transaction),
+        events: embedded_events(transaction),
+        balance_changes: balance_changes(transaction),
+        tags: Vec::new(),
+        chain: None,
+        raw_transaction: None,
+        raw_effects: None,
+        enrichments: BTreeMap::new(),
+    })
+}
+
+/// Decodes the Move abort of a failed transaction
+fn move_abort(transaction: &SuiTransactionBlockResponse) -> Option<MoveAbort> {
+    match transaction.effects.as_ref()?.status() {
+        SuiExecutionStatus::Failure { error } => MoveAbort::parse(error),
+        SuiExecutionStatus::Success => None,
+    }
+}
+
+/// Converts a transaction fetched without input, keeping only its digest and effects
+fn effects_only_event(
+    transaction: &SuiTransactionBlockResponse,
+) -> Result<SuiEvent, ConversionError> {
+    let timestamp = transaction
+        .timestamp_ms
+        .ok_or_else(|| ConversionError::MissingField("timestamp_ms".to_string()))?;
+    let effects = transaction
+        .effects
+        .clone()
+        .ok_or_else(|| ConversionError::MissingField("effects".to_string()))?;
+
+    Ok(SuiEvent {
+        transaction_digest: transaction.digest.to_string(),
+        transaction_type: "unknown".to_string(),
+        timestamp,
+        checkpoint: transaction.checkpoint,
+        sender: "unknown".to_string(),
+        metadata: None,
+        abort: move_abort(transaction),
+        effects: Some(effects),
+        events: Vec::new(),
+        balance_changes: Vec::new(),
+        tags: Vec::new(),
+        chain: None,
+        raw_transaction: None,
+        raw_effects: None,
+        enrichments: BTreeMap::new(),
+    })
+}
+
+/// Converts a transaction according to the effects-only and metadata settings
+fn convert(
+    transaction: &SuiTransactionBlockResponse,
+    effects_only: bool,
+    include_metadata: bool,
+) -> Result<SuiEvent, ConversionError> {
+    if effects_only {
+        effects_only_event(transaction)
+    } else {
+        full_event(transaction, include_metadata)
+    }
+}
+
+/// Progress through a bounded checkpoint range
+struct CheckpointRange {
+    /// Checkpoint being read
+    next: CheckpointSequenceNumber,
+    /// Last checkpoint of the range, inclusive
+    end: CheckpointSequenceNumber,
+    /// Cursor within the checkpoint being read
+    cursor: Option<TransactionDigest>,
+    /// Latest checkpoint known to exist
+    latest: Option<CheckpointSequenceNumber>,
+    /// Distance between the checkpoints read, the partition count when partitioned
+    stride: u64,
+}
+
+impl CheckpointRange {
+    /// Moves `next` to the first checkpoint of a partition at or after its position
+    fn align(&mut self, index: u64, count: u64) {
+        self.stride = count;
+        self.next += (index + count - self.next % count) % count;
+    }
+}
+
+/// Advances past a handled page
+///
+/// Range reads move to the next checkpoint once this one is read. Ascending reads
+/// move forward page by page, descending ones re-read the head.
+fn advance_page(
+    range: &mut Option<CheckpointRange>,
+    control: &mut ControlQueue,
+    cursor: &mut Option<TransactionDigest>,
+    page: &TransactionBlocksPage,
+    descending_order: bool,
+) {
+    if let Some(range) = range {
+        if page.has_next_page {
+            range.cursor = page.next_cursor;
+        } else {
+            control.checkpoint_complete(range.next);
+            range.next = range.next.saturating_add(range.stride);
+            range.cursor = None;
+        }
+    } else if !descending_order && page.next_cursor.is_some() {
+        *cursor = page.next_cursor;
+    }
+}
+
+/// Age threshold and the run of transactions dropped for exceeding it
+struct AgeOut {
+    /// Maximum age relative to the chain head
+    max_age: Duration,
+    /// Transactions dropped since the last report
+    skipped: u64,
+    /// Timestamp of the oldest dropped transaction (milliseconds)
+    oldest: u64,
+    /// Timestamp of the newest dropped transaction (milliseconds)
+    newest: u64,
+}
+
+impl AgeOut {
+    /// Returns whether a transaction is kept, counting it otherwise
+    fn keep(&mut self, timestamp: u64, threshold: u64) -> bool {
+        if timestamp >= threshold {
+            return true;
+        }
+        if self.skipped == 0 {
+            (self.oldest, self.newest) = (timestamp, timestamp);
+        }
+        self.skipped += 1;
+        self.oldest = self.oldest.min(timestamp);
+        self.newest = self.newest.max(timestamp);
+        false
+    }
+
+    /// Takes the summary of the run dropped so far
+    fn take_report(&mut self) -> Option<(u64, u64, u64)> {
+        if self.skipped == 0 {
+            return None;
+        }
+        let report = (self.skipped, self.oldest, self.newest);
+        self.skipped = 0;
+        Some(report)
+    }
+}
+
+/// Backfill of the transactions published between two head polls
+struct GapBackfill {
+    /// Maximum number of transactions fetched beyond the polled page
+    max_depth: usize,
+    /// Newest transaction emitted and its checkpoint, None before the first emission
+    newest: Option<(TransactionDigest, Option<CheckpointSequenceNumber>)>,
+}
+
+impl GapBackfill {
+    /// Returns whether a descending page reaches back to the newest transaction emitted
+    fn connects(&self, transactions: &[SuiTransactionBlockResponse]) -> bool {
+        let Some((digest, checkpoint)) = self.newest else {
+            return true;
+        };
+        transactions.iter().any(|tx| {
+            tx.digest == digest
+                || matches!((tx.checkpoint, checkpoint), (Some(seen), Some(newest)) if seen < newest)
+        })
+    }
+
+    /// Pages past the polled page until it joins the previous poll or the depth is spent
+    ///
+    /// Descending reads continue into older transactions until reaching the
+    /// newest one emitted. Ascending reads continue forward while the node holds
+    /// further pages, leaving the page's cursor at the last page read.
+    async fn fill(
+        &self,
+        client: &SuiClient,
+        query: &SuiTransactionBlockResponseQuery,
+        page: &mut TransactionBlocksPage,
+        limit: usize,
+        descending_order: bool,
+    ) -> StreamResult<usize> {
+        let mut fetched = 0;
+        while fetched < self.max_depth
+            && page.has_next_page
+            && page.next_cursor.is_some()
+            && !(descending_order && self.connects(&page.data))
+        {
+            let next = rpc::call(
+                "SuiTransactionSource",
+                "suix_queryTransactionBlocks",
+                "backfill transactions",
+                client.read_api().query_transaction_blocks(
+                    query.clone(),
+                    page.next_cursor,
+                    Some(limit.min(self.max_depth - fetched)),
+                    descending_order,
+                ),
+            )
+            .await?;
+            fetched += next.data.len();
+            page.data.extend(next.data);
+            page.next_cursor = next.next_cursor;
+            page.has_next_page = next.has_next_page;
+        }
+        Ok(fetched)
+    }
+
+    /// Drops the transactions of a descending page emitted by previous polls
+    fn trim(&self, page: &mut TransactionBlocksPage) {
+        let Some((digest, checkpoint)) = self.newest else {
+            return;
+        };
+        if let Some(position) = page.data.iter().position(|tx| tx.digest == digest) {
+            page.data.truncate(position);
+        } else if let Some(newest) = checkpoint {
+            page.data
+                .retain(|tx| tx.checkpoint.is_none_or(|seen| seen >= newest));
+        }
+    }
 }
 
 /// Sui blockchain data source for fetching transaction data from the Sui network
@@ -43,12 +352,81 @@ pub struct SuiTransactionSource {
     last_processed_checkpoint: Option<CheckpointSequenceNumber>,
     /// Transaction query
     query: SuiTransactionBlockResponseQuery,
+    /// Address whose sent and received transactions are queried, and whether the node
+    /// supports the combined filter, disabled when None
+    address_activity: Option<(SuiAddress, bool)>,
     /// Cursor for pagination
     cursor: Option<TransactionDigest>,
     /// Whether to fetch transactions in descending order
     descending_order: bool,
     /// Maximum number of transactions to fetch
     max_transactions: usize,
+    /// Largest page the node serves, checked against `max_transactions` at init
+    node_page_limit: usize,
+    /// Order of transactions within emitted batches
+    emit_order: EmitOrder,
+    /// Channel receiving transactions that fail conversion
+    dead_letters: Option<DeadLetterSender>,
+    /// Heuristics classification, disabled when None
+    heuristics: Option<HeuristicsConfig>,
+    /// Shared request scheduler and this source's priority class
+    scheduler: Option<(SuiScheduler, Priority)>,
+    /// Shared connection reused instead of building a client
+    connection: Option<SuiConnection>,
+    /// Network name override, derived from the RPC URL when None
+    network: Option<String>,
+    /// Chain metadata fetched at init
+    chain: Option<ChainMetadata>,
+    /// Whether only digests and effects are fetched
+    effects_only: bool,
+    /// Whether raw transaction and effects BCS bytes are fetched
+    raw_bcs: bool,
+    /// Whether balance changes are fetched and decoded
+    balance_changes: bool,
+    /// Whether the transaction input is kept in `metadata`
+    include_metadata: bool,
+    /// Fullnode lag check, disabled when None
+    health: Option<HealthMonitor>,
+    /// Delay applied before the first poll
+    start_delay: StartDelay,
+    /// Field redaction applied before emission, disabled when None
+    redaction: Option<RedactionConfig>,
+    /// Runtime counters
+    stats: StatsRecorder,
+    /// Bounded checkpoint range, polling the chain head when None
+    checkpoint_range: Option<CheckpointRange>,
+    /// Partition index and count of a partitioned source
+    partition: Option<(u64, u64)>,
+    /// Endpoints polls are spread across, using the source's own client when None
+    pool: Option<EndpointPool>,
+    /// Control events pending emission
+    control: ControlQueue,
+    /// Failed items pending emission as error records
+    item_errors: ItemErrorQueue,
+    /// Handling of items that fail conversion
+    decode_error_policy: ErrorPolicy,
+    /// Handling of failed RPC requests
+    rpc_error_policy: ErrorPolicy,
+    /// Backoff of transient RPC failures, disabled when None
+    retry: Option<RetryConfig>,
+    /// Enrichers run over records before emission
+    enrichers: EnrichmentChain<SuiEvent>,
+    /// Per-address callbacks, disabled when None
+    notifications: Option<NotificationRegistry>,
+    /// Recently emitted digests and the file they persist to, disabled when None
+    digest_filter: Option<PersistedFilter>,
+    /// Age threshold for emitted transactions, disabled when None
+    max_age: Option<AgeOut>,
+    /// Backfill of gaps between head polls, disabled when None
+    backfill: Option<GapBackfill>,
+    /// Chain identifier the cursor was recorded on, unchecked when None
+    expected_chain: Option<String>,
+    /// Whether the cursor is dropped instead of failing init after a network reset
+    restart_on_reset: bool,
+    /// Store the position is saved to and resumed from, disabled when None
+    cursor_store: Option<CursorSlot>,
+    /// Decimals scaling balance changes, disabled when None
+    coin_decimals: Option<CoinDecimals>,
 }
 
 impl SuiTransactionSource {
@@ -60,12 +438,7 @@ impl SuiTransactionSource {
     /// * `max_transactions` - Maximum number of transactions to fetch per poll
     pub fn new(rpc_url: String, interval_ms: u64, max_transactions: usize) -> Self {
         // Set transaction query options
-        let options = SuiTransactionBlockResponseOptions::new()
-            .with_input()
-            .with_effects()
-            .with_events()
-            .with_balance_changes();
-        let query = SuiTransactionBlockResponseQuery::new(None, Some(options));
+        let query = SuiTransactionBlockResponseQuery::new(None, Some(full_options()));
         Self {
             rpc_url,
             interval: Duration::from_millis(interval_ms),
@@ -73,10 +446,44 @@ impl SuiTransactionSource {
             client: None,
             last_processed_digest: None,
             last_processed_checkpoint: None,
+            address_activity: None,
             cursor: None,
             query,
             descending_order: true,
             max_transactions,
+            node_page_limit: rpc::QUERY_MAX_RESULT_LIMIT,
+            emit_order: EmitOrder::default(),
+            dead_letters: None,
+            heuristics: None,
+            scheduler: None,
+            connection: None,
+            network: None,
+            chain: None,
+            start_delay: StartDelay::default(),
+            redaction: None,
+            stats: StatsRecorder::default(),
+            checkpoint_range: None,
+            partition: None,
+            pool: None,
+            control: ControlQueue::default(),
+            item_errors: ItemErrorQueue::default(),
+            decode_error_policy: ErrorPolicy::SkipAndLog,
+            rpc_error_policy: ErrorPolicy::FailFast,
+            retry: None,
+            enrichers: EnrichmentChain::default(),
+            notifications: None,
+            digest_filter: None,
+            max_age: None,
+            backfill: None,
+            expected_chain: None,
+            restart_on_reset: false,
+            cursor_store: None,
+            coin_decimals: None,
+            effects_only: false,
+            raw_bcs: false,
+            balance_changes: true,
+            include_metadata: true,
+            health: None,
         }
     }
 
@@ -97,85 +504,599 @@ impl SuiTransactionSource {
         self
     }
 
+    /// Queries the transactions sent or received by an address
+    ///
+    /// Uses the combined `FromOrToAddress` filter, replacing the query's filter but
+    /// keeping its options. Nodes rejecting it are queried with `FromAddress` and
+    /// `ToAddress` from the same cursor instead, the two pages merged in checkpoint
+    /// order without duplicates.
+    pub fn with_address_activity(mut self, address: SuiAddress) -> Self {
+        self.query.filter = Some(TransactionFilter::FromOrToAddress { addr: address });
+        self.address_activity = Some((address, true));
+        self
+    }
+
     /// Sets the descending order flag
     pub fn with_descending_order(mut self, descending_order: bool) -> Self {
         self.descending_order = descending_order;
         self
     }
 
-    /// Converts SuiTransactionBlockResponse to SuiEvent
-    fn transaction_to_event(&self, transaction: SuiTransactionBlockResponse) -> SuiEvent {
-        let transaction_digest = transaction.digest.to_string();
-        let timestamp = transaction.timestamp_ms.unwrap_or(0);
+    /// Sets the order of transactions within emitted batches
+    pub fn with_emit_order(mut self, emit_order: EmitOrder) -> Self {
+        self.emit_order = emit_order;
+        self
+    }
 
-        // Determine transaction type
-        let transaction_type = if let Some(kind) = transaction
-            .transaction
-            .as_ref()
-            .map(|tx| tx.data.transaction().name())
-        {
-            kind.to_string()
+    /// Sets the channel receiving transactions that fail conversion
+    pub fn with_dead_letter_channel(mut self, sender: DeadLetterSender) -> Self {
+        self.dead_letters = Some(sender);
+        self
+    }
+
+    /// Enables tagging transactions with anti-spam heuristics
+    pub fn with_heuristics(mut self, config: HeuristicsConfig) -> Self {
+        self.heuristics = Some(config);
+        self
+    }
+
+    /// Registers the source with a shared request scheduler
+    pub fn with_scheduler(mut self, scheduler: SuiScheduler, priority: Priority) -> Self {
+        self.scheduler = Some((scheduler, priority));
+        self
+    }
+
+    /// Sets whether the transaction input is kept in `metadata`, enabled by default
+    ///
+    /// The input is still fetched to read the sender and transaction kind, but not
+    /// cloned into the records, which saves allocation and serialization for
+    /// pipelines that only count transactions. See also `compact`.
+    pub fn with_include_metadata(mut self, include_metadata: bool) -> Self {
+        self.include_metadata = include_metadata;
+        self
+    }
+
+    /// Emits `CompactSuiEvent` records instead of full transactions
+    ///
+    /// Drops metadata and balance changes and stops fetching events. Effects are
+    /// still fetched for the execution status in `success`. Configure the source
+    /// before calling this.
+    pub fn compact(self) -> CompactSuiTransactionSource {
+        let mut source = self
+            .with_include_metadata(false)
+            .with_balance_changes(false);
+        if let Some(options) = &mut source.query.options {
+            options.show_events = false;
+        }
+        CompactSuiTransactionSource::new(source)
+    }
+
+    /// Fetches only digests and effects, skipping input, events and balance changes
+    ///
+    /// Emitted events carry execution status and touched objects in `effects`, while
+    /// `metadata` is None and `transaction_type` and `sender` are "unknown". Heuristics
+    /// need the transaction input and are skipped in this mode.
+    pub fn with_effects_only(mut self, effects_only: bool) -> Self {
+        self.effects_only = effects_only;
+        self.query.options = Some(self.query_options());
+        self
+    }
+
+    /// Includes the raw transaction and effects BCS bytes, base64 encoded, on every record
+    ///
+    /// Consumers can verify or reserialize transactions from `raw_transaction` and
+    /// `raw_effects` without fetching them a second time.
+    pub fn with_raw_bcs(mut self, raw_bcs: bool) -> Self {
+        self.raw_bcs = raw_bcs;
+        self.query.options = Some(self.query_options());
+        self
+    }
+
+    /// Sets whether balance changes are fetched and decoded, enabled by default
+    ///
+    /// Disabling them shrinks responses for pipelines that do not track balances.
+    pub fn with_balance_changes(mut self, balance_changes: bool) -> Self {
+        self.balance_changes = balance_changes;
+        self.query.options = Some(self.query_options());
+        self
+    }
+
+    /// Sets `decimal_amount` of balance changes, scaled by the decimals of the coin
+    ///
+    /// Decimals are read from the coin metadata, once per coin type.
+    pub fn with_scaled_amounts(mut self, scaled_amounts: bool) -> Self {
+        self.coin_decimals = scaled_amounts.then(CoinDecimals::new);
+        self
+    }
+
+    /// Enables periodic checks of the connected fullnode against local time
+    ///
+    /// # Parameters
+    /// * `interval` - Minimum time between checks, each costing one extra RPC call
+    /// * `lag_threshold` - Lag above which a warning is logged
+    pub fn with_health_check(mut self, interval: Duration, lag_threshold: Duration) -> Self {
+        self.health = Some(HealthMonitor::new(interval, lag_threshold));
+        self
+    }
+
+    /// Compares the fullnode with a reference endpoint during health checks
+    pub fn with_health_reference(mut self, rpc_url: impl Into<String>) -> Self {
+        self.health = self
+            .health
+            .map(|health| health.with_reference(rpc_url.into()));
+        self
+    }
+
+    /// Returns the result of the last health check, None unless enabled
+    pub fn health(&self) -> Option<&NodeHealth> {
+        self.health.as_ref().map(HealthMonitor::health)
+    }
+
+    /// Reuses a shared connection instead of building a client in `init`
+    ///
+    /// The connection's endpoint takes precedence over the RPC URL of the source.
+    pub fn with_connection(mut self, connection: SuiConnection) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Sets the network name stamped on records, e.g. for custom endpoints
+    pub fn with_network(mut self, network: impl Into<String>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
+    /// Returns the chain metadata fetched at init
+    pub fn chain_metadata(&self) -> Option<&ChainMetadata> {
+        self.chain.as_ref()
+    }
+
+    /// Delays the first poll by a random duration of up to `max_jitter`
+    pub fn with_start_jitter(mut self, max_jitter: Duration) -> Self {
+        self.start_delay.jitter = max_jitter;
+        self
+    }
+
+    /// Delays the first poll by a fixed offset, e.g. `replica * interval / replicas`
+    pub fn with_phase_offset(mut self, offset: Duration) -> Self {
+        self.start_delay.phase_offset = offset;
+        self
+    }
+
+    /// Redacts sender, recipient and metadata fields of records before emission
+    pub fn with_redaction(mut self, config: RedactionConfig) -> Self {
+        self.redaction = Some(config);
+        self
+    }
+
+    /// Reads the transactions of checkpoints `start..=end` instead of polling the chain head
+    ///
+    /// Checkpoints are read in ascending order, one `TransactionFilter::Checkpoint`
+    /// query at a time, keeping the filter's options but replacing its filter. Polls
+    /// wait for checkpoints that do not exist yet, so `end` may lie in the future, and
+    /// once the range is exhausted every poll returns None; see `is_range_complete`.
+    ///
+    /// Sources wrapped with `controlled()` emit `ControlEvent::CheckpointComplete`
+    /// after the last transaction of every checkpoint of the range.
+    pub fn with_checkpoint_range(
+        mut self,
+        start: CheckpointSequenceNumber,
+        end: CheckpointSequenceNumber,
+    ) -> Self {
+        self.checkpoint_range = Some(CheckpointRange {
+            next: start,
+            end,
+            cursor: None,
+            latest: None,
+            stride: 1,
+        });
+        self
+    }
+
+    /// Reads only the checkpoints `seq` where `seq % count == index`
+    ///
+    /// Run `count` sources with indices `0..count` to split the chain between
+    /// them instead of polling the same head redundantly. Partitioned sources read
+    /// checkpoint by checkpoint like `with_checkpoint_range`, from the head at init
+    /// unless a range is set, and sources sharing a `SuiConnection` share one
+    /// latest-checkpoint lookup per polling interval. An `index` of `count` or more
+    /// wraps around.
+    pub fn with_partition(mut self, index: u64, count: u64) -> Self {
+        let count = count.max(1);
+        self.partition = Some((index % count, count));
+        self
+    }
+
+    /// Reads the transactions of checkpoints `start..=end` as an async stream
+    ///
+    /// For scripts and batch jobs outside the Fluxus `Source` loop: the source is
+    /// initialized on first poll, its polling interval is dropped while catching
+    /// up, and the stream ends once the range is read or after yielding the first
+    /// error. Checkpoints past the chain head are waited for, polling at the
+    /// configured interval, or every second when it is zero.
+    ///
+    /// ```rust,no_run
+    /// use fluxus_source_sui::SuiTransactionSource;
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn run() -> fluxus::utils::models::StreamResult<()> {
+    /// let transactions: Vec<_> = SuiTransactionSource::new_with_mainnet(0, 50)
+    ///     .fetch_range(1_000_000, 1_000_010)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_range(
+        mut self,
+        start: CheckpointSequenceNumber,
+        end: CheckpointSequenceNumber,
+    ) -> impl Stream<Item = StreamResult<SuiEvent>> + Send {
+        let head_wait = if self.interval.is_zero() {
+            RANGE_HEAD_WAIT
         } else {
-            "unknown".to_string()
+            self.interval
         };
+        self.interval = Duration::ZERO;
+        let source = self.with_checkpoint_range(start, end);
 
-        // Get sender address
-        let sender = transaction
-            .transaction
+        futures::stream::try_unfold(
+            (source, VecDeque::new()),
+            |(mut source, mut buffered)| async move {
+                if !source.is_initialized() {
+                    source.init().await?;
+                }
+                loop {
+                    if let Some(event) = buffered.pop_front() {
+                        return Ok(Some((event, (source, buffered))));
+                    }
+                    if source.is_range_complete() {
+                        return Ok(None);
+                    }
+                    match source.next().await? {
+                        Some(record) => buffered.extend(record.data),
+                        None if source.awaiting_head() => sleep(head_wait).await,
+                        None => {}
+                    }
+                }
+            },
+        )
+    }
+
+    /// Returns whether the next checkpoint of the range is past the chain head
+    fn awaiting_head(&self) -> bool {
+        self.checkpoint_range.as_ref().is_some_and(|range| {
+            range.next <= range.end && range.latest.is_some_and(|latest| range.next > latest)
+        })
+    }
+
+    /// Returns whether a bounded checkpoint range has been read completely
+    pub fn is_range_complete(&self) -> bool {
+        self.checkpoint_range
             .as_ref()
-            .map(|tx| tx.data.sender().as_ref())
-            .map(|addr| {
-                SuiAddress::try_from(addr)
-                    .map_err(|_| "Invalid sender address format")
-                    .ok()
-                    .map(|addr| addr.to_string())
-                    .unwrap_or_else(|| "unknown".to_string())
-            })
-            .unwrap_or_else(|| "unknown".to_string());
+            .is_some_and(|range| range.next > range.end)
+    }
+
+    /// Spreads polls across the endpoints of a pool instead of one RPC URL
+    pub fn with_endpoint_pool(mut self, pool: EndpointPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
 
-        let metadata = transaction.transaction.as_ref().map(|tx| tx.data.clone());
+    /// Appends an enricher run over records before emission, after those added before
+    pub fn with_enricher(mut self, enricher: impl Enricher<SuiEvent> + 'static) -> Self {
+        self.enrichers.push(enricher);
+        self
+    }
 
-        SuiEvent {
-            transaction_digest,
-            transaction_type,
-            timestamp,
-            sender,
-            metadata,
-        }
+    /// Sets the maximum number of lookups of one enricher in flight at once, 8 by default
+    pub fn with_enrichment_concurrency(mut self, concurrency: usize) -> Self {
+        self.enrichers.set_concurrency(concurrency);
+        self
+    }
+
+    /// Bounds the time enrichers may delay a poll, cancelling the lookups still in flight
+    pub fn with_enrichment_deadline(mut self, deadline: Duration) -> Self {
+        self.enrichers.set_deadline(deadline);
+        self
+    }
+
+    /// Drops transactions emitted before a restart, remembered in a file
+    ///
+    /// Emitted digests are kept in a Bloom filter written to `path` every few
+    /// seconds and at close, and read back at init, so a pipeline restarted from an
+    /// imprecise cursor does not re-emit the overlap. A false positive drops a
+    /// transaction that was never emitted, with probability `false_positive_rate`.
+    ///
+    /// # Parameters
+    /// * `path` - File the filter persists to
+    /// * `capacity` - Number of recent digests remembered
+    /// * `false_positive_rate` - Probability that a new transaction is dropped
+    pub fn with_digest_filter(
+        mut self,
+        path: impl Into<PathBuf>,
+        capacity: usize,
+        false_positive_rate: f64,
+    ) -> Self {
+        self.digest_filter = Some(PersistedFilter::new(
+            path.into(),
+            DigestFilter::new(capacity, false_positive_rate),
+        ));
+        self
+    }
+
+    /// Sets how per-item decode errors are handled, `SkipAndLog` by default
+    pub fn with_decode_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.decode_error_policy = policy;
+        self
+    }
+
+    /// Sets how per-poll RPC errors are handled, `FailFast` by default
+    pub fn with_rpc_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.rpc_error_policy = policy;
+        self
+    }
+
+    /// Retries transient RPC failures with exponential backoff, disabled by default
+    ///
+    /// Rate limiting, server errors and timeouts are retried within the poll, up to
+    /// `max_attempts` requests. Other failures, and transient ones still failing
+    /// after the last attempt, are handled by the RPC error policy.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Drops transactions older than `max_age` relative to the chain head
+    ///
+    /// Meant for resuming after a long downtime when only fresh data matters and
+    /// the gap is accepted. The head time is the timestamp of the newest
+    /// checkpoint, fetched once per non-empty poll. Instead of one log line per
+    /// dropped transaction, a run of dropped transactions is summarized once the
+    /// first fresh one is emitted: as a warning, and as `ControlEvent::AgedOut`
+    /// for sources wrapped with `controlled()`.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(AgeOut {
+            max_age,
+            skipped: 0,
+            oldest: 0,
+            newest: 0,
+        });
+        self
+    }
+
+    /// Fills gaps left by bursts between head polls, fetching at most `max_depth`
+    /// extra transactions per poll
+    ///
+    /// A descending page that does not reach back to the newest transaction of the
+    /// previous poll is followed by older pages until it does, and the transactions
+    /// already emitted are dropped from it. Ascending reads keep paging forward
+    /// while the node holds further pages. Gaps left once `max_depth` is spent are
+    /// logged, and reported as `ControlEvent::GapDetected` by sources wrapped with
+    /// `controlled()`. Ignored for checkpoint ranges and the split address
+    /// activity query, which page by cursor.
+    pub fn with_gap_backfill(mut self, max_depth: usize) -> Self {
+        self.backfill = Some(GapBackfill {
+            max_depth,
+            newest: None,
+        });
+        self
+    }
+
+    /// Sets the chain identifier the cursor was recorded on
+    ///
+    /// `init` fails with `SuiSourceError::NetworkReset` when the node reports
+    /// another chain, e.g. after a devnet wipe invalidated the cursor.
+    pub fn with_expected_chain(mut self, chain_identifier: impl Into<String>) -> Self {
+        self.expected_chain = Some(chain_identifier.into());
+        self
+    }
+
+    /// Drops the cursor and reads from the head instead of failing on a network reset
+    pub fn with_restart_on_reset(mut self, restart_on_reset: bool) -> Self {
+        self.restart_on_reset = restart_on_reset;
+        self
+    }
+
+    /// Saves the position to a cursor store and resumes from it at init
+    ///
+    /// The newest emitted digest is saved after every poll. A saved digest replaces
+    /// the configured cursor and switches to ascending order, so a restarted source
+    /// reads on from where the previous run stopped.
+    pub fn with_cursor_store(
+        mut self,
+        store: Arc<dyn CursorStore>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.cursor_store = Some(CursorSlot::new(store, name.into()));
+        self
+    }
+
+    /// Saves the position to an asynchronous cursor store, see `with_cursor_store`
+    pub fn with_async_cursor_store(
+        mut self,
+        store: Arc<dyn AsyncCursorStore>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.cursor_store = Some(CursorSlot::new_async(store, name.into()));
+        self
+    }
+
+    /// Invokes the callbacks of a registry for emitted records, before redaction
+    pub fn with_notifications(mut self, registry: NotificationRegistry) -> Self {
+        self.notifications = Some(registry);
+        self
+    }
+
+    /// Interleaves lifecycle control events with the emitted records
+    pub fn controlled(self) -> ControlledSource<Self> {
+        ControlledSource::new(self)
+    }
+
+    /// Emits failed items and polls as error records instead of only logging them
+    pub fn item_results(self) -> ItemResultSource<Self> {
+        ItemResultSource::new(self)
+    }
+
+    /// Returns a snapshot of the runtime counters
+    pub fn stats(&self) -> SourceStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets whether `bytes_fetched` is counted, disabled by default
+    pub fn with_byte_stats(mut self, byte_stats: bool) -> Self {
+        self.stats.count_bytes(byte_stats);
+        self
+    }
+
+    /// Sets the largest page the node serves, 50 by default as on standard fullnodes
+    pub fn with_node_page_limit(mut self, limit: usize) -> Self {
+        self.node_page_limit = limit;
+        self
     }
 
     pub fn is_initialized(&self) -> bool {
         self.initialized
     }
-}
 
-#[async_trait]
-impl Source<Vec<SuiEvent>> for SuiTransactionSource {
-    async fn init(&mut self) -> StreamResult<()> {
-        if self.initialized {
-            return Ok(());
+    /// Query options matching the effects-only, raw BCS and balance change settings
+    fn query_options(&self) -> SuiTransactionBlockResponseOptions {
+        let mut options = if self.effects_only {
+            SuiTransactionBlockResponseOptions::new().with_effects()
+        } else {
+            full_options()
+        };
+        options.show_balance_changes &= self.balance_changes;
+        if self.raw_bcs {
+            options.with_raw_input().with_raw_effects()
+        } else {
+            options
         }
+    }
+}
 
-        // Initialize Sui client
-        let client = SuiClientBuilder::default()
-            .build(self.rpc_url.as_str())
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to initialize Sui client: {}", e);
-                StreamError::Runtime(format!("Failed to initialize Sui client: {}", e))
-            })?;
+/// Transactions sent or received by an address, merging a `FromAddress` and a
+/// `ToAddress` query read from the same cursor
+///
+/// The merged page is cut to `limit`, so it never reaches past the last
+/// transaction of a full page and the next page resumes both queries in step.
+async fn query_address_activity(
+    client: &SuiClient,
+    address: SuiAddress,
+    options: Option<SuiTransactionBlockResponseOptions>,
+    cursor: Option<TransactionDigest>,
+    limit: usize,
+    descending_order: bool,
+) -> StreamResult<TransactionBlocksPage> {
+    let query = |filter| SuiTransactionBlockResponseQuery::new(Some(filter), options.clone());
+    let (sent, received) = tokio::try_join!(
+        rpc::call(
+            "SuiTransactionSource",
+            "suix_queryTransactionBlocks",
+            &format!("fetch transactions sent by {}", address),
+            client.read_api().query_transaction_blocks(
+                query(TransactionFilter::FromAddress(address)),
+                cursor,
+                Some(limit),
+                descending_order,
+            ),
+        ),
+        rpc::call(
+            "SuiTransactionSource",
+            "suix_queryTransactionBlocks",
+            &format!("fetch transactions received by {}", address),
+            client.read_api().query_transaction_blocks(
+                query(TransactionFilter::ToAddress(address)),
+                cursor,
+                Some(limit),
+                descending_order,
+            ),
+        ),
+    )?;
 
-        self.client = Some(client);
-        self.initialized = true;
-        tracing::info!(
-            "SuiTransactionSource initialized with RPC URL: {}",
-            self.rpc_url
-        );
+    let has_next_page = sent.has_next_page || received.has_next_page;
+    let mut seen = HashSet::new();
+    let mut data: Vec<SuiTransactionBlockResponse> = sent
+        .data
+        .into_iter()
+        .chain(received.data)
+        .filter(|transaction| seen.insert(transaction.digest))
+        .collect();
+    data.sort_by_key(|transaction| {
+        (
+            transaction.checkpoint.unwrap_or(u64::MAX),
+            transaction.timestamp_ms.unwrap_or(u64::MAX),
+        )
+    });
+    if descending_order {
+        data.reverse();
+    }
+    let truncated = data.len() > limit;
+    data.truncate(limit);
 
-        Ok(())
+    Ok(TransactionBlocksPage {
+        next_cursor: data.last().map(|transaction| transaction.digest).or(cursor),
+        has_next_page: has_next_page || truncated,
+        data,
+    })
+}
+
+/// Timestamp of the newest checkpoint (milliseconds)
+async fn head_timestamp(client: &SuiClient) -> StreamResult<u64> {
+    let page = rpc::call(
+        "SuiTransactionSource",
+        "sui_getCheckpoints",
+        "fetch latest checkpoint",
+        client.read_api().get_checkpoints(None, Some(1), true),
+    )
+    .await?;
+    page.data
+        .first()
+        .map(|checkpoint| checkpoint.timestamp_ms)
+        .ok_or_else(|| StreamError::Runtime("No checkpoint found".to_string()))
+}
+
+/// Query options fetching input, effects, events and balance changes
+fn full_options() -> SuiTransactionBlockResponseOptions {
+    SuiTransactionBlockResponseOptions::new()
+        .with_input()
+        .with_effects()
+        .with_events()
+        .with_balance_changes()
+}
+
+/// Base64 encodes raw BCS bytes, None if the node returned none
+fn encode_raw(bytes: &[u8]) -> Option<String> {
+    (!bytes.is_empty()).then(|| BASE64.encode(bytes))
+}
+
+impl ControlEvents for SuiTransactionSource {
+    fn enable_control_events(&mut self) {
+        self.control.enable();
     }
 
-    async fn next(&mut self) -> StreamResult<Option<Record<Vec<SuiEvent>>>> {
+    fn drain_control_events(&mut self) -> Vec<ControlEvent> {
+        self.control.drain()
+    }
+}
+
+impl ItemErrors for SuiTransactionSource {
+    fn source_name(&self) -> &'static str {
+        "SuiTransactionSource"
+    }
+
+    fn enable_item_errors(&mut self) {
+        self.item_errors.enable();
+    }
+
+    fn drain_item_errors(&mut self) -> Vec<SourceItemError> {
+        self.item_errors.drain()
+    }
+}
+
+impl SuiTransactionSource {
+    /// Polls once, drawing every request of the poll from the scheduler budget
+    async fn poll(&mut self) -> StreamResult<Option<Record<Vec<SuiEvent>>>> {
         // Ensure initialized
         if !self.initialized || self.client.is_none() {
             return Err(StreamError::Runtime(
@@ -183,34 +1104,227 @@ impl Source<Vec<SuiEvent>> for SuiTransactionSource {
             ));
         }
 
+        // Spread the first poll of replicas started together
+        self.start_delay.wait().await;
+
         // Polling interval
         sleep(self.interval).await;
 
-        let client = self.client.as_ref().ok_or_else(|| {
-            StreamError::Runtime("SuiTransactionSource client not available".to_string())
-        })?;
-
-        // Get recent transactions
-        let transactions = client
-            .read_api()
-            .query_transaction_blocks(
-                self.query.clone(),
-                self.cursor,
-                Some(self.max_transactions),
-                self.descending_order,
-            )
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch transactions: {}", e);
-                StreamError::Runtime(format!("Failed to fetch transactions: {}", e))
-            })?;
+        // Mark the shared connection as active
+        if let Some(connection) = &self.connection {
+            connection.touch();
+        }
+
+        self.stats.poll();
+
+        // Pick the endpoint of this poll when spreading polls across a pool
+        let picked = match &self.pool {
+            Some(pool) => Some(pool.pick().await?),
+            None => None,
+        };
+        if let Some(endpoint) = &picked {
+            self.control.observe_endpoint(&endpoint.rpc_url);
+        }
+        let client = match &picked {
+            Some(endpoint) => &endpoint.client,
+            None => self.client.as_ref().ok_or_else(|| {
+                StreamError::Runtime("SuiTransactionSource client not available".to_string())
+            })?,
+        };
+
+        // Check fullnode lag against local time
+        if let Some(health) = &mut self.health {
+            health.check("SuiTransactionSource", client).await;
+        }
+
+        // Wait for the next checkpoint of a range to be produced
+        if let Some(range) = &mut self.checkpoint_range
+            && range.next <= range.end
+            && range.latest.is_none_or(|latest| range.next > latest)
+        {
+            let latest = match &self.connection {
+                Some(connection) => connection.latest_checkpoint(self.interval).await,
+                None => {
+                    rpc::call(
+                        "SuiTransactionSource",
+                        "sui_getLatestCheckpointSequenceNumber",
+                        "fetch latest checkpoint",
+                        client.read_api().get_latest_checkpoint_sequence_number(),
+                    )
+                    .await
+                }
+            }
+            .inspect_err(|_| self.stats.error("rpc"))?;
+            range.latest = Some(latest);
+            if range.next > latest {
+                tracing::info!("Checkpoint {} not produced yet", range.next);
+                return Ok(None);
+            }
+        }
+
+        // Read the next checkpoint of a bounded range, or the recent transactions
+        let (query, cursor, descending_order) = match &self.checkpoint_range {
+            Some(range) if range.next > range.end => return Ok(None),
+            Some(range) => (
+                SuiTransactionBlockResponseQuery::new(
+                    Some(TransactionFilter::Checkpoint(range.next)),
+                    self.query.options.clone(),
+                ),
+                range.cursor,
+                false,
+            ),
+            None => (self.query.clone(), self.cursor, self.descending_order),
+        };
+        let mut fetch = PhaseTimer::start("SuiTransactionSource", Phase::Fetch);
+        let mut failures = 0;
+        let mut retries = 0;
+        let (mut transactions, split_query) = loop {
+            let fallback = match self.address_activity {
+                Some((address, false)) if self.checkpoint_range.is_none() => Some(address),
+                _ => None,
+            };
+            let result = match fallback {
+                Some(address) => {
+                    query_address_activity(
+                        client,
+                        address,
+                        query.options.clone(),
+                        cursor,
+                        self.max_transactions,
+                        descending_order,
+                    )
+                    .await
+                }
+                None => {
+                    rpc::call(
+                        "SuiTransactionSource",
+                        "suix_queryTransactionBlocks",
+                        "fetch transactions",
+                        client.read_api().query_transaction_blocks(
+                            query.clone(),
+                            cursor,
+                            Some(self.max_transactions),
+                            descending_order,
+                        ),
+                    )
+                    .await
+                }
+            };
+            match result {
+                Ok(transactions) => break (transactions, fallback.is_some()),
+                Err(StreamError::Runtime(message))
+                    if fallback.is_none()
+                        && self.checkpoint_range.is_none()
+                        && matches!(self.address_activity, Some((_, true)))
+                        && is_address_filter_unsupported(&message) =>
+                {
+                    // Merge the sent and received transactions instead
+                    self.stats.error("address_filter_unsupported");
+                    tracing::warn!(
+                        "Node rejects the combined address filter, querying sent and received transactions apart: {}",
+                        message
+                    );
+                    if let Some((_, combined)) = &mut self.address_activity {
+                        *combined = false;
+                    }
+                }
+                Err(StreamError::Runtime(message))
+                    if is_transient(&message)
+                        && self.retry.is_some_and(|retry| retry.allows(retries)) =>
+                {
+                    self.stats.error("transient");
+                    retries += 1;
+                    let delay = self
+                        .retry
+                        .map_or(Duration::ZERO, |retry| retry.delay(retries));
+                    tracing::warn!(
+                        "Transient RPC failure, retrying in {:?}: {}",
+                        delay,
+                        message
+                    );
+                    sleep(delay).await;
+                }
+                Err(e) => {
+                    self.stats.error("rpc");
+                    if let Some(endpoint) = &picked {
+                        endpoint.mark_failed();
+                    }
+                    failures += 1;
+                    match self.rpc_error_policy.recovery(failures) {
+                        Recovery::Fail => return Err(e),
+                        Recovery::Skip => {
+                            tracing::warn!("Skipping poll after {} failed attempts", failures);
+                            self.item_errors.fetch_failed("SuiTransactionSource", &e);
+                            return Ok(None);
+                        }
+                        Recovery::Retry => sleep(self.interval).await,
+                    }
+                }
+            }
+        };
+
+        // Page past the polled page until it joins the previous poll
+        let mut backfilled_newest = None;
+        if let Some(backfill) = &mut self.backfill
+            && self.checkpoint_range.is_none()
+            && !split_query
+        {
+            match backfill
+                .fill(
+                    client,
+                    &query,
+                    &mut transactions,
+                    self.max_transactions,
+                    descending_order,
+                )
+                .await
+            {
+                Ok(0) => {}
+                Ok(fetched) => tracing::info!("Backfilled {} transactions", fetched),
+                Err(e) => {
+                    self.stats.error("rpc");
+                    tracing::warn!("Backfill stopped early: {}", e);
+                }
+            }
+            if descending_order {
+                if !backfill.connects(&transactions.data) {
+                    tracing::warn!(
+                        "Gap not filled within the backfill depth of {} transactions",
+                        backfill.max_depth
+                    );
+                }
+                backfill.trim(&mut transactions);
+                backfilled_newest = transactions
+                    .data
+                    .first()
+                    .map(|newest| (newest.digest, newest.checkpoint));
+            }
+        }
+        self.stats.fetched(&transactions);
+        fetch.items(transactions.data.len());
+        drop(fetch);
 
         // Return None if no new transactions
         if transactions.data.is_empty() {
+            advance_page(
+                &mut self.checkpoint_range,
+                &mut self.control,
+                &mut self.cursor,
+                &transactions,
+                descending_order,
+            );
             tracing::info!("No new transactions found");
             return Ok(None);
         }
 
+        // Newest transaction of the page, saved as the position to resume from
+        let newest_digest = if descending_order {
+            transactions.data.first()
+        } else {
+            transactions.data.last()
+        }
+        .map(|transaction| transaction.digest);
+
         // Get latest transaction digest
         let latest_transaction = transactions
             .data
@@ -222,33 +1336,365 @@ impl Source<Vec<SuiEvent>> for SuiTransactionSource {
         if let Some(last_digest) = &self.last_processed_digest
             && last_digest == &latest_digest
         {
+            advance_page(
+                &mut self.checkpoint_range,
+                &mut self.control,
+                &mut self.cursor,
+                &transactions,
+                descending_order,
+            );
             tracing::info!("No new transactions since last check");
             return Ok(None);
         }
+        let latest_checkpoint = latest_transaction.checkpoint;
+
+        // Convert transactions to events, routing malformed ones to the dead-letter channel
+        let mut decode = PhaseTimer::start("SuiTransactionSource", Phase::Decode);
+        let mut events = Vec::with_capacity(transactions.data.len());
+        for tx in &transactions.data {
+            let mut converted = convert(tx, self.effects_only, self.include_metadata);
+            let mut refetched = None;
+            if let Err(error) = &converted {
+                match self.decode_error_policy {
+                    ErrorPolicy::FailFast => {
+                        self.stats.error("conversion");
+                        return Err(StreamError::Runtime(format!(
+                            "Failed to convert transaction {}: {}",
+                            tx.digest, error
+                        )));
+                    }
+                    ErrorPolicy::SkipAndLog => {}
+                    ErrorPolicy::RetryThenSkip { retries } => {
+                        // Fields such as the timestamp may be missing until the node catches up
+                        let options = query.options.clone().unwrap_or_else(full_options);
+                        for _ in 0..retries {
+                            sleep(self.interval).await;
+                            let Ok(response) = rpc::call(
+                                "SuiTransactionSource",
+                                "sui_getTransactionBlock",
+                                &format!("refetch transaction {}", tx.digest),
+                                client
+                                    .read_api()
+                                    .get_transaction_with_options(tx.digest, options.clone()),
+                            )
+                            .await
+                            else {
+                                continue;
+                            };
+                            converted =
+                                convert(&response, self.effects_only, self.include_metadata);
+                            if converted.is_ok() {
+                                refetched = Some(response);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            let tx = refetched.as_ref().unwrap_or(tx);
+            match converted {
+                Ok(mut event) => {
+                    event.chain = self.chain.clone();
+                    for chain_event in &mut event.events {
+                        chain_event.chain = self.chain.clone();
+                    }
+                    if self.raw_bcs {
+                        event.raw_transaction = encode_raw(&tx.raw_transaction);
+                        event.raw_effects = encode_raw(&tx.raw_effects);
+                    }
+                    if let Some(heuristics) = &self.heuristics {
+                        event.tags = heuristics.classify(tx);
+                    }
+                    tracing::debug!(
+                        "Processed Sui transaction: {} checkpoint: {:?}",
+                        tx.digest,
+                        tx.checkpoint
+                    );
+                    events.push(event);
+                }
+                Err(error) => {
+                    self.stats.error("conversion");
+                    let letter = DeadLetter {
+                        source: "SuiTransactionSource".to_string(),
+                        item_id: tx.digest.to_string(),
+                        error,
+                        raw: format!("{:?}", tx),
+                    };
+                    self.item_errors.decode_failed(&letter);
+                    route_dead_letter(self.dead_letters.as_ref(), letter);
+                }
+            }
+        }
 
-        // Update last processed digest and checkpoint
+        if let Some(heuristics) = &self.heuristics {
+            heuristics.tag_failed_spam(&transactions.data, &mut events);
+        }
+        decode.items(events.len());
+        drop(decode);
+
+        // Move past the page only once it converted, so a failed page is read again
+        advance_page(
+            &mut self.checkpoint_range,
+            &mut self.control,
+            &mut self.cursor,
+            &transactions,
+            descending_order,
+        );
+        if let (Some(backfill), Some(newest)) = (&mut self.backfill, backfilled_newest) {
+            backfill.newest = Some(newest);
+        }
+        self.stats.set_cursor(Some(latest_digest.clone()));
         self.last_processed_digest = Some(latest_digest);
-        self.last_processed_checkpoint = latest_transaction.checkpoint;
+        self.last_processed_checkpoint = latest_checkpoint;
 
-        // Convert transactions to events
-        let events: Vec<SuiEvent> = transactions
-            .data
-            .into_iter()
-            .map(|tx| {
-                let event = self.transaction_to_event(tx.clone());
-                tracing::debug!(
-                    "Processed Sui transaction: {} checkpoint: {:?}",
-                    tx.digest,
-                    tx.checkpoint
+        // Return None if every transaction was dropped
+        if events.is_empty() {
+            tracing::info!("No convertible transactions found");
+            return Ok(None);
+        }
+
+        // Drop transactions emitted before a restart
+        if let Some(filter) = &self.digest_filter {
+            let fetched = events.len();
+            events.retain(|event| !filter.contains(&event.transaction_digest));
+            self.stats.duplicates(fetched - events.len());
+            if events.is_empty() {
+                tracing::info!("No transactions beyond those already emitted");
+                return Ok(None);
+            }
+        }
+
+        // Drop transactions older than the maximum age, summarizing the dropped run
+        if let Some(age_out) = &mut self.max_age {
+            let head_ms = head_timestamp(client)
+                .await
+                .inspect_err(|_| self.stats.error("rpc"))?;
+            let threshold = head_ms.saturating_sub(age_out.max_age.as_millis() as u64);
+            events.retain(|event| age_out.keep(event.timestamp, threshold));
+            if events.is_empty() {
+                tracing::info!("No transactions within the maximum age");
+                return Ok(None);
+            }
+            if let Some((skipped, oldest, newest)) = age_out.take_report() {
+                tracing::warn!(
+                    "Skipped {} transactions older than {:?} (timestamps {} to {})",
+                    skipped,
+                    age_out.max_age,
+                    oldest,
+                    newest
                 );
-                event
-            })
-            .collect();
+                self.control.aged_out(skipped, oldest, newest);
+            }
+        }
+
+        // Track gaps between head polls and epoch changes
+        if self.checkpoint_range.is_none() {
+            let checkpoints = events.iter().filter_map(|event| event.checkpoint);
+            if let (Some(lowest), Some(highest)) = (checkpoints.clone().min(), checkpoints.max()) {
+                self.control.observe_checkpoints(lowest, highest);
+            }
+        }
+        if let Some(epoch) = transactions
+            .data
+            .iter()
+            .filter_map(|tx| tx.effects.as_ref().map(|effects| effects.executed_epoch()))
+            .max()
+        {
+            self.control.observe_epoch(epoch);
+        }
 
+        let mut emit = PhaseTimer::start("SuiTransactionSource", Phase::Emit);
+
+        // Scale balance changes into decimal amounts of their coin
+        if let Some(coin_decimals) = &mut self.coin_decimals {
+            for event in &mut events {
+                for change in &mut event.balance_changes {
+                    if let Some(decimals) = coin_decimals
+                        .get(
+                            "SuiTransactionSource",
+                            client,
+                            self.connection.as_ref(),
+                            &change.coin_type,
+                        )
+                        .await
+                    {
+                        change.decimal_amount = Some(format_amount(change.amount, decimals));
+                    }
+                }
+            }
+        }
+
+        self.emit_order
+            .arrange(&mut events, descending_order, |event| {
+                (event.checkpoint.unwrap_or_default(), event.timestamp)
+            });
+
+        let failures = self.enrichers.apply(&mut events).await;
+        self.stats.errors("enrichment", failures);
+
+        if let Some(notifications) = &self.notifications {
+            notifications.notify_transfers(&events);
+        }
+
+        if let Some(redaction) = &self.redaction {
+            for item in &mut events {
+                item.redact(redaction);
+            }
+        }
+
+        // Remember the emitted digests across restarts
+        if let Some(filter) = &mut self.digest_filter {
+            for event in &events {
+                filter.insert(&event.transaction_digest);
+            }
+            if let Err(e) = filter.save_due().await {
+                self.stats.error("digest_filter");
+                tracing::warn!("{}", e);
+            }
+        }
+        if let (Some(slot), Some(digest)) = (&self.cursor_store, newest_digest)
+            && !slot.save(&digest.to_string()).await
+        {
+            self.stats.error("cursor_store");
+        }
+
+        self.stats.emitted(events.len());
+        emit.items(events.len());
         Ok(Some(Record::new(events)))
     }
+}
+
+#[async_trait]
+impl Source<Vec<SuiEvent>> for SuiTransactionSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        if self.initialized {
+            return Ok(());
+        }
+
+        // Initialize Sui client, reusing the endpoint pool or shared connection if set
+        let client = match (&self.pool, &self.connection) {
+            (Some(pool), _) => {
+                pool.connect("SuiTransactionSource").await?;
+                pool.pick().await?.client
+            }
+            (None, Some(connection)) => connection.client().clone(),
+            (None, None) => {
+                rpc::call(
+                    "SuiTransactionSource",
+                    "rpc.discover",
+                    "initialize Sui client",
+                    SuiClientBuilder::default().build(self.rpc_url.as_str()),
+                )
+                .await?
+            }
+        };
+
+        // Resume from the position saved by a previous run
+        if let Some(slot) = &self.cursor_store
+            && let Some(saved) = slot.load().await?
+        {
+            let digest = TransactionDigest::from_str(saved.trim()).map_err(|e| {
+                StreamError::Runtime(format!("Invalid saved cursor {}: {}", saved, e))
+            })?;
+            tracing::info!("SuiTransactionSource resuming after {}", digest);
+            self.cursor = Some(digest);
+            self.descending_order = false;
+        }
+
+        // Identify the chain stamped on every record
+        let rpc_url = self
+            .connection
+            .as_ref()
+            .map_or(self.rpc_url.as_str(), SuiConnection::rpc_url);
+        let network = self
+            .network
+            .clone()
+            .unwrap_or_else(|| network_name(rpc_url));
+        let chain = ChainMetadata::fetch("SuiTransactionSource", &client, network).await?;
+
+        // Detect a network wiped since the cursor was recorded
+        if let Some(expected) = &self.expected_chain
+            && let Some(error) = chain.reset_from(expected)
+        {
+            self.stats.error("network_reset");
+            if !self.restart_on_reset {
+                return Err(error.into());
+            }
+            tracing::warn!("{}, dropping the cursor", error);
+            self.cursor = None;
+            self.last_processed_digest = None;
+            self.last_processed_checkpoint = None;
+        }
+        self.chain = Some(chain);
+
+        // Keep pages within the node limit instead of failing at runtime
+        self.max_transactions = rpc::check_page_size(
+            "SuiTransactionSource",
+            "max_transactions",
+            self.max_transactions,
+            self.node_page_limit,
+        )?;
+
+        // Restore the digests emitted before a restart
+        if let Some(filter) = &mut self.digest_filter {
+            filter.restore().await;
+        }
+
+        // Start partitioned reads at the head unless a range is set
+        if let Some((index, count)) = self.partition {
+            if self.checkpoint_range.is_none() {
+                let latest = match &self.connection {
+                    Some(connection) => connection.latest_checkpoint(self.interval).await?,
+                    None => {
+                        rpc::call(
+                            "SuiTransactionSource",
+                            "sui_getLatestCheckpointSequenceNumber",
+                            "fetch latest checkpoint",
+                            client.read_api().get_latest_checkpoint_sequence_number(),
+                        )
+                        .await?
+                    }
+                };
+                self.checkpoint_range = Some(CheckpointRange {
+                    next: latest,
+                    end: CheckpointSequenceNumber::MAX,
+                    cursor: None,
+                    latest: Some(latest),
+                    stride: 1,
+                });
+            }
+            if let Some(range) = &mut self.checkpoint_range {
+                range.align(index, count);
+            }
+            tracing::info!(
+                "SuiTransactionSource reading partition {} of {}",
+                index,
+                count
+            );
+        }
+
+        self.client = Some(client);
+        self.initialized = true;
+        self.stats.start();
+        tracing::info!(
+            "SuiTransactionSource initialized with RPC URL: {}",
+            self.rpc_url
+        );
+
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<SuiEvent>>>> {
+        rpc::with_budget(self.scheduler.clone(), self.poll()).await
+    }
 
     async fn close(&mut self) -> StreamResult<()> {
+        if let Some(filter) = &mut self.digest_filter
+            && let Err(e) = filter.flush().await
+        {
+            self.stats.error("digest_filter");
+            tracing::warn!("{}", e);
+        }
         self.initialized = false;
         self.client = None;
         tracing::info!("SuiTransactionSource closed");
@@ -0,0 +1,132 @@
+use crate::conversion::{ConversionError, DeadLetter, DeadLetterSender, route_dead_letter};
+use crate::event::SuiEventSource;
+use crate::item_result::{ItemErrorQueue, ItemErrors, ItemResultSource, SourceItemError};
+use crate::policy::ErrorPolicy;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+/// Event source deserializing the parsed JSON of every event into `T`
+///
+/// Suits sources whose query selects a single Move event type, e.g.
+/// `EventFilter::MoveEventType`. Events of several types are better served by a
+/// `MoveEventRegistry`. An event's JSON never changes, so `RetryThenSkip` skips
+/// failed events right away, like `SkipAndLog`.
+pub struct TypedEventSource<T> {
+    /// Underlying event source
+    inner: SuiEventSource,
+    /// Handling of events that fail to deserialize
+    decode_error_policy: ErrorPolicy,
+    /// Channel receiving events that fail to deserialize, dropped when None
+    dead_letters: Option<DeadLetterSender>,
+    /// Failed events pending emission as error records
+    item_errors: ItemErrorQueue,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedEventSource<T> {
+    /// Creates a new TypedEventSource wrapping an event source
+    pub fn new(inner: SuiEventSource) -> Self {
+        Self {
+            inner,
+            decode_error_policy: ErrorPolicy::SkipAndLog,
+            dead_letters: None,
+            item_errors: ItemErrorQueue::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets how events that fail to deserialize are handled, `SkipAndLog` by default
+    pub fn with_decode_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.decode_error_policy = policy;
+        self
+    }
+
+    /// Forwards events that fail to deserialize to a dead-letter channel
+    pub fn with_dead_letter_channel(mut self, sender: DeadLetterSender) -> Self {
+        self.dead_letters = Some(sender);
+        self
+    }
+
+    /// Emits failed events and polls as error records instead of only logging them
+    pub fn item_results(self) -> ItemResultSource<Self> {
+        ItemResultSource::new(self)
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+impl<T> ItemErrors for TypedEventSource<T> {
+    fn source_name(&self) -> &'static str {
+        "TypedEventSource"
+    }
+
+    fn enable_item_errors(&mut self) {
+        self.inner.enable_item_errors();
+        self.item_errors.enable();
+    }
+
+    fn drain_item_errors(&mut self) -> Vec<SourceItemError> {
+        let mut errors = self.inner.drain_item_errors();
+        errors.extend(self.item_errors.drain());
+        errors
+    }
+}
+
+#[async_trait]
+impl<T> Source<Vec<T>> for TypedEventSource<T>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<T>>>> {
+        let Some(record) = self.inner.next().await? else {
+            return Ok(None);
+        };
+
+        let mut typed = Vec::with_capacity(record.data.len());
+        for event in record.data {
+            match serde_json::from_value::<T>(event.parsed_json.clone()) {
+                Ok(value) => typed.push(value),
+                Err(e) => {
+                    let item_id = format!("{}:{}", event.id.tx_digest, event.id.event_seq);
+                    if self.decode_error_policy == ErrorPolicy::FailFast {
+                        return Err(StreamError::Runtime(format!(
+                            "Failed to deserialize event {} of type {}: {}",
+                            item_id, event.event_type, e
+                        )));
+                    }
+                    let letter = DeadLetter {
+                        source: "TypedEventSource".to_string(),
+                        item_id,
+                        error: ConversionError::InvalidField {
+                            field: "parsed_json".to_string(),
+                            reason: e.to_string(),
+                        },
+                        raw: event.parsed_json.to_string(),
+                    };
+                    self.item_errors.decode_failed(&letter);
+                    route_dead_letter(self.dead_letters.as_ref(), letter);
+                }
+            }
+        }
+
+        // Return None if every event was dropped
+        if typed.is_empty() {
+            tracing::info!("No events deserialized");
+            return Ok(None);
+        }
+        Ok(Some(Record::new(typed)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
@@ -0,0 +1,143 @@
+use crate::connection::SuiConnection;
+use crate::conversion::ConversionError;
+use crate::rpc;
+use std::collections::HashMap;
+use sui_sdk::SuiClient;
+
+/// MIST in one SUI
+pub const MIST_PER_SUI: u64 = 1_000_000_000;
+
+/// Decimals of SUI, one MIST being `10^-9` SUI
+pub const SUI_DECIMALS: u8 = 9;
+
+/// Formats an amount of MIST as a decimal number of SUI, e.g. `1.5`
+pub fn mist_to_sui(mist: i128) -> String {
+    format_amount(mist, SUI_DECIMALS)
+}
+
+/// Parses a decimal number of SUI into MIST, rejecting digits below one MIST
+pub fn sui_to_mist(sui: &str) -> Result<i128, ConversionError> {
+    parse_amount(sui, SUI_DECIMALS)
+}
+
+/// Formats an amount in a coin's smallest unit as a decimal number
+///
+/// The digits are shifted exactly, without a round trip through floats, and
+/// trailing zeros of the fraction are dropped: `format_amount(1_500, 3)` is `1.5`.
+pub fn format_amount(amount: i128, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", amount.unsigned_abs(), width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    let sign = if amount < 0 { "-" } else { "" };
+    if fraction.is_empty() {
+        format!("{}{}", sign, whole)
+    } else {
+        format!("{}{}.{}", sign, whole, fraction)
+    }
+}
+
+/// Parses a decimal number into an amount in a coin's smallest unit
+///
+/// Fails on anything but an optional minus sign, digits and one decimal point,
+/// and on more fractional digits than the coin has decimals.
+pub fn parse_amount(value: &str, decimals: u8) -> Result<i128, ConversionError> {
+    let invalid = |reason: String| ConversionError::InvalidField {
+        field: "amount".to_string(),
+        reason,
+    };
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid(format!("{:?} is not a number", value)));
+    }
+    if !whole
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid(format!("{:?} is not a decimal number", value)));
+    }
+    if fraction.len() > decimals as usize {
+        return Err(invalid(format!(
+            "{:?} has more than {} decimals",
+            value, decimals
+        )));
+    }
+
+    let scaled = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    let amount: i128 = scaled
+        .parse()
+        .map_err(|e| invalid(format!("{:?} is out of range: {}", value, e)))?;
+    Ok(if negative { -amount } else { amount })
+}
+
+/// Returns whether a coin type is SUI, whatever the padding of its address
+fn is_sui(coin_type: &str) -> bool {
+    coin_type.split_once("::").is_some_and(|(address, name)| {
+        address.trim_start_matches("0x").trim_start_matches('0') == "2" && name == "sui::SUI"
+    })
+}
+
+/// Decimals of coin types, looked up once from their coin metadata
+///
+/// Decimals are fixed when a coin is created, so they are cached for the life of
+/// the source. Failed lookups are not cached and retried with the next amount.
+/// Sources with a shared connection look metadata up through it, so the
+/// connection's cache serves every source scaling the same coin types.
+pub(crate) struct CoinDecimals {
+    decimals: HashMap<String, Option<u8>>,
+}
+
+impl CoinDecimals {
+    pub(crate) fn new() -> Self {
+        Self {
+            decimals: HashMap::new(),
+        }
+    }
+
+    /// Returns the decimals of a coin type, None without metadata or on failure
+    pub(crate) async fn get(
+        &mut self,
+        source: &str,
+        client: &SuiClient,
+        connection: Option<&SuiConnection>,
+        coin_type: &str,
+    ) -> Option<u8> {
+        if is_sui(coin_type) {
+            return Some(SUI_DECIMALS);
+        }
+        if let Some(decimals) = self.decimals.get(coin_type) {
+            return *decimals;
+        }
+
+        let metadata = match connection {
+            Some(connection) => connection.get_coin_metadata(coin_type).await,
+            None => {
+                rpc::call(
+                    source,
+                    "suix_getCoinMetadata",
+                    &format!("fetch coin metadata of {}", coin_type),
+                    client
+                        .coin_read_api()
+                        .get_coin_metadata(coin_type.to_string()),
+                )
+                .await
+            }
+        };
+        match metadata {
+            Ok(metadata) => {
+                let decimals = metadata.map(|metadata| metadata.decimals);
+                self.decimals.insert(coin_type.to_string(), decimals);
+                decimals
+            }
+            Err(e) => {
+                tracing::warn!("{} could not scale {} amounts: {}", source, coin_type, e);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::transaction::SuiTransactionSource;
+use crate::window::TumblingWindow;
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoinVolume {
+    /// Coin type of the balance changes, with every address in long form
+    pub coin_type: String,
+    /// Sum of the absolute balance changes within the window, in the coin's smallest unit
+    pub volume: u128,
+    /// Number of balance changes within the window
+    pub changes: u64,
+    /// Number of transactions changing a balance of the coin within the window
+    pub transactions: u64,
+    /// Window start, local time (milliseconds)
+    pub window_start: u64,
+    /// Window end, local time (milliseconds)
+    pub window_end: u64,
+}
+
+/// Volume accumulated for one coin type
+#[derive(Default)]
+struct Accumulated {
+    volume: u128,
+    changes: u64,
+    transactions: u64,
+}
+
+/// Source emitting per-coin-type volume over tumbling windows
+///
+/// Volume is the sum of the absolute balance changes of the transactions polled by
+/// the inner source, which must fetch balance changes (not effects-only), so a
+/// transfer counts both the amount sent and the amount received. Gas payments count
+/// towards the SUI volume. Windows follow local time; one record holding every coin
+/// type moved within the window is emitted when it closes, and polls within the
+/// window yield None.
+pub struct SuiVolumeSource {
+    /// Underlying transaction source
+    inner: SuiTransactionSource,
+    /// Aggregation window
+    window: TumblingWindow,
+    /// Volume accumulated in the current window by coin type
+    volumes: HashMap<String, Accumulated>,
+}
+
+impl SuiVolumeSource {
+    /// Creates a new SuiVolumeSource
+    ///
+    /// # Parameters
+    /// * `inner` - Transaction source providing the balance changes
+    /// * `window` - Length of the aggregation window
+    pub fn new(inner: SuiTransactionSource, window: Duration) -> Self {
+        Self {
+            inner,
+            window: TumblingWindow::new(window),
+            volumes: HashMap::new(),
+        }
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+}
+
+#[async_trait]
+impl Source<Vec<CoinVolume>> for SuiVolumeSource {
+    async fn init(&mut self) -> StreamResult<()> {
+        self.inner.init().await
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<CoinVolume>>>> {
+        self.window.open();
+
+        if let Some(record) = self.inner.next().await? {
+            for transaction in record.data {
+                if !self.window.first_seen(&transaction.transaction_digest) {
+                    continue;
+                }
+                let mut coin_types = Vec::new();
+                for change in &transaction.balance_changes {
+                    let volume = self.volumes.entry(change.coin_type.clone()).or_default();
+                    volume.volume = volume.volume.saturating_add(change.amount.unsigned_abs());
+                    volume.changes += 1;
+                    if !coin_types.contains(&&change.coin_type) {
+                        coin_types.push(&change.coin_type);
+                        volume.transactions += 1;
+                    }
+                }
+            }
+        }
+
+        // Keep accumulating until the window closes
+        let Some((window_start, window_end)) = self.window.close() else {
+            return Ok(None);
+        };
+
+        let mut volumes: Vec<CoinVolume> = self
+            .volumes
+            .drain()
+            .map(|(coin_type, volume)| CoinVolume {
+                coin_type,
+                volume: volume.volume,
+                changes: volume.changes,
+                transactions: volume.transactions,
+                window_start,
+                window_end,
+            })
+            .collect();
+        if volumes.is_empty() {
+            tracing::info!("No balance changes within the window");
+            return Ok(None);
+        }
+        volumes.sort_by(|a, b| b.volume.cmp(&a.volume));
+
+        Ok(Some(Record::new(volumes)))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.inner.close().await
+    }
+}
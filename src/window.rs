@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Local-time tumbling window shared by the derived aggregate sources
+///
+/// Pages of the inner transaction source may overlap, so the window also remembers
+/// the digests counted in the current and previous window to count each once.
+pub(crate) struct TumblingWindow {
+    /// Window length
+    length: Duration,
+    /// Start of the current window, local time (milliseconds)
+    start: Option<(Instant, u64)>,
+    /// Transactions counted in the current window
+    seen: HashSet<String>,
+    /// Transactions counted in the previous window
+    previous_seen: HashSet<String>,
+}
+
+impl TumblingWindow {
+    pub(crate) fn new(length: Duration) -> Self {
+        Self {
+            length,
+            start: None,
+            seen: HashSet::new(),
+            previous_seen: HashSet::new(),
+        }
+    }
+
+    /// Starts the first window if none is open
+    pub(crate) fn open(&mut self) {
+        self.start.get_or_insert_with(|| (Instant::now(), now_ms()));
+    }
+
+    /// Returns whether the transaction has not been counted yet, marking it counted
+    pub(crate) fn first_seen(&mut self, digest: &str) -> bool {
+        !self.previous_seen.contains(digest) && self.seen.insert(digest.to_string())
+    }
+
+    /// Closes the window if its length elapsed, returning its start and end times
+    pub(crate) fn close(&mut self) -> Option<(u64, u64)> {
+        let (started, start) = self.start?;
+        if started.elapsed() < self.length {
+            return None;
+        }
+
+        let end = now_ms();
+        self.start = Some((Instant::now(), end));
+        self.previous_seen = std::mem::take(&mut self.seen);
+        Some((start, end))
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
@@ -0,0 +1,51 @@
+use fluxus_source_sui::{AbortLocation, MoveAbort};
+
+const ABORT: &str = "MoveAbort(MoveLocation { module: ModuleId { address: 0000000000000000000000000000000000000000000000000000000000000002, name: Identifier(\"balance\") }, function: 2, instruction: 10, function_name: Some(\"split\") }, 2) in command 0";
+
+#[test]
+fn test_move_abort_parse() {
+    let abort = MoveAbort::parse(ABORT).expect("Should decode the abort");
+
+    assert_eq!(
+        abort,
+        MoveAbort {
+            location: AbortLocation {
+                package: "0x0000000000000000000000000000000000000000000000000000000000000002"
+                    .to_string(),
+                module: "balance".to_string(),
+                function_index: 2,
+                instruction: 10,
+                function_name: Some("split".to_string()),
+            },
+            code: 2,
+            command: Some(0),
+        }
+    );
+}
+
+#[test]
+fn test_move_abort_without_function_name() {
+    let error = "MoveAbort(MoveLocation { module: ModuleId { address: 0xabc, name: Identifier(\"pool\") }, function: 7, instruction: 3, function_name: None }, 1001)";
+    let abort = MoveAbort::parse(error).expect("Should decode the abort");
+
+    assert_eq!(abort.location.package, "0xabc");
+    assert_eq!(abort.location.function_name, None);
+    assert_eq!(abort.code, 1001);
+    assert_eq!(abort.command, None);
+    assert_eq!(abort.to_string(), "0xabc::pool::#7 abort 1001");
+}
+
+#[test]
+fn test_move_abort_display() {
+    let abort = MoveAbort::parse(ABORT).expect("Should decode the abort");
+    assert!(abort.to_string().ends_with("::balance::split abort 2"));
+}
+
+#[test]
+fn test_move_abort_rejects_other_errors() {
+    assert_eq!(MoveAbort::parse("InsufficientGas"), None);
+    assert_eq!(
+        MoveAbort::parse("MoveObjectTooBig { object_size: 1, max_object_size: 0 }"),
+        None
+    );
+}
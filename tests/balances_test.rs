@@ -0,0 +1,48 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiBalanceSource;
+
+const TEST_ADDRESS: &str = "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c";
+
+#[tokio::test]
+async fn test_sui_balance_source_initial_balances() {
+    let mut source = SuiBalanceSource::new_with_mainnet(100, vec![TEST_ADDRESS.to_string()])
+        .with_initial_balances(true);
+    source.init().await.expect("Initialization failed");
+    assert!(source.is_initialized(), "Source should be initialized");
+
+    if let Ok(Some(updates)) = source.next().await {
+        for update in &updates.data {
+            assert_eq!(update.address, TEST_ADDRESS);
+            assert_eq!(update.old_balance, 0, "Starting balances change from zero");
+            assert_eq!(update.delta, update.new_balance as i128);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_balance_source_emits_only_changes() {
+    let mut source = SuiBalanceSource::new_with_mainnet(100, vec![TEST_ADDRESS.to_string()])
+        .with_coin_type("0x2::sui::SUI");
+    source.init().await.expect("Initialization failed");
+
+    // The first poll records the starting balance
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "First poll should emit nothing"
+    );
+    if let Ok(Some(updates)) = source.next().await {
+        for update in &updates.data {
+            assert_ne!(update.old_balance, update.new_balance);
+            assert_eq!(update.coin_type, "0x2::sui::SUI");
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_balance_source_invalid_address() {
+    let mut source = SuiBalanceSource::new_with_mainnet(100, vec!["not-an-address".to_string()]);
+    assert!(
+        source.init().await.is_err(),
+        "Invalid address should fail init"
+    );
+}
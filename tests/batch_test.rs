@@ -0,0 +1,47 @@
+use fluxus_source_sui::batch_digest;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+struct Item {
+    id: u64,
+    labels: HashMap<String, u64>,
+}
+
+fn item(id: u64) -> Item {
+    Item {
+        id,
+        labels: (0..20)
+            .map(|label| (format!("label{}", label), label))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_batch_digest_ignores_order() {
+    let forward = batch_digest(&[item(1), item(2), item(3)]).expect("Batch should encode");
+    let backward = batch_digest(&[item(3), item(2), item(1)]).expect("Batch should encode");
+    assert_eq!(forward, backward, "Item order should not change the digest");
+
+    // Maps are rebuilt with a fresh iteration order every time
+    assert_eq!(
+        forward,
+        batch_digest(&[item(1), item(2), item(3)]).expect("Batch should encode"),
+        "Map iteration order should not change the digest"
+    );
+}
+
+#[test]
+fn test_batch_digest_detects_changes() {
+    let digest = batch_digest(&[item(1), item(2)]).expect("Batch should encode");
+    assert_ne!(
+        digest,
+        batch_digest(&[item(1), item(3)]).expect("Batch should encode")
+    );
+    assert_ne!(
+        digest,
+        batch_digest(&[item(1), item(2), item(2)]).expect("Batch should encode"),
+        "Repeated items should count"
+    );
+    assert_eq!(digest.len(), 64, "Digest should be hex SHA-256");
+}
@@ -0,0 +1,88 @@
+use fluxus_source_sui::DigestFilter;
+
+#[test]
+fn test_digest_filter_contains_inserted() {
+    let mut filter = DigestFilter::new(1_000, 0.01);
+    for i in 0..1_000 {
+        filter.insert(&format!("digest-{}", i));
+    }
+
+    for i in 0..1_000 {
+        assert!(
+            filter.contains(&format!("digest-{}", i)),
+            "Inserted digests are always found"
+        );
+    }
+    let false_positives = (0..10_000)
+        .filter(|i| filter.contains(&format!("other-{}", i)))
+        .count();
+    assert!(
+        false_positives < 300,
+        "False positives should stay near the configured rate, got {}",
+        false_positives
+    );
+}
+
+#[test]
+fn test_digest_filter_rotation() {
+    let mut filter = DigestFilter::new(10, 0.01);
+    for i in 0..20 {
+        filter.insert(&format!("digest-{}", i));
+    }
+    assert!(filter.contains("digest-0"), "Previous generation is kept");
+
+    for i in 20..30 {
+        filter.insert(&format!("digest-{}", i));
+    }
+    assert!(filter.contains("digest-29"), "Recent digests are kept");
+}
+
+#[test]
+fn test_digest_filter_save_load() {
+    let path = std::env::temp_dir().join(format!("digest-filter-{}.json", std::process::id()));
+    let mut filter = DigestFilter::new(100, 0.01);
+    filter.insert("digest-a");
+    filter.save(&path).expect("Filter should be written");
+
+    let loaded = DigestFilter::load(&path)
+        .expect("Filter should be read")
+        .expect("Filter file should exist");
+    assert!(loaded.contains("digest-a"), "Loaded filter keeps digests");
+    std::fs::remove_file(&path).expect("Filter file should be removed");
+
+    assert!(
+        DigestFilter::load(&path)
+            .expect("Missing file is not an error")
+            .is_none()
+    );
+}
+
+#[test]
+fn test_digest_filter_load_rejects_corrupt_generations() {
+    let path =
+        std::env::temp_dir().join(format!("digest-filter-corrupt-{}.json", std::process::id()));
+    let mut filter = DigestFilter::new(2, 0.01);
+    for i in 0..3 {
+        filter.insert(&format!("digest-{}", i));
+    }
+    filter.save(&path).expect("Filter should be written");
+    let saved: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&path).expect("Filter file should be read"))
+            .expect("Filter file is JSON");
+
+    let mut short_previous = saved.clone();
+    short_previous["previous"]["bits"] = serde_json::json!("");
+    let mut no_bits = saved.clone();
+    no_bits["bits"] = serde_json::json!(0);
+    no_bits["current"]["bits"] = serde_json::json!("");
+    no_bits["previous"]["bits"] = serde_json::json!("");
+    for corrupt in [short_previous, no_bits] {
+        std::fs::write(&path, serde_json::to_vec(&corrupt).expect("JSON encodes"))
+            .expect("Filter file should be written");
+        assert!(
+            DigestFilter::load(&path).is_err(),
+            "Mis-sized generations are rejected instead of panicking on lookup"
+        );
+    }
+    std::fs::remove_file(&path).expect("Filter file should be removed");
+}
@@ -0,0 +1,45 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiCallFrequencySource, SuiTransactionSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_call_frequency_source_initialization() {
+    let mut source = SuiCallFrequencySource::new(
+        SuiTransactionSource::new_with_mainnet(500, 10),
+        Duration::from_secs(1),
+    );
+
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized());
+}
+
+#[tokio::test]
+async fn test_call_frequency_source_window() {
+    let mut source = SuiCallFrequencySource::new(
+        SuiTransactionSource::new_with_mainnet(200, 20),
+        Duration::from_secs(1),
+    );
+    source.init().await.expect("Initialization failed");
+
+    // Poll until the window closes
+    let mut counts = None;
+    for _ in 0..10 {
+        if let Ok(Some(record)) = source.next().await {
+            counts = Some(record.data);
+            break;
+        }
+    }
+
+    if let Some(counts) = counts {
+        assert!(
+            counts.windows(2).all(|pair| pair[0].count >= pair[1].count),
+            "Counts should be sorted by frequency"
+        );
+        for count in counts {
+            assert!(count.count > 0, "Counts should be positive");
+            assert!(count.window_start <= count.window_end);
+            assert!(!count.function.is_empty(), "Function should not be empty");
+        }
+    }
+}
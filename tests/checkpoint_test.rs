@@ -0,0 +1,52 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiCheckpointSource;
+
+#[tokio::test]
+async fn test_checkpoint_source_initialization() {
+    let mut source = SuiCheckpointSource::new_with_mainnet(500);
+
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized());
+}
+
+#[tokio::test]
+async fn test_checkpoint_source_in_order() {
+    let client = sui_sdk::SuiClientBuilder::default()
+        .build(sui_sdk::SUI_MAINNET_URL)
+        .await
+        .expect("Client initialization failed");
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .expect("Fetching latest checkpoint failed");
+
+    // Checkpoints are emitted one per record, without gaps
+    let start = latest - 10;
+    let mut source = SuiCheckpointSource::new_with_mainnet(100).with_start(start);
+    source.init().await.expect("Initialization failed");
+
+    let mut expected = start;
+    let mut previous_digest = None;
+    while expected <= start + 5 {
+        let Some(record) = source.next().await.expect("Fetching checkpoints failed") else {
+            continue;
+        };
+        let checkpoint = record.data;
+        assert_eq!(
+            checkpoint.sequence_number, expected,
+            "Checkpoints should be in order"
+        );
+        if let Some(previous) = &previous_digest {
+            assert_eq!(checkpoint.previous_digest.as_ref(), Some(previous));
+        }
+        assert!(
+            !checkpoint.transactions.is_empty(),
+            "Checkpoints hold transactions"
+        );
+        previous_digest = Some(checkpoint.digest);
+        expected += 1;
+    }
+    assert_eq!(source.cursor(), Some(start + 5));
+}
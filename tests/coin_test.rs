@@ -0,0 +1,57 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiCoinSource;
+
+const TEST_ADDRESS: &str = "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c";
+const SUI: &str = "0x2::sui::SUI";
+
+#[tokio::test]
+async fn test_sui_coin_source_initialization() {
+    let mut source =
+        SuiCoinSource::new_with_mainnet(500, TEST_ADDRESS.to_string(), SUI.to_string());
+    assert!(source.init().await.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized(), "Source should be initialized");
+}
+
+#[tokio::test]
+async fn test_sui_coin_source_data_fetching() {
+    // Small pages exercise pagination
+    let mut source =
+        SuiCoinSource::new_with_mainnet(100, TEST_ADDRESS.to_string(), SUI.to_string())
+            .with_page_size(2)
+            .with_max_coins(10);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching coins should succeed");
+    if let Ok(Some(coins)) = result {
+        assert!(coins.data.len() <= 10, "Should respect the scan budget");
+        for coin in &coins.data {
+            assert_eq!(coin.owner, TEST_ADDRESS);
+            assert_eq!(coin.symbol.as_deref(), Some("SUI"));
+            assert_eq!(coin.decimals, Some(9));
+            assert!(coin.decimal_balance.is_some(), "Balance should be scaled");
+        }
+
+        // Unchanged coins are not emitted again
+        if let Ok(Some(changed)) = source.next().await {
+            for coin in &changed.data {
+                let previous = coins
+                    .data
+                    .iter()
+                    .find(|previous| previous.coin_object_id == coin.coin_object_id);
+                assert!(previous.is_none_or(|previous| previous.version != coin.version));
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_coin_source_invalid_address() {
+    let mut source =
+        SuiCoinSource::new_with_mainnet(100, "not-an-address".to_string(), SUI.to_string());
+    source.init().await.expect("Initialization failed");
+    assert!(
+        source.next().await.is_err(),
+        "Should fail with invalid address"
+    );
+}
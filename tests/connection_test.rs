@@ -0,0 +1,118 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiCoinSource, SuiConnection, SuiEventSource, SuiTransactionSource};
+use std::time::Duration;
+use sui_sdk::SUI_MAINNET_URL;
+use sui_sdk::rpc_types::SuiObjectDataOptions;
+use sui_sdk::types::base_types::ObjectID;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_sui_connection_shared_by_sources() {
+    // Connect once and share the client with two sources
+    let connection = SuiConnection::connect(SUI_MAINNET_URL)
+        .await
+        .expect("Connection failed");
+    assert_eq!(connection.rpc_url(), SUI_MAINNET_URL);
+
+    let mut transactions =
+        SuiTransactionSource::new_with_mainnet(500, 5).with_connection(connection.clone());
+    let mut events = SuiEventSource::new_with_mainnet(500, 5).with_connection(connection);
+
+    transactions.init().await.expect("Initialization failed");
+    events.init().await.expect("Initialization failed");
+
+    assert!(
+        transactions.next().await.is_ok(),
+        "Fetching transactions should succeed"
+    );
+    assert!(
+        events.next().await.is_ok(),
+        "Fetching events should succeed"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_connection_keep_warm() {
+    let connection = SuiConnection::connect(SUI_MAINNET_URL)
+        .await
+        .expect("Connection failed");
+
+    // Let the pinger run through several idle intervals
+    connection.start_keep_warm(Duration::from_millis(100));
+    sleep(Duration::from_millis(500)).await;
+    connection.stop_keep_warm();
+
+    // The connection stays usable after pinging
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 5).with_connection(connection);
+    source.init().await.expect("Initialization failed");
+    assert!(
+        source.next().await.is_ok(),
+        "Fetching after keep-warm should succeed"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_connection_error_handling() {
+    let result = SuiConnection::connect("https://invalid.endpoint.example.com").await;
+    assert!(
+        result.is_err(),
+        "Connecting to an invalid endpoint should fail"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_connection_cache() {
+    let connection = SuiConnection::connect(SUI_MAINNET_URL)
+        .await
+        .expect("Connection failed");
+    connection.enable_cache(Duration::from_secs(60));
+
+    let metadata = connection
+        .get_coin_metadata("0x2::sui::SUI")
+        .await
+        .expect("Fetching coin metadata failed")
+        .expect("SUI should have coin metadata");
+    assert_eq!(metadata.symbol, "SUI");
+
+    // The clock changes every checkpoint, a cached read returns the same version
+    let clock = ObjectID::from_hex_literal("0x6").expect("Invalid object ID");
+    let first = connection
+        .get_object(clock, SuiObjectDataOptions::new())
+        .await
+        .expect("Fetching the clock failed");
+    sleep(Duration::from_millis(1000)).await;
+    let second = connection
+        .get_object(clock, SuiObjectDataOptions::new())
+        .await
+        .expect("Fetching the clock failed");
+    assert_eq!(
+        first.data.map(|data| data.version),
+        second.data.map(|data| data.version),
+        "Cached reads should not refetch"
+    );
+
+    connection.disable_cache();
+}
+
+#[tokio::test]
+async fn test_sui_connection_cache_shared_by_coin_source() {
+    let connection = SuiConnection::connect(SUI_MAINNET_URL)
+        .await
+        .expect("Connection failed");
+    connection.enable_cache(Duration::from_secs(60));
+
+    // Coin metadata read at init goes through the connection's cache
+    let mut source = SuiCoinSource::new_with_mainnet(
+        100,
+        "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c".to_string(),
+        "0x2::sui::SUI".to_string(),
+    )
+    .with_connection(connection.clone());
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(coins)) = source.next().await {
+        for coin in &coins.data {
+            assert_eq!(coin.symbol.as_deref(), Some("SUI"));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{ControlEvent, StreamItem, SuiTransactionSource};
+
+#[tokio::test]
+async fn test_controlled_source_emits_stream_started() {
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).controlled();
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Polling should succeed")
+        .expect("First poll should carry the start event");
+    assert!(
+        matches!(
+            record.data.first(),
+            Some(StreamItem::Control(ControlEvent::StreamStarted))
+        ),
+        "First item should be StreamStarted"
+    );
+}
+
+#[tokio::test]
+async fn test_controlled_source_interleaves_data() {
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).controlled();
+    source.init().await.expect("Initialization failed");
+
+    let mut data = 0;
+    let mut started = 0;
+    for _ in 0..3 {
+        if let Ok(Some(record)) = source.next().await {
+            for item in record.data {
+                match item {
+                    StreamItem::Data(transaction) => {
+                        assert!(!transaction.transaction_digest.is_empty());
+                        data += 1;
+                    }
+                    StreamItem::Control(ControlEvent::StreamStarted) => started += 1,
+                    StreamItem::Control(_) => {}
+                }
+            }
+        }
+    }
+
+    assert_eq!(started, 1, "StreamStarted should be emitted once");
+    assert!(data > 0, "Data records should be interleaved");
+}
+
+#[tokio::test]
+async fn test_controlled_source_checkpoint_markers() {
+    let client = sui_sdk::SuiClientBuilder::default()
+        .build(sui_sdk::SUI_MAINNET_URL)
+        .await
+        .expect("Client initialization failed");
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .expect("Fetching latest checkpoint failed");
+    let (start, end) = (latest - 2, latest - 1);
+
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 50)
+        .with_checkpoint_range(start, end)
+        .controlled();
+    source.init().await.expect("Initialization failed");
+
+    let mut completed = Vec::new();
+    for _ in 0..20 {
+        if completed.len() == 2 {
+            break;
+        }
+        if let Ok(Some(record)) = source.next().await {
+            for item in record.data {
+                match item {
+                    StreamItem::Data(transaction) => {
+                        let checkpoint = transaction.checkpoint.expect("Checkpoint should be set");
+                        assert!(
+                            !completed.contains(&checkpoint),
+                            "Data should precede the marker of its checkpoint"
+                        );
+                    }
+                    StreamItem::Control(ControlEvent::CheckpointComplete { sequence }) => {
+                        completed.push(sequence)
+                    }
+                    StreamItem::Control(_) => {}
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        completed,
+        vec![start, end],
+        "Every checkpoint should be marked in order"
+    );
+}
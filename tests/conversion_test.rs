@@ -0,0 +1,34 @@
+use fluxus_source_sui::{ConversionError, DeadLetter, dead_letter_channel};
+
+#[test]
+fn test_conversion_error_display() {
+    let missing = ConversionError::MissingField("timestamp_ms".to_string());
+    assert_eq!(missing.to_string(), "Missing field: timestamp_ms");
+
+    let invalid = ConversionError::InvalidField {
+        field: "sender".to_string(),
+        reason: "not an address".to_string(),
+    };
+    assert_eq!(invalid.to_string(), "Invalid field sender: not an address");
+}
+
+#[tokio::test]
+async fn test_dead_letter_channel_delivers_letters() {
+    let (sender, mut receiver) = dead_letter_channel();
+
+    sender
+        .send(DeadLetter {
+            source: "SuiEventSource".to_string(),
+            item_id: "digest:0".to_string(),
+            error: ConversionError::MissingField("timestamp_ms".to_string()),
+            raw: String::new(),
+        })
+        .expect("Receiver should be open");
+
+    let letter = receiver.recv().await.expect("Should receive dead letter");
+    assert_eq!(letter.source, "SuiEventSource");
+    assert_eq!(
+        letter.error,
+        ConversionError::MissingField("timestamp_ms".to_string())
+    );
+}
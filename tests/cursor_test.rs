@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::StreamResult;
+use fluxus_source_sui::{
+    AsyncCursorStore, CursorStore, FileCursorStore, SuiEventSource, SuiObjectSource,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+const TEST_ADDRESS: &str = "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c";
+
+fn store_dir(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("cursor-store-{}-{}", name, std::process::id()))
+}
+
+#[test]
+fn test_file_cursor_store_round_trip() {
+    let dir = store_dir("round-trip");
+    let store = FileCursorStore::new(&dir);
+
+    // Nothing is saved before the first write
+    assert_eq!(store.load("events").expect("Load should succeed"), None);
+
+    store.save("events", "first").expect("Save should succeed");
+    store.save("events", "second").expect("Save should succeed");
+    assert_eq!(
+        store.load("events").expect("Load should succeed"),
+        Some("second".to_string()),
+        "The last save should win"
+    );
+
+    // Names with path separators stay inside the directory
+    store
+        .save("../mainnet/events", "other")
+        .expect("Save should succeed");
+    assert_eq!(
+        store
+            .load("../mainnet/events")
+            .expect("Load should succeed"),
+        Some("other".to_string())
+    );
+    assert_eq!(
+        store.load("events").expect("Load should succeed"),
+        Some("second".to_string()),
+        "Sources should not share a cursor"
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_file_cursor_store_versions_files() {
+    let dir = store_dir("versions");
+    let store = FileCursorStore::new(&dir);
+    std::fs::create_dir_all(&dir).expect("Directory should be created");
+
+    // A bare cursor of an unversioned release is read and rewritten with a header
+    let path = dir.join("events.cursor");
+    std::fs::write(&path, "digest:0").expect("Cursor should be written");
+    assert_eq!(
+        store.load("events").expect("Load should succeed"),
+        Some("digest:0".to_string())
+    );
+    let migrated = std::fs::read_to_string(&path).expect("Cursor should be read");
+    assert!(
+        migrated.starts_with("fluxus-sui-cursor 1\n"),
+        "Unversioned files are migrated, got {:?}",
+        migrated
+    );
+    assert_eq!(
+        store.load("events").expect("Load should succeed"),
+        Some("digest:0".to_string()),
+        "Migrated files keep the cursor"
+    );
+
+    // Newer formats are rejected rather than misread
+    std::fs::write(&path, "fluxus-sui-cursor 99\ndigest:0").expect("Cursor should be written");
+    assert!(store.load("events").is_err(), "Newer versions should fail");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_sui_event_source_resumes_from_store() {
+    let dir = store_dir("events");
+    let store: Arc<dyn CursorStore> = Arc::new(FileCursorStore::new(&dir));
+
+    // The first run saves the newest emitted event
+    let mut source =
+        SuiEventSource::new_with_mainnet(500, 5).with_cursor_store(store.clone(), "events");
+    source.init().await.expect("Initialization failed");
+    if let Ok(Some(_)) = source.next().await {
+        let saved = store.load("events").expect("Load should succeed");
+        assert!(
+            saved.is_some_and(|cursor| cursor.contains(':')),
+            "Should save the event ID"
+        );
+
+        // A restarted source reads on from the saved position
+        let mut source =
+            SuiEventSource::new_with_mainnet(500, 5).with_cursor_store(store.clone(), "events");
+        source.init().await.expect("Initialization failed");
+        assert!(source.next().await.is_ok(), "Should resume polling");
+    }
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_sui_event_source_rejects_invalid_cursor() {
+    let dir = store_dir("invalid");
+    let store = FileCursorStore::new(&dir);
+    store
+        .save("events", "not-a-cursor")
+        .expect("Save should succeed");
+
+    let mut source =
+        SuiEventSource::new_with_mainnet(500, 5).with_cursor_store(Arc::new(store), "events");
+    assert!(
+        source.init().await.is_err(),
+        "Invalid saved cursor should fail init"
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Asynchronous store keeping cursors in memory, standing in for a remote backend
+#[derive(Default)]
+struct MemoryStore {
+    cursors: Mutex<HashMap<String, String>>,
+}
+
+#[async_trait]
+impl AsyncCursorStore for MemoryStore {
+    async fn load(&self, source: &str) -> StreamResult<Option<String>> {
+        Ok(self.cursors.lock().unwrap().get(source).cloned())
+    }
+
+    async fn save(&self, source: &str, cursor: &str) -> StreamResult<()> {
+        self.cursors
+            .lock()
+            .unwrap()
+            .insert(source.to_string(), cursor.to_string());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_sui_object_source_async_store() {
+    let store = Arc::new(MemoryStore::default());
+    let name = format!("objects/{}", TEST_ADDRESS);
+
+    // Polls emitting objects save the version map under the address
+    let mut source = SuiObjectSource::new_with_mainnet(500, TEST_ADDRESS.to_string(), 10)
+        .with_async_cursor_store(store.clone(), name.clone());
+    source.init().await.expect("Initialization failed");
+    if let Ok(Some(objects)) = source.next().await {
+        let saved = store.load(&name).await.expect("Load should succeed");
+        let versions: HashMap<String, u64> =
+            serde_json::from_str(&saved.expect("Versions should be saved"))
+                .expect("Versions should decode");
+        for object in &objects.data {
+            assert_eq!(versions.get(&object.id), Some(&object.version));
+        }
+
+        // A restarted source skips the objects it already emitted
+        let mut source = SuiObjectSource::new_with_mainnet(500, TEST_ADDRESS.to_string(), 10)
+            .with_async_cursor_store(store.clone(), name);
+        source.init().await.expect("Initialization failed");
+        if let Ok(Some(resumed)) = source.next().await {
+            assert!(
+                resumed.data.iter().all(|object| versions
+                    .get(&object.id)
+                    .is_none_or(|version| object.version > *version)),
+                "Should only emit changed objects"
+            );
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use fluxus_source_sui::{FieldChange, content_diff};
+use serde_json::json;
+
+#[test]
+fn test_content_diff_changed_field() {
+    let previous = json!({ "fields": { "balance": "100", "owner": "0xa" } });
+    let current = json!({ "fields": { "balance": "150", "owner": "0xa" } });
+
+    assert_eq!(
+        content_diff(&previous, &current),
+        vec![FieldChange {
+            path: "/fields/balance".to_string(),
+            previous: Some(json!("100")),
+            current: Some(json!("150")),
+        }]
+    );
+}
+
+#[test]
+fn test_content_diff_added_and_removed_fields() {
+    let previous = json!({ "a": 1, "items": [1, 2] });
+    let current = json!({ "b": 2, "items": [1] });
+
+    let changes = content_diff(&previous, &current);
+    let paths: Vec<_> = changes.iter().map(|change| change.path.as_str()).collect();
+    assert_eq!(paths, vec!["/a", "/b", "/items/1"]);
+    assert_eq!(
+        changes[0].current, None,
+        "Removed field has no current value"
+    );
+    assert_eq!(
+        changes[1].previous, None,
+        "Added field has no previous value"
+    );
+    assert_eq!(changes[2].previous, Some(json!(2)));
+}
+
+#[test]
+fn test_content_diff_escapes_keys() {
+    let previous = json!({ "a/b": 1 });
+    let current = json!({ "a/b": 2 });
+
+    assert_eq!(content_diff(&previous, &current)[0].path, "/a~1b");
+}
+
+#[test]
+fn test_content_diff_identical_content() {
+    let content = json!({ "fields": { "balance": "100" } });
+    assert!(content_diff(&content, &content).is_empty());
+}
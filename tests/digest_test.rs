@@ -0,0 +1,32 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiDigestSource;
+use std::collections::HashSet;
+
+#[tokio::test]
+async fn test_digest_source_initialization() {
+    let mut source = SuiDigestSource::new_with_mainnet(500, 10);
+
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.cursor().is_some(), "Reads should start at the head");
+}
+
+#[tokio::test]
+async fn test_digest_source_tailing() {
+    let mut source = SuiDigestSource::new_with_mainnet(200, 50);
+    source.init().await.expect("Initialization failed");
+
+    // Consecutive polls move forward without repeating digests
+    let mut seen = HashSet::new();
+    let mut last_checkpoint = 0;
+    for _ in 0..5 {
+        if let Ok(Some(digests)) = source.next().await {
+            for record in digests.data {
+                assert!(seen.insert(record.digest.clone()), "Digest repeated");
+                let checkpoint = record.checkpoint.expect("Checkpoint should be set");
+                assert!(checkpoint >= last_checkpoint, "Reads should be ascending");
+                last_checkpoint = checkpoint;
+            }
+        }
+    }
+}
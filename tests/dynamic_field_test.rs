@@ -0,0 +1,45 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{DynamicFieldChangeKind, SuiDynamicFieldSource};
+
+/// Sui system state object, holding the versioned inner state in a dynamic field
+const SYSTEM_STATE: &str = "0x5";
+
+#[tokio::test]
+async fn test_sui_dynamic_field_source_initialization() {
+    let mut source = SuiDynamicFieldSource::new_with_mainnet(500, SYSTEM_STATE.to_string());
+    assert!(source.init().await.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized(), "Source should be initialized");
+}
+
+#[tokio::test]
+async fn test_sui_dynamic_field_source_invalid_parent() {
+    let mut source = SuiDynamicFieldSource::new_with_mainnet(500, "not-an-id".to_string());
+    assert!(
+        source.init().await.is_err(),
+        "Initialization should reject an invalid parent ID"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_dynamic_field_source_data_fetching() {
+    let mut source = SuiDynamicFieldSource::new_with_mainnet(100, SYSTEM_STATE.to_string());
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching dynamic fields should succeed");
+    if let Ok(Some(fields)) = result {
+        for field in &fields.data {
+            assert_eq!(field.kind, DynamicFieldChangeKind::Added);
+            assert_eq!(field.name_type, "u64");
+            assert!(!field.object_field);
+            assert!(field.value.is_some(), "Values should be fetched");
+        }
+
+        // Unchanged fields are not emitted again
+        if let Ok(Some(changed)) = source.next().await {
+            for field in &changed.data {
+                assert_ne!(field.kind, DynamicFieldChangeKind::Added);
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiEpochSource;
+use tokio::time::{Duration, timeout};
+
+#[tokio::test]
+async fn test_sui_epoch_source_initialization() {
+    let mut source = SuiEpochSource::new_with_mainnet(500);
+    assert_eq!(source.current_epoch(), None);
+
+    source.init().await.expect("Initialization failed");
+    assert!(source.is_initialized(), "Source should be initialized");
+    assert!(
+        source.current_epoch().is_some_and(|epoch| epoch > 0),
+        "Should record the current epoch"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_epoch_source_waits_for_boundary() {
+    let mut source = SuiEpochSource::new_with_mainnet(100);
+    source.init().await.expect("Initialization failed");
+    let epoch = source.current_epoch();
+
+    // Epochs last a day, so polls within this test see the same epoch
+    let result = timeout(Duration::from_secs(30), source.next())
+        .await
+        .expect("Poll should not hang");
+    match result {
+        Ok(None) => assert_eq!(source.current_epoch(), epoch),
+        Ok(Some(record)) => {
+            let record = &record.data[0];
+            assert!(
+                Some(record.epoch) > epoch,
+                "Records should report a new epoch"
+            );
+            assert!(record.reference_gas_price > 0);
+            if record.last_checkpoint.is_some() {
+                assert!(
+                    !record.next_epoch_committee.is_empty(),
+                    "End-of-epoch data should carry the next committee"
+                );
+                assert_eq!(
+                    record.next_epoch_protocol_version,
+                    Some(record.protocol_version)
+                );
+            }
+        }
+        Err(e) => panic!("Polling should succeed: {}", e),
+    }
+}
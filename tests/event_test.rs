@@ -1,7 +1,20 @@
+use async_trait::async_trait;
 use fluxus::sources::Source;
-use fluxus_source_sui::SuiEventSource;
+use fluxus::utils::models::StreamResult;
+use fluxus_source_sui::{
+    ChainEvent, CursorStore, Enricher, ErrorPolicy, EventDelivery, FileCursorStore, SuiEventSource,
+    SuiSourceError,
+};
+use serde_json::{Value, json};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use sui_sdk::SUI_TESTNET_URL;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::event::EventID;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
 use tokio::time::sleep;
 
 #[tokio::test]
@@ -51,7 +64,7 @@ async fn test_sui_event_source_data_fetching() {
                 "Event ID should not be empty"
             );
             assert!(
-                !event.event_type.is_empty(),
+                !event.event_type.name.is_empty(),
                 "Event type should not be empty"
             );
         }
@@ -107,3 +120,577 @@ async fn test_sui_event_source_error_handling() {
     let init_result = source.init().await;
     assert!(init_result.is_err(), "Should fail with invalid endpoint");
 }
+
+#[tokio::test]
+async fn test_sui_event_source_pruned_cursor() {
+    // Cursor referencing a transaction the node does not retain
+    let cursor = EventID {
+        tx_digest: TransactionDigest::random(),
+        event_seq: 0,
+    };
+
+    // Without restart, polling fails with a typed error
+    let mut source = SuiEventSource::new_with_mainnet(500, 5).with_cursor(cursor);
+    source.init().await.expect("Initialization failed");
+    assert!(source.next().await.is_err(), "Pruned cursor should fail");
+    assert!(
+        matches!(
+            source.last_error(),
+            Some(SuiSourceError::HistoryPruned { .. })
+        ),
+        "Should report pruned history"
+    );
+
+    // With restart, the cursor is dropped and polling continues
+    let mut source = SuiEventSource::new_with_mainnet(500, 5)
+        .with_cursor(cursor)
+        .with_restart_on_pruning(true);
+    source.init().await.expect("Initialization failed");
+    assert!(
+        source.next().await.is_ok(),
+        "Should restart from the oldest event"
+    );
+    assert!(
+        source.next().await.is_ok(),
+        "Polling should continue after restart"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_event_source_network_reset() {
+    // A cursor recorded on another chain fails init with a typed error
+    let mut source = SuiEventSource::new_with_mainnet(500, 5).with_expected_chain("00000000");
+    assert!(source.init().await.is_err(), "Changed chain should fail");
+    assert!(
+        matches!(
+            source.last_error(),
+            Some(SuiSourceError::NetworkReset { expected, .. }) if expected == "00000000"
+        ),
+        "Should report the network reset"
+    );
+
+    // With restart, the cursor is dropped and polling starts over
+    let mut source = SuiEventSource::new_with_mainnet(500, 5)
+        .with_expected_chain("00000000")
+        .with_restart_on_reset(true);
+    source.init().await.expect("Initialization failed");
+    assert!(source.next().await.is_ok(), "Should poll after the reset");
+}
+
+#[tokio::test]
+async fn test_sui_event_source_events_api_probe() {
+    // Nodes serving the events API keep reading from it
+    let mut source = SuiEventSource::new_with_mainnet(500, 5).with_transaction_fallback(true);
+    source.init().await.expect("Initialization failed");
+    assert!(
+        !matches!(
+            source.last_error(),
+            Some(SuiSourceError::MethodUnavailable { .. })
+        ),
+        "Mainnet should serve the events API"
+    );
+    assert!(source.next().await.is_ok(), "Should poll the events API");
+}
+
+#[tokio::test]
+async fn test_sui_event_source_subscription_fallback() {
+    // Nodes without WebSocket subscriptions keep being polled
+    let mut source = SuiEventSource::new_with_mainnet(500, 5)
+        .with_subscription("wss://fullnode.mainnet.sui.io:443");
+    source.init().await.expect("Initialization failed");
+    assert!(source.next().await.is_ok(), "Should fall back to polling");
+    assert!(source.next().await.is_ok(), "Polling should continue");
+}
+
+#[tokio::test]
+async fn test_sui_event_source_start_delay() {
+    // Phase offset plus jitter delays only the first poll
+    let mut source = SuiEventSource::new_with_mainnet(100, 5)
+        .with_phase_offset(Duration::from_millis(500))
+        .with_start_jitter(Duration::from_millis(200));
+    source.init().await.expect("Initialization failed");
+
+    let start = std::time::Instant::now();
+    let _ = source.next().await;
+    assert!(
+        start.elapsed() >= Duration::from_millis(600),
+        "First poll should wait for the phase offset"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_event_source_clamps_page_size() {
+    // Page sizes above the node limit are clamped at init
+    let mut source = SuiEventSource::new_with_mainnet(500, 500);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Oversized pages should not fail");
+    if let Ok(Some(events)) = result {
+        assert!(events.data.len() <= 50, "Pages should be clamped");
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_node_page_limit() {
+    // A lower node limit is applied instead of the fullnode default
+    let mut source = SuiEventSource::new_with_mainnet(500, 40).with_node_page_limit(10);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(events)) = source.next().await {
+        assert!(
+            events.data.len() <= 10,
+            "Pages should respect the node limit"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_rejects_empty_pages() {
+    // A page size of zero is a configuration error rather than silently raised
+    let mut source = SuiEventSource::new_with_mainnet(500, 0);
+    assert!(
+        source.init().await.is_err(),
+        "Zero max_events should fail at init"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_event_source_drops_duplicates() {
+    // Consecutive polls must not emit the same event twice
+    let mut source = SuiEventSource::new_with_mainnet(500, 20);
+    source.init().await.expect("Initialization failed");
+
+    let Ok(Some(first)) = source.next().await else {
+        return;
+    };
+    let first_ids: Vec<_> = first.data.iter().map(|event| event.id).collect();
+
+    if let Ok(Some(second)) = source.next().await {
+        for event in &second.data {
+            assert!(
+                !first_ids.contains(&event.id),
+                "Event emitted twice: {:?}",
+                event.id
+            );
+        }
+    }
+}
+
+/// Event of a mock node, event `n` of transaction `[n; 32]`
+fn mock_event(n: u8) -> Value {
+    json!({
+        "id": {
+            "txDigest": TransactionDigest::new([n; 32]).to_string(),
+            "eventSeq": "0",
+        },
+        "packageId": format!("0x{:064x}", 2),
+        "transactionModule": "mock",
+        "sender": format!("0x{:064x}", n),
+        "type": "0x2::mock::Ping",
+        "parsedJson": { "n": n },
+        "bcsEncoding": "base64",
+        "bcs": "",
+        "timestampMs": (1_700_000_000_000u64 + u64::from(n)).to_string(),
+    })
+}
+
+/// Serves a JSON-RPC node answering event polls with `pages` in order, returning its URL
+///
+/// The last page is repeated once every page was served, and the one-event probe
+/// at init is answered with an empty page.
+async fn mock_node(pages: Vec<Vec<Value>>) -> String {
+    serve_mock(pages, None).await
+}
+
+/// Serves a JSON-RPC node failing every event poll with `message`, returning its URL
+///
+/// The one-event probes at init and of the oldest event are answered with an
+/// empty page.
+async fn mock_failing_node(message: &str) -> String {
+    serve_mock(vec![Vec::new()], Some(message.into())).await
+}
+
+/// Accepts connections of a mock node, failing event polls with `error` if set
+async fn serve_mock(pages: Vec<Vec<Value>>, error: Option<Arc<str>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Binding the mock node failed");
+    let url = format!(
+        "http://{}",
+        listener.local_addr().expect("Mock node has no address")
+    );
+    let pages = Arc::new(pages);
+    let polls = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(serve_mock_node(
+                stream,
+                pages.clone(),
+                polls.clone(),
+                error.clone(),
+            ));
+        }
+    });
+    url
+}
+
+/// Answers the HTTP requests of one keep-alive connection
+async fn serve_mock_node(
+    mut stream: TcpStream,
+    pages: Arc<Vec<Vec<Value>>>,
+    polls: Arc<AtomicUsize>,
+    error: Option<Arc<str>>,
+) {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        // Read the headers, then a body of Content-Length bytes
+        let header_end = loop {
+            if let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break end + 4;
+            }
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+            }
+        };
+        let headers = String::from_utf8_lossy(&buffer[..header_end]).to_lowercase();
+        let length = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .and_then(|length| length.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        while buffer.len() < header_end + length {
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+            }
+        }
+        let request: Value =
+            serde_json::from_slice(&buffer[header_end..header_end + length]).unwrap_or_default();
+        buffer.drain(..header_end + length);
+
+        let result = match request["method"].as_str() {
+            Some("rpc.discover") => json!({
+                "openrpc": "1.2.6",
+                "info": { "title": "Mock Sui JSON-RPC", "version": "1.63.0" },
+                "methods": [
+                    { "name": "sui_getChainIdentifier" },
+                    { "name": "suix_queryEvents" },
+                ],
+            }),
+            Some("sui_getChainIdentifier") => json!("4c78adac"),
+            Some("suix_queryEvents") if request["params"][2] == json!(1) => json!({
+                "data": [],
+                "nextCursor": null,
+                "hasNextPage": false,
+            }),
+            Some("suix_queryEvents") => {
+                let poll = polls.fetch_add(1, Ordering::SeqCst);
+                let page = &pages[poll.min(pages.len() - 1)];
+                json!({
+                    "data": page,
+                    "nextCursor": page.last().map(|event| event["id"].clone()),
+                    "hasNextPage": false,
+                })
+            }
+            _ => Value::Null,
+        };
+        let body = match &error {
+            Some(message)
+                if request["method"] == "suix_queryEvents" && request["params"][2] != json!(1) =>
+            {
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": { "code": -32000, "message": message.as_ref() },
+                })
+            }
+            _ => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+        }
+        .to_string();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        if stream.write_all(response.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_drops_events_of_older_pages() {
+    // Head pages of three events move one event forward per poll, so the third
+    // page overlaps events the second page did not emit
+    let url = mock_node(vec![
+        vec![mock_event(3), mock_event(2), mock_event(1)],
+        vec![mock_event(4), mock_event(3), mock_event(2)],
+        vec![mock_event(5), mock_event(4), mock_event(3)],
+    ])
+    .await;
+    let mut source = SuiEventSource::new(url, 10, 3);
+    source.init().await.expect("Initialization failed");
+
+    let mut emitted = Vec::new();
+    for _ in 0..3 {
+        if let Some(events) = source.next().await.expect("Polling failed") {
+            emitted.extend(events.data.into_iter().map(|event| event.id.tx_digest));
+        }
+    }
+    emitted.sort();
+    let mut expected: Vec<_> = (1..=5).map(|n| TransactionDigest::new([n; 32])).collect();
+    expected.sort();
+    assert_eq!(
+        emitted, expected,
+        "Every event should be emitted exactly once"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_event_source_saves_cursor_after_held_back_batches() {
+    // Every event exceeds the byte budget, so the page is emitted over three calls
+    let url = mock_node(vec![vec![mock_event(3), mock_event(2), mock_event(1)]]).await;
+    let dir = std::env::temp_dir().join(format!("held-back-cursor-{}", std::process::id()));
+    let store = Arc::new(FileCursorStore::new(&dir));
+    let mut source = SuiEventSource::new(url, 10, 3)
+        .with_max_batch_bytes(1)
+        .with_cursor_store(store.clone(), "events");
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..2 {
+        assert!(
+            matches!(source.next().await, Ok(Some(_))),
+            "Batches should be emitted one call at a time"
+        );
+        assert_eq!(
+            store.load("events").expect("Loading the cursor failed"),
+            None,
+            "Cursor should wait for the held back batches"
+        );
+    }
+    assert!(matches!(source.next().await, Ok(Some(_))));
+    assert_eq!(
+        store.load("events").expect("Loading the cursor failed"),
+        Some(format!("{}:0", TransactionDigest::new([3; 32]))),
+        "Cursor should be saved with the last batch"
+    );
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn test_sui_event_source_pause_resume() {
+    // A paused source waits in next until resumed
+    let mut source = SuiEventSource::new_with_mainnet(100, 10);
+    source.init().await.expect("Initialization failed");
+
+    let handle = source.pause_handle();
+    handle.pause();
+    assert!(handle.is_paused(), "Handle should report the pause");
+
+    let paused = tokio::time::timeout(Duration::from_millis(500), source.next()).await;
+    assert!(paused.is_err(), "Paused source should not poll");
+
+    handle.resume();
+    let resumed = tokio::time::timeout(Duration::from_secs(30), source.next()).await;
+    assert!(resumed.is_ok(), "Resumed source should poll");
+    assert!(source.is_initialized(), "Pausing should keep the client");
+}
+
+struct SenderLengths {
+    lookups: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Enricher<ChainEvent> for SenderLengths {
+    fn name(&self) -> &str {
+        "sender_length"
+    }
+
+    fn key(&self, event: &ChainEvent) -> Option<String> {
+        Some(event.sender.clone())
+    }
+
+    async fn lookup(&self, sender: &str) -> StreamResult<Option<serde_json::Value>> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        Ok(Some(sender.len().into()))
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_enricher() {
+    // Enrichers attach values once per distinct key
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let mut source = SuiEventSource::new_with_mainnet(500, 20).with_enricher(SenderLengths {
+        lookups: lookups.clone(),
+    });
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(events)) = source.next().await {
+        let senders: HashSet<_> = events.data.iter().map(|event| &event.sender).collect();
+        for event in &events.data {
+            assert_eq!(
+                event.enrichments.get("sender_length"),
+                Some(&serde_json::json!(event.sender.len())),
+                "Enrichment should be attached"
+            );
+        }
+        assert!(
+            lookups.load(Ordering::SeqCst) <= senders.len(),
+            "Lookups should be cached per sender"
+        );
+    }
+}
+
+struct SlowLabels;
+
+#[async_trait]
+impl Enricher<ChainEvent> for SlowLabels {
+    fn name(&self) -> &str {
+        "slow_label"
+    }
+
+    fn key(&self, event: &ChainEvent) -> Option<String> {
+        Some(event.sender.clone())
+    }
+
+    async fn lookup(&self, _sender: &str) -> StreamResult<Option<serde_json::Value>> {
+        sleep(Duration::from_secs(60)).await;
+        Ok(Some("late".into()))
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_enrichment_deadline() {
+    // Slow lookups are cancelled at the deadline instead of stalling the poll
+    let mut source = SuiEventSource::new_with_mainnet(100, 20)
+        .with_enricher(SlowLabels)
+        .with_enrichment_concurrency(2)
+        .with_enrichment_deadline(Duration::from_millis(500));
+    source.init().await.expect("Initialization failed");
+
+    let result = tokio::time::timeout(Duration::from_secs(30), source.next())
+        .await
+        .expect("Poll should not wait for slow lookups");
+    if let Ok(Some(events)) = result {
+        for event in &events.data {
+            assert!(
+                !event.enrichments.contains_key("slow_label"),
+                "Cancelled lookups should attach nothing"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_max_batch_bytes() {
+    // Batches over the byte budget are split across calls
+    let mut source = SuiEventSource::new_with_mainnet(500, 20).with_max_batch_bytes(2_000);
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..3 {
+        if let Ok(Some(events)) = source.next().await {
+            let bytes = serde_json::to_vec(&events.data)
+                .expect("Events should serialize")
+                .len();
+            assert!(
+                events.data.len() == 1 || bytes <= 2_000 + events.data.len() + 1,
+                "Batch of {} bytes should be split",
+                bytes
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_from_transactions() {
+    // Events reconstructed from transactions carry the same fields
+    let mut source =
+        SuiEventSource::new_with_mainnet(500, 10).with_delivery(EventDelivery::FromTransactions);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(
+        result.is_ok(),
+        "Fetching events from transactions should succeed"
+    );
+    if let Ok(Some(events)) = result {
+        for event in events.data {
+            assert!(event.timestamp > 0, "Timestamp should be inherited");
+            assert!(!event.event_type.name.is_empty());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_event_source_pruning_matches_node_message() {
+    let cursor = EventID {
+        tx_digest: TransactionDigest::new([9; 32]),
+        event_seq: 0,
+    };
+
+    // The "fetch events" prefix of the error is not taken for pruned history
+    let url = mock_failing_node("Requested resource not found").await;
+    let mut source = SuiEventSource::new(url, 10, 3).with_cursor(cursor);
+    source.init().await.expect("Initialization failed");
+    assert!(source.next().await.is_err(), "Failed poll should fail");
+    assert!(
+        !matches!(
+            source.last_error(),
+            Some(SuiSourceError::HistoryPruned { .. })
+        ),
+        "A plain not-found answer is not pruned history"
+    );
+
+    let url =
+        mock_failing_node("Could not find the referenced transaction events [TransactionDigest]")
+            .await;
+    let mut source = SuiEventSource::new(url, 10, 3).with_cursor(cursor);
+    source.init().await.expect("Initialization failed");
+    assert!(source.next().await.is_err(), "Pruned cursor should fail");
+    assert!(
+        matches!(
+            source.last_error(),
+            Some(SuiSourceError::HistoryPruned { .. })
+        ),
+        "The node's pruning phrase is pruned history"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_event_source_rereads_page_failing_to_convert() {
+    // An event without a timestamp fails conversion on every read of its page
+    let mut broken = mock_event(2);
+    broken["timestampMs"] = Value::Null;
+    let url = mock_node(vec![vec![broken, mock_event(1)]]).await;
+    let mut source =
+        SuiEventSource::new(url, 10, 3).with_decode_error_policy(ErrorPolicy::FailFast);
+    source.init().await.expect("Initialization failed");
+
+    assert!(source.next().await.is_err(), "Conversion should fail");
+    assert!(
+        source.next().await.is_err(),
+        "The failed page should be read again rather than skipped as processed"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_event_source_byte_stats_opt_in() {
+    // Fetched pages are only serialized to count their size when enabled
+    for byte_stats in [false, true] {
+        let url = mock_node(vec![vec![mock_event(2), mock_event(1)]]).await;
+        let mut source = SuiEventSource::new(url, 10, 3).with_byte_stats(byte_stats);
+        source.init().await.expect("Initialization failed");
+        source.next().await.expect("Fetching events should succeed");
+
+        let bytes_fetched = source.stats().bytes_fetched;
+        assert_eq!(
+            bytes_fetched > 0,
+            byte_stats,
+            "Bytes counted: {}",
+            bytes_fetched
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiFailureRateSource, SuiTransactionSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_failure_rate_source_initialization() {
+    let mut source = SuiFailureRateSource::new(
+        SuiTransactionSource::new_with_mainnet(500, 10),
+        Duration::from_secs(1),
+    );
+
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized());
+}
+
+#[tokio::test]
+async fn test_failure_rate_source_window() {
+    let mut source = SuiFailureRateSource::new(
+        SuiTransactionSource::new_with_mainnet(200, 20),
+        Duration::from_secs(1),
+    )
+    .with_top_errors(2);
+    source.init().await.expect("Initialization failed");
+
+    // Poll until the window closes
+    let mut rates = None;
+    for _ in 0..10 {
+        if let Ok(Some(record)) = source.next().await {
+            rates = Some(record.data);
+            break;
+        }
+    }
+
+    if let Some(rates) = rates {
+        for rate in rates {
+            assert!(
+                rate.successes + rate.failures > 0,
+                "Every package should have at least one outcome"
+            );
+            assert!(rate.top_errors.len() <= 2, "Top errors should be truncated");
+            let error_count: u64 = rate.top_errors.iter().map(|(_, count)| count).sum();
+            assert!(error_count <= rate.failures);
+        }
+    }
+}
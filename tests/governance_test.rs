@@ -0,0 +1,62 @@
+use fluxus_source_sui::{ChainEvent, GovernanceRecord, MoveModulePath};
+use serde_json::json;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::event::EventID;
+
+fn chain_event(event_type: &str, parsed_json: serde_json::Value) -> ChainEvent {
+    ChainEvent {
+        id: EventID {
+            tx_digest: TransactionDigest::new([0; 32]),
+            event_seq: 0,
+        },
+        module: MoveModulePath::new("0xda0", "governance").expect("Module path should parse"),
+        event_type: event_type.parse().expect("Type tag should parse"),
+        sender: "0xsender".to_string(),
+        data: format!("{:?}", parsed_json),
+        parsed_json,
+        timestamp: 42,
+        chain: None,
+        enrichments: Default::default(),
+    }
+}
+
+#[test]
+fn test_governance_vote_normalized() {
+    let event = chain_event(
+        "0xda0::governance::VoteCast",
+        json!({ "proposal_id": "7", "voter": "0xvoter", "support": true, "weight": "1000000000000000000000" }),
+    );
+
+    let Some(GovernanceRecord::Vote(vote)) = GovernanceRecord::from_event(&event) else {
+        panic!("Event should be a vote");
+    };
+    assert_eq!(vote.proposal_id.as_deref(), Some("7"));
+    assert_eq!(vote.voter, "0xvoter");
+    assert_eq!(vote.choice.as_deref(), Some("true"));
+    assert_eq!(vote.weight, Some(1_000_000_000_000_000_000_000));
+    assert_eq!(vote.timestamp, 42);
+}
+
+#[test]
+fn test_governance_proposal_normalized() {
+    let event = chain_event(
+        "0xda0::governance::ProposalCreated<0x2::sui::SUI>",
+        json!({ "proposal": { "id": "0xp" }, "title": "Raise fee" }),
+    );
+
+    let Some(GovernanceRecord::Proposal(proposal)) = GovernanceRecord::from_event(&event) else {
+        panic!("Event should be a proposal");
+    };
+    assert_eq!(proposal.proposal_id.as_deref(), Some("0xp"));
+    assert_eq!(
+        proposal.proposer, "0xsender",
+        "Proposer falls back to the sender"
+    );
+    assert_eq!(proposal.fields["title"], "Raise fee");
+}
+
+#[test]
+fn test_governance_other_events_skipped() {
+    let event = chain_event("0xda0::governance::FeeCollected", json!({}));
+    assert_eq!(GovernanceRecord::from_event(&event), None);
+}
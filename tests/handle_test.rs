@@ -0,0 +1,39 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SourceHandle, SuiEventSource};
+
+#[tokio::test]
+async fn test_source_handle_shares_initialization() {
+    let mut handle = SourceHandle::new(SuiEventSource::new_with_mainnet(500, 10));
+    let mut clone = handle.clone();
+
+    handle.init().await.expect("Initialization failed");
+    assert!(
+        clone.lock().await.is_initialized(),
+        "Clones should share the initialized client"
+    );
+    assert!(
+        clone.next().await.is_ok(),
+        "Clones should poll the shared source"
+    );
+}
+
+#[tokio::test]
+async fn test_source_handle_closes_from_last_handle() {
+    let mut handle = SourceHandle::new(SuiEventSource::new_with_mainnet(500, 10));
+    handle.init().await.expect("Initialization failed");
+
+    let mut clone = handle.clone();
+    clone.close().await.expect("Close should succeed");
+    clone.close().await.expect("Repeated close should succeed");
+    assert!(
+        handle.lock().await.is_initialized(),
+        "Other handles keep the source open"
+    );
+
+    handle.close().await.expect("Close should succeed");
+    assert!(!handle.lock().await.is_initialized(), "Last handle closes");
+    assert!(
+        !clone.lock().await.is_initialized(),
+        "Closed handles still share the source"
+    );
+}
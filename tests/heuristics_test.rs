@@ -0,0 +1,176 @@
+use fluxus_source_sui::{HeuristicTag, HeuristicsConfig};
+use serde_json::{Value, json};
+use sui_sdk::rpc_types::SuiTransactionBlockResponse;
+
+// Base58 encoding of an all-zero 32 byte digest
+const DIGEST: &str = "11111111111111111111111111111111";
+const SENDER: u64 = 1;
+
+/// Address or object ID `n`
+fn address(n: u64) -> String {
+    format!("0x{:064x}", n)
+}
+
+/// Reference to object `n` of a fixture
+fn object_ref(n: u64) -> Value {
+    json!({ "objectId": address(1_000 + n), "version": 2, "digest": DIGEST })
+}
+
+/// Object `n` owned by address `owner`
+fn owned(owner: u64, n: u64) -> Value {
+    json!({ "owner": { "AddressOwner": address(owner) }, "reference": object_ref(n) })
+}
+
+/// Builds a transaction response as a fullnode serves it
+///
+/// `created` lists the owners of the created objects and `credits` the
+/// balance changes as (owner, amount) pairs.
+fn response(
+    commands: Value,
+    created: &[u64],
+    credits: &[(u64, i128)],
+    success: bool,
+) -> SuiTransactionBlockResponse {
+    let status = if success {
+        json!({ "status": "success" })
+    } else {
+        json!({ "status": "failure", "error": "InsufficientGas" })
+    };
+    let created: Vec<Value> = created
+        .iter()
+        .enumerate()
+        .map(|(n, owner)| owned(*owner, n as u64 + 1))
+        .collect();
+    let balance_changes: Vec<Value> = credits
+        .iter()
+        .map(|(owner, amount)| {
+            json!({
+                "owner": { "AddressOwner": address(*owner) },
+                "coinType": "0x2::sui::SUI",
+                "amount": amount.to_string(),
+            })
+        })
+        .collect();
+
+    serde_json::from_value(json!({
+        "digest": DIGEST,
+        "transaction": {
+            "data": {
+                "messageVersion": "v1",
+                "transaction": {
+                    "kind": "ProgrammableTransaction",
+                    "inputs": [],
+                    "transactions": commands,
+                },
+                "sender": address(SENDER),
+                "gasData": {
+                    "payment": [object_ref(0)],
+                    "owner": address(SENDER),
+                    "price": "1000",
+                    "budget": "10000000",
+                },
+            },
+            "txSignatures": [],
+        },
+        "effects": {
+            "messageVersion": "v1",
+            "status": status,
+            "executedEpoch": "1",
+            "gasUsed": {
+                "computationCost": "1000",
+                "storageCost": "0",
+                "storageRebate": "0",
+                "nonRefundableStorageFee": "0",
+            },
+            "transactionDigest": DIGEST,
+            "created": created,
+            "mutated": [owned(SENDER, 0)],
+            "gasObject": owned(SENDER, 0),
+            "dependencies": [],
+        },
+        "balanceChanges": balance_changes,
+        "timestampMs": "1000",
+        "checkpoint": "1",
+    }))
+    .expect("Transaction fixture should deserialize")
+}
+
+/// Commands transferring one object
+fn transfer_objects() -> Value {
+    json!([{ "TransferObjects": [[{ "Input": 0 }], { "Input": 1 }] }])
+}
+
+/// Commands splitting the gas coin
+fn split_coins() -> Value {
+    json!([{ "SplitCoins": ["GasCoin", [{ "Input": 0 }]] }])
+}
+
+#[test]
+fn test_heuristics_self_transfer() {
+    // Objects transferred back to the sender
+    let transaction = response(transfer_objects(), &[SENDER], &[], true);
+    let tags = HeuristicsConfig::default().classify(&transaction);
+    assert_eq!(tags, vec![HeuristicTag::SelfTransfer]);
+}
+
+#[test]
+fn test_heuristics_transfer_to_recipient() {
+    // An ordinary transfer of a non-dust amount carries no tag
+    let transaction = response(transfer_objects(), &[2], &[(2, 5_000_000)], true);
+    let tags = HeuristicsConfig::default().classify(&transaction);
+    assert!(tags.is_empty(), "Unexpected tags: {:?}", tags);
+}
+
+#[test]
+fn test_heuristics_dusting() {
+    // Every credit to another address is at or below the dust threshold
+    let transaction = response(
+        split_coins(),
+        &[],
+        &[(2, 1_000), (3, 1), (SENDER, 50_000)],
+        true,
+    );
+    let tags = HeuristicsConfig::default().classify(&transaction);
+    assert_eq!(tags, vec![HeuristicTag::Dusting]);
+
+    // One credit above the threshold is not dusting
+    let transaction = response(split_coins(), &[], &[(2, 1_000), (3, 1_001)], true);
+    let tags = HeuristicsConfig::default().classify(&transaction);
+    assert!(tags.is_empty(), "Unexpected tags: {:?}", tags);
+}
+
+#[test]
+fn test_heuristics_airdrop_spray() {
+    // Objects minted to many distinct recipients
+    let recipients: Vec<u64> = (2..12).collect();
+    let transaction = response(split_coins(), &recipients, &[], true);
+    let tags = HeuristicsConfig::default().classify(&transaction);
+    assert_eq!(tags, vec![HeuristicTag::AirdropSpray]);
+
+    // Fewer recipients than configured is not a spray
+    let config = HeuristicsConfig {
+        spray_min_recipients: 11,
+        ..HeuristicsConfig::default()
+    };
+    assert!(config.classify(&transaction).is_empty());
+}
+
+#[test]
+fn test_heuristics_dusting_spray() {
+    // Dust sprayed across many recipients carries both tags
+    let credits: Vec<(u64, i128)> = (2..12).map(|owner| (owner, 10)).collect();
+    let transaction = response(split_coins(), &[], &credits, true);
+    let tags = HeuristicsConfig::default().classify(&transaction);
+    assert_eq!(
+        tags,
+        vec![HeuristicTag::Dusting, HeuristicTag::AirdropSpray]
+    );
+}
+
+#[test]
+fn test_heuristics_failed_transaction() {
+    // Failed transactions are left to the batch wide failed spam heuristic
+    let transaction = response(transfer_objects(), &[SENDER], &[(2, 1)], false);
+    let tags = HeuristicsConfig::default().classify(&transaction);
+    assert!(tags.is_empty(), "Unexpected tags: {:?}", tags);
+}
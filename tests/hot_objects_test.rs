@@ -0,0 +1,48 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiHotObjectSource, SuiTransactionSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_hot_object_source_initialization() {
+    let mut source = SuiHotObjectSource::new(
+        SuiTransactionSource::new_with_mainnet(500, 10),
+        Duration::from_secs(60),
+        5,
+    );
+
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized());
+}
+
+#[tokio::test]
+async fn test_hot_object_source_top_n() {
+    let mut source = SuiHotObjectSource::new(
+        SuiTransactionSource::new_with_mainnet(200, 20),
+        Duration::from_secs(60),
+        3,
+    );
+    source.init().await.expect("Initialization failed");
+
+    let mut hot = None;
+    for _ in 0..5 {
+        if let Ok(Some(record)) = source.next().await {
+            hot = Some(record.data);
+            break;
+        }
+    }
+
+    if let Some(hot) = hot {
+        assert!(hot.len() <= 3, "Should emit at most top_n objects");
+        assert!(
+            hot.windows(2)
+                .all(|pair| pair[0].transactions >= pair[1].transactions),
+            "Objects should be sorted by transactions"
+        );
+        for object in hot {
+            assert!(object.transactions > 0, "Counts should be positive");
+            assert!(object.writes <= object.transactions);
+            assert!(object.window_start <= object.window_end);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiConnection, SuiDigestSource, SuiHydratedSource, TxHydrator};
+use sui_sdk::SUI_MAINNET_URL;
+
+#[tokio::test]
+async fn test_hydrator_preserves_order() {
+    let connection = SuiConnection::connect(SUI_MAINNET_URL)
+        .await
+        .expect("Connection failed");
+    let mut digests =
+        SuiDigestSource::new_with_mainnet(200, 20).with_connection(connection.clone());
+    digests.init().await.expect("Initialization failed");
+
+    let mut records = Vec::new();
+    for _ in 0..10 {
+        if let Ok(Some(record)) = digests.next().await {
+            records = record.data;
+            break;
+        }
+    }
+    if records.is_empty() {
+        return;
+    }
+
+    let hydrator = TxHydrator::new(&connection).with_concurrency(2);
+    let events = hydrator.hydrate(&records).await.expect("Hydration failed");
+    let expected: Vec<_> = records.iter().map(|record| &record.digest).collect();
+    let hydrated: Vec<_> = events
+        .iter()
+        .map(|event| &event.transaction_digest)
+        .collect();
+    assert_eq!(hydrated, expected, "Transactions should follow the records");
+    assert!(events.iter().all(|event| event.metadata.is_some()));
+
+    // Cached transactions hydrate identically
+    let cached = hydrator.hydrate(&records).await.expect("Hydration failed");
+    assert_eq!(cached.len(), events.len());
+}
+
+#[tokio::test]
+async fn test_hydrated_source() {
+    let connection = SuiConnection::connect(SUI_MAINNET_URL)
+        .await
+        .expect("Connection failed");
+    let mut source = SuiHydratedSource::new(
+        SuiDigestSource::new_with_mainnet(200, 10).with_connection(connection.clone()),
+        TxHydrator::new(&connection),
+    );
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Hydrating digests should succeed");
+}
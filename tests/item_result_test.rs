@@ -0,0 +1,59 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{ErrorPolicy, SourceItemError, SuiTransactionSource};
+use sui_sdk::types::digests::TransactionDigest;
+
+#[tokio::test]
+async fn test_item_results_emit_data() {
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).item_results();
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(record)) = source.next().await {
+        for item in record.data {
+            let transaction = item.expect("Healthy polls should only emit data");
+            assert!(!transaction.transaction_digest.is_empty());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_item_results_keep_running_on_failed_polls() {
+    // An unresolvable cursor fails every poll
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 5)
+        .with_cursor(TransactionDigest::random())
+        .item_results();
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..2 {
+        let record = source
+            .next()
+            .await
+            .expect("Failed polls should not end the stream")
+            .expect("Failed polls should emit an error record");
+        assert!(
+            matches!(
+                record.data.as_slice(),
+                [Err(SourceItemError::Fetch { source, .. })] if source == "SuiTransactionSource"
+            ),
+            "Should emit the poll failure"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_item_results_report_skipped_polls() {
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 5)
+        .with_cursor(TransactionDigest::random())
+        .with_rpc_error_policy(ErrorPolicy::SkipAndLog)
+        .item_results();
+    source.init().await.expect("Initialization failed");
+
+    let record = source
+        .next()
+        .await
+        .expect("Skipped polls should not fail")
+        .expect("Skipped polls should emit an error record");
+    assert!(matches!(
+        record.data.as_slice(),
+        [Err(SourceItemError::Fetch { .. })]
+    ));
+}
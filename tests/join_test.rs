@@ -0,0 +1,45 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiTransactionSource, TxEventJoiner};
+
+#[tokio::test]
+async fn test_tx_event_joiner_initialization() {
+    let mut joiner = TxEventJoiner::new(SuiTransactionSource::new_with_mainnet(500, 10));
+
+    let init_result = joiner.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(joiner.is_initialized());
+}
+
+#[tokio::test]
+async fn test_tx_event_joiner_attaches_events() {
+    let mut joiner = TxEventJoiner::new(SuiTransactionSource::new_with_mainnet(500, 10));
+    joiner.init().await.expect("Initialization failed");
+
+    let result = joiner.next().await;
+    assert!(
+        result.is_ok(),
+        "Fetching joined transactions should succeed"
+    );
+
+    if let Ok(Some(joined)) = result {
+        for item in joined.data {
+            assert!(
+                item.transaction.events.is_empty(),
+                "Events should be moved out of the transaction"
+            );
+            for event in &item.events {
+                assert_eq!(
+                    event.id.tx_digest.to_string(),
+                    item.transaction.transaction_digest,
+                    "Event should belong to its parent transaction"
+                );
+            }
+            assert!(
+                item.events
+                    .windows(2)
+                    .all(|pair| pair[0].id.event_seq < pair[1].id.event_seq),
+                "Events should be ordered by sequence number"
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use fluxus_source_sui::{
+    ChainEvent, LendingAction, LendingActionKind, LendingProtocol, MoveModulePath,
+};
+use serde_json::json;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::event::EventID;
+
+const OBLIGATION: &str = "0x0000000000000000000000000000000000000000000000000000000000000b01";
+const SUI: &str = "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI";
+
+fn chain_event(
+    protocol: LendingProtocol,
+    event_name: &str,
+    parsed_json: serde_json::Value,
+) -> ChainEvent {
+    let (module, _) = event_name
+        .split_once("::")
+        .expect("Event name should have a module");
+    ChainEvent {
+        id: EventID {
+            tx_digest: TransactionDigest::new([0; 32]),
+            event_seq: 0,
+        },
+        module: MoveModulePath::new(protocol.package(), module).expect("Module path should parse"),
+        event_type: format!("{}::{}", protocol.package(), event_name)
+            .parse()
+            .expect("Type tag should parse"),
+        sender: "0xsender".to_string(),
+        data: format!("{:?}", parsed_json),
+        parsed_json,
+        timestamp: 42,
+        chain: None,
+        enrichments: Default::default(),
+    }
+}
+
+#[test]
+fn test_scallop_borrow_normalized() {
+    let event = chain_event(
+        LendingProtocol::Scallop,
+        "borrow::BorrowEventV3",
+        json!({
+            "borrower": "0xborrower",
+            "obligation": OBLIGATION,
+            "asset": { "name": "0000000000000000000000000000000000000000000000000000000000000002::sui::SUI" },
+            "amount": "5000000000",
+        }),
+    );
+
+    let action = LendingAction::from_event(&event).expect("Event should be a position change");
+    assert_eq!(action.protocol, LendingProtocol::Scallop);
+    assert_eq!(action.kind, LendingActionKind::Borrow);
+    assert_eq!(action.account, OBLIGATION);
+    assert_eq!(action.coin_type.as_deref(), Some(SUI));
+    assert_eq!(action.amount, 5_000_000_000);
+    assert_eq!(action.liquidator, None);
+    assert_eq!(action.timestamp, 42);
+}
+
+#[test]
+fn test_suilend_liquidation_normalized() {
+    let event = chain_event(
+        LendingProtocol::Suilend,
+        "lending_market::LiquidateEvent",
+        json!({
+            "obligation_id": OBLIGATION,
+            "repay_coin_type": { "name": "0000000000000000000000000000000000000000000000000000000000000c01::usdc::USDC" },
+            "withdraw_coin_type": { "name": "0000000000000000000000000000000000000000000000000000000000000002::sui::SUI" },
+            "repay_amount": "1000000",
+            "withdraw_amount": "310000000",
+        }),
+    );
+
+    let action = LendingAction::from_event(&event).expect("Event should be a position change");
+    assert_eq!(action.kind, LendingActionKind::Liquidation);
+    assert_eq!(action.amount, 1_000_000);
+    assert_eq!(action.collateral_type.as_deref(), Some(SUI));
+    assert_eq!(action.collateral_amount, Some(310_000_000));
+    assert_eq!(
+        action.liquidator.as_deref(),
+        Some("0xsender"),
+        "Liquidator falls back to the sender"
+    );
+}
+
+#[test]
+fn test_navi_deposit_without_coin_type() {
+    let event = chain_event(
+        LendingProtocol::Navi,
+        "lending::DepositEvent",
+        json!({ "reserve": 0, "sender": "0xuser", "amount": "250" }),
+    );
+
+    let action = LendingAction::from_event(&event).expect("Event should be a position change");
+    assert_eq!(action.kind, LendingActionKind::Deposit);
+    assert_eq!(action.account, "0xuser");
+    assert_eq!(action.coin_type, None, "Navi events name a reserve index");
+    assert_eq!(action.amount, 250);
+}
+
+#[test]
+fn test_other_lending_events_skipped() {
+    let other = chain_event(
+        LendingProtocol::Navi,
+        "lending::WithdrawEvent",
+        json!({ "reserve": 0, "sender": "0xuser", "amount": "250" }),
+    );
+    assert_eq!(LendingAction::from_event(&other), None);
+
+    let malformed = chain_event(LendingProtocol::Navi, "lending::BorrowEvent", json!({}));
+    assert_eq!(LendingAction::from_event(&malformed), None);
+}
@@ -0,0 +1,60 @@
+use fluxus_source_sui::{MoveModulePath, MoveTypeTag};
+use std::sync::Arc;
+
+const SUI: &str = "0x0000000000000000000000000000000000000000000000000000000000000002";
+
+#[test]
+fn test_move_type_tag_canonical_form() {
+    let tag: MoveTypeTag = "0x2::pool::Pool<0x2::sui::SUI>"
+        .parse()
+        .expect("Type tag should parse");
+
+    assert_eq!(&*tag.module.package, SUI);
+    assert_eq!(&*tag.module.module, "pool");
+    assert_eq!(&*tag.name, "Pool");
+    assert_eq!(tag.base(), format!("{}::pool::Pool", SUI));
+    assert_eq!(
+        tag.to_string(),
+        format!("{}::pool::Pool<{}::sui::SUI>", SUI, SUI)
+    );
+}
+
+#[test]
+fn test_move_type_tag_short_and_long_forms_equal() {
+    let short: MoveTypeTag = "0x2::coin::Coin<0x2::sui::SUI>".parse().unwrap();
+    let long: MoveTypeTag = format!("{}::coin::Coin<{}::sui::SUI>", SUI, SUI)
+        .parse()
+        .unwrap();
+    assert_eq!(short, long);
+}
+
+#[test]
+fn test_move_type_tag_serde_round_trip() {
+    let tag: MoveTypeTag = "0x2::pool::SwapEvent".parse().unwrap();
+    let json = serde_json::to_value(&tag).expect("Tag should serialize");
+    assert_eq!(json, serde_json::json!(format!("{}::pool::SwapEvent", SUI)));
+
+    let parsed: MoveTypeTag = serde_json::from_value(json).expect("Tag should deserialize");
+    assert_eq!(parsed, tag);
+}
+
+#[test]
+fn test_move_type_tag_rejects_invalid() {
+    assert!("not a type".parse::<MoveTypeTag>().is_err());
+    assert!("0x2::pool".parse::<MoveModulePath>().is_ok());
+    assert!("pool".parse::<MoveModulePath>().is_err());
+}
+
+#[test]
+fn test_move_type_tag_shares_identifiers() {
+    let first: MoveTypeTag = "0x2::pool::Pool<0x2::sui::SUI>".parse().unwrap();
+    let second: MoveTypeTag = format!("{}::pool::Pool<0x2::sui::SUI>", SUI)
+        .parse()
+        .unwrap();
+
+    // Tags parsed apart point to the same strings
+    assert!(Arc::ptr_eq(&first.module.package, &second.module.package));
+    assert!(Arc::ptr_eq(&first.module.module, &second.module.module));
+    assert!(Arc::ptr_eq(&first.name, &second.name));
+    assert!(Arc::ptr_eq(&first.type_params[0], &second.type_params[0]));
+}
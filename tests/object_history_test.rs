@@ -0,0 +1,94 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiObjectHistorySource;
+
+// The Clock object is updated by every checkpoint, so it always has history to walk
+const CLOCK_OBJECT_ID: &str = "0x6";
+
+#[tokio::test]
+async fn test_sui_object_history_source_initialization() {
+    // Create a new SuiObjectHistorySource instance with mainnet configuration
+    let mut source =
+        SuiObjectHistorySource::new_with_mainnet(500, vec![CLOCK_OBJECT_ID.to_string()]);
+
+    // Test initialization
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+}
+
+#[tokio::test]
+async fn test_sui_object_history_source_lineage() {
+    // Create SuiObjectHistorySource walking at most 3 versions
+    let mut source =
+        SuiObjectHistorySource::new_with_mainnet(500, vec![CLOCK_OBJECT_ID.to_string()])
+            .with_max_depth(3);
+    source.init().await.expect("Initialization failed");
+
+    // Get first lineage batch
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching object history should succeed");
+
+    if let Ok(Some(versions)) = result {
+        assert!(!versions.data.is_empty(), "Should return object versions");
+        assert!(versions.data.len() <= 3, "Should respect max depth");
+
+        // Versions are emitted oldest first and chain to each other
+        for pair in versions.data.windows(2) {
+            assert!(pair[0].version < pair[1].version, "Versions should ascend");
+            assert_eq!(pair[1].previous_version, Some(pair[0].version));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_object_history_source_invalid_object_id() {
+    // Create SuiObjectHistorySource with an invalid object ID
+    let mut source = SuiObjectHistorySource::new_with_mainnet(500, vec!["invalid".to_string()]);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_err(), "Should fail with invalid object ID");
+}
+
+#[tokio::test]
+async fn test_sui_object_history_source_error_handling() {
+    // Create SuiObjectHistorySource with invalid RPC endpoint
+    let mut source = SuiObjectHistorySource::new(
+        "http://invalid-endpoint".to_string(),
+        500,
+        vec![CLOCK_OBJECT_ID.to_string()],
+    );
+
+    // Test initialization
+    let init_result = source.init().await;
+    assert!(init_result.is_err(), "Should fail with invalid endpoint");
+}
+
+#[tokio::test]
+async fn test_sui_object_history_source_resumes_truncated_walk() {
+    // The Clock advances faster than a walk of one version per poll can follow
+    let mut source =
+        SuiObjectHistorySource::new_with_mainnet(500, vec![CLOCK_OBJECT_ID.to_string()])
+            .with_max_depth(1);
+    source.init().await.expect("Initialization failed");
+
+    let mut polls = Vec::new();
+    for _ in 0..3 {
+        let versions = match source.next().await {
+            Ok(Some(record)) => record.data,
+            _ => return,
+        };
+        polls.push(versions.iter().map(|v| v.version).collect::<Vec<_>>());
+    }
+
+    // Versions skipped by the truncated second walk are emitted by the third
+    let mut seen = std::collections::HashSet::new();
+    for version in polls.iter().flatten() {
+        assert!(seen.insert(*version), "Versions should not repeat");
+    }
+    assert!(
+        polls[2]
+            .iter()
+            .all(|v| v < &polls[1][0] && v > &polls[0][0]),
+        "Third poll should resume the gap before the second head"
+    );
+}
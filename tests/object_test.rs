@@ -1,7 +1,14 @@
 use fluxus::sources::Source;
-use fluxus_source_sui::SuiObjectSource;
+use fluxus_source_sui::{
+    NotificationRegistry, ObjectChange, ObjectTimestamp, SnapshotMode, SuiObjectSource,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use sui_sdk::SUI_MAINNET_URL;
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::types::object::Owner;
 use tokio::time::sleep;
 
 const TEST_ADDRESS: &str = "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c";
@@ -115,3 +122,165 @@ async fn test_sui_object_source_error_handling() {
     let init_result = source.init().await;
     assert!(init_result.is_err(), "Should fail with invalid endpoint");
 }
+
+#[tokio::test]
+async fn test_sui_object_source_owner_kind() {
+    let mut source = SuiObjectSource::new_with_mainnet(500, TEST_ADDRESS.to_string(), 5);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(objects)) = source.next().await {
+        for object in objects.data {
+            // Owned objects of the address report it as their owner
+            match object.owner_kind {
+                Some(Owner::AddressOwner(address)) => {
+                    assert_eq!(address.to_string(), TEST_ADDRESS);
+                    assert_eq!(object.owner, TEST_ADDRESS);
+                }
+                Some(_) => {}
+                None => panic!("Owner should be decoded"),
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_object_source_summaries() {
+    // Summaries carry no content unless selected for hydration
+    let mut source = SuiObjectSource::new_with_mainnet(500, TEST_ADDRESS.to_string(), 10)
+        .summaries()
+        .with_hydration(|summary| summary.object_type.contains("::coin::Coin"), 2);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(summaries)) = source.next().await {
+        let hydrated = summaries
+            .data
+            .iter()
+            .filter(|summary| summary.hydrated.is_some())
+            .count();
+        assert!(hydrated <= 2, "Hydration should stay within the budget");
+        for summary in &summaries.data {
+            assert!(!summary.id.is_empty(), "Summary should carry the ID");
+            if let Some(data) = &summary.hydrated {
+                assert!(
+                    summary.object_type.contains("::coin::Coin"),
+                    "Only selected summaries are hydrated"
+                );
+                assert!(data.content.is_some(), "Hydrated data carries content");
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_object_source_notifications() {
+    // Callbacks fire once per emitted object of the watched owner
+    let registry = NotificationRegistry::new();
+    let notified = Arc::new(AtomicUsize::new(0));
+    let counter = notified.clone();
+    registry.on_object_change(
+        SuiAddress::from_str(TEST_ADDRESS).expect("Address should parse"),
+        move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        },
+    );
+
+    let mut source = SuiObjectSource::new_with_mainnet(500, TEST_ADDRESS.to_string(), 10)
+        .with_notifications(registry);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(objects)) = source.next().await {
+        let owned = objects
+            .data
+            .iter()
+            .filter(|object| {
+                SuiAddress::from_str(&object.owner).ok() == SuiAddress::from_str(TEST_ADDRESS).ok()
+            })
+            .count();
+        assert_eq!(notified.load(Ordering::SeqCst), owned);
+    }
+}
+
+#[tokio::test]
+async fn test_sui_object_source_changes() {
+    // The first batch lists the owned objects as created
+    let mut source = SuiObjectSource::new_with_mainnet(500, TEST_ADDRESS.to_string(), 10).changes();
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(batch)) = source.next().await {
+        assert!(batch.data.snapshot, "First batch should be the snapshot");
+        assert!(
+            batch
+                .data
+                .changes
+                .iter()
+                .all(|change| matches!(change, ObjectChange::Created(_))),
+            "Snapshot should only hold created objects"
+        );
+    }
+
+    // Later batches report changes relative to the snapshot
+    if let Ok(Some(batch)) = source.next().await {
+        assert!(!batch.data.snapshot, "Later batches are incremental");
+        for change in &batch.data.changes {
+            if let ObjectChange::Updated {
+                old_version,
+                new_version,
+                ..
+            } = change
+            {
+                assert!(new_version > old_version, "Versions should increase");
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_object_source_snapshot_once() {
+    // Small pages make the snapshot read several of them
+    let mut source = SuiObjectSource::new_with_mainnet(60_000, TEST_ADDRESS.to_string(), 5)
+        .with_snapshot_mode(SnapshotMode::Once);
+    source.init().await.expect("Initialization failed");
+
+    let start = std::time::Instant::now();
+    let snapshot = source.next().await.expect("Snapshot should succeed");
+    assert!(
+        start.elapsed() < Duration::from_secs(60),
+        "Snapshot should not wait for the polling interval"
+    );
+    if let Some(objects) = snapshot {
+        let mut ids: Vec<&String> = objects.data.iter().map(|object| &object.id).collect();
+        let listed = ids.len();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), listed, "Objects should be listed once");
+    }
+
+    // The stream ends after the snapshot
+    assert!(matches!(source.next().await, Ok(None)));
+    assert!(matches!(source.next().await, Ok(None)));
+}
+
+#[tokio::test]
+async fn test_sui_object_source_previous_transaction_timestamp() {
+    let mut source = SuiObjectSource::new_with_mainnet(100, TEST_ADDRESS.to_string(), 10)
+        .with_object_timestamp(ObjectTimestamp::PreviousTransaction);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching objects should succeed");
+    if let Ok(Some(objects)) = result {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        for object in &objects.data {
+            assert!(object.timestamp > 0 && object.timestamp <= now);
+        }
+        let newest = objects.data.iter().map(|object| object.timestamp).max();
+        assert_eq!(
+            newest.map(|newest| newest as i64),
+            Some(objects.timestamp),
+            "Records should carry the newest object timestamp"
+        );
+    }
+}
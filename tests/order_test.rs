@@ -0,0 +1,107 @@
+use fluxus_source_sui::EmitOrder;
+use proptest::prelude::*;
+
+/// Item with its chain position: (checkpoint, position within the checkpoint)
+type Item = (u64, u32);
+
+/// Builds a chain of items in ascending chain order from checkpoint sizes
+fn build_chain(checkpoint_sizes: &[u32]) -> Vec<Item> {
+    checkpoint_sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(checkpoint, size)| (0..*size).map(move |seq| (checkpoint as u64, seq)))
+        .collect()
+}
+
+/// Splits items into pages of the given sizes, repeating the pages marked as retried
+fn paginate(items: &[Item], page_size: usize, retried: &[bool]) -> Vec<Item> {
+    let mut arrived = Vec::new();
+    for (index, page) in items.chunks(page_size).enumerate() {
+        arrived.extend_from_slice(page);
+        if retried.get(index).copied().unwrap_or(false) {
+            arrived.extend_from_slice(page);
+        }
+    }
+    arrived
+}
+
+/// Drops repeated deliveries, keeping the first occurrence
+fn dedup(items: &[Item]) -> Vec<Item> {
+    let mut seen = std::collections::HashSet::new();
+    items
+        .iter()
+        .copied()
+        .filter(|item| seen.insert(*item))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn chain_ascending_holds_across_pages_and_retries(
+        checkpoint_sizes in prop::collection::vec(0u32..6, 1..20),
+        page_size in 1usize..8,
+        retried in prop::collection::vec(any::<bool>(), 0..20),
+        descending_query in any::<bool>(),
+    ) {
+        let chain = build_chain(&checkpoint_sizes);
+        let mut queried = chain.clone();
+        if descending_query {
+            queried.reverse();
+        }
+        let mut batch = paginate(&queried, page_size, &retried);
+        let arrived_len = batch.len();
+
+        // Only the checkpoint is visible to the source, ties rely on arrival order
+        EmitOrder::ChainAscending.arrange(&mut batch, descending_query, |item| item.0);
+
+        prop_assert_eq!(batch.len(), arrived_len);
+        prop_assert!(batch.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        prop_assert_eq!(dedup(&batch), chain);
+    }
+
+    #[test]
+    fn chain_descending_holds_across_pages_and_retries(
+        checkpoint_sizes in prop::collection::vec(0u32..6, 1..20),
+        page_size in 1usize..8,
+        retried in prop::collection::vec(any::<bool>(), 0..20),
+        descending_query in any::<bool>(),
+    ) {
+        let chain = build_chain(&checkpoint_sizes);
+        let mut queried = chain.clone();
+        if descending_query {
+            queried.reverse();
+        }
+        let mut batch = paginate(&queried, page_size, &retried);
+
+        EmitOrder::ChainDescending.arrange(&mut batch, descending_query, |item| item.0);
+
+        let mut expected = chain;
+        expected.reverse();
+        prop_assert!(batch.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+        prop_assert_eq!(dedup(&batch), expected);
+    }
+
+    #[test]
+    fn arrival_order_keeps_node_order(
+        checkpoint_sizes in prop::collection::vec(0u32..6, 1..20),
+        page_size in 1usize..8,
+        retried in prop::collection::vec(any::<bool>(), 0..20),
+        descending_query in any::<bool>(),
+    ) {
+        let mut queried = build_chain(&checkpoint_sizes);
+        if descending_query {
+            queried.reverse();
+        }
+        let arrived = paginate(&queried, page_size, &retried);
+        let mut batch = arrived.clone();
+
+        EmitOrder::ArrivalOrder.arrange(&mut batch, descending_query, |item| item.0);
+
+        prop_assert_eq!(batch, arrived);
+    }
+}
+
+#[test]
+fn test_emit_order_default_is_arrival_order() {
+    assert_eq!(EmitOrder::default(), EmitOrder::ArrivalOrder);
+}
@@ -0,0 +1,47 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiPackageSource;
+use tokio::time::{Duration, timeout};
+
+#[tokio::test]
+async fn test_sui_package_source_initialization() {
+    let mut source = SuiPackageSource::new_with_mainnet(500);
+    assert_eq!(source.next_checkpoint(), None);
+
+    source.init().await.expect("Initialization failed");
+    assert!(source.is_initialized(), "Source should be initialized");
+    assert!(
+        source
+            .next_checkpoint()
+            .is_some_and(|checkpoint| checkpoint > 0),
+        "Should start after the newest checkpoint"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_package_source_scans_checkpoints() {
+    let mut source = SuiPackageSource::new_with_mainnet(1000).with_max_checkpoints(5);
+    source.init().await.expect("Initialization failed");
+    let start = source
+        .next_checkpoint()
+        .expect("Start checkpoint should be set");
+
+    let result = timeout(Duration::from_secs(30), source.next())
+        .await
+        .expect("Poll should not hang");
+    match result {
+        Ok(Some(record)) => {
+            for package in &record.data {
+                assert!(package.checkpoint >= start);
+                assert!(!package.modules.is_empty(), "Packages should have modules");
+            }
+        }
+        Ok(None) => {}
+        Err(e) => panic!("Polling should succeed: {}", e),
+    }
+    assert!(
+        source
+            .next_checkpoint()
+            .is_some_and(|checkpoint| checkpoint <= start + 5),
+        "Should read at most the configured checkpoints"
+    );
+}
@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamResult};
+use fluxus_source_sui::SuiEvent;
+use fluxus_source_sui::pipelines::{
+    DistinctTransactions, PipelineConfig, event_type_pipeline, transaction_type_pipeline,
+    transfer_volume_pipeline,
+};
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+
+/// Transaction of digest `digest-n`
+fn transaction(n: u64) -> SuiEvent {
+    SuiEvent {
+        transaction_digest: format!("digest-{}", n),
+        transaction_type: "ProgrammableTransaction".to_string(),
+        timestamp: 1_700_000_000_000 + n,
+        checkpoint: Some(n),
+        sender: "0x1".to_string(),
+        metadata: None,
+        effects: None,
+        abort: None,
+        events: Vec::new(),
+        balance_changes: Vec::new(),
+        chain: None,
+        tags: Vec::new(),
+        raw_transaction: None,
+        raw_effects: None,
+        enrichments: BTreeMap::new(),
+    }
+}
+
+/// Source returning fixed pages of transactions
+struct Pages(VecDeque<Vec<SuiEvent>>);
+
+#[async_trait]
+impl Source<Vec<SuiEvent>> for Pages {
+    async fn init(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<Vec<SuiEvent>>>> {
+        Ok(self.0.pop_front().map(Record::new))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_pipeline_config_builders() {
+    let config = PipelineConfig::default()
+        .with_rpc_url("http://127.0.0.1:9000")
+        .with_interval_ms(1000)
+        .with_batch_size(25)
+        .with_window(Duration::from_secs(60))
+        .with_parallelism(0);
+    assert_eq!(config.rpc_url, "http://127.0.0.1:9000");
+    assert_eq!(config.interval_ms, 1000);
+    assert_eq!(config.batch_size, 25);
+    assert_eq!(config.window, Duration::from_secs(60));
+    assert_eq!(config.parallelism, 1, "Parallelism should be at least 1");
+}
+
+#[tokio::test]
+async fn test_pipelines_initialize_sources() {
+    let config = PipelineConfig::default();
+    assert!(transaction_type_pipeline(&config).await.is_ok());
+    assert!(event_type_pipeline(&config).await.is_ok());
+    assert!(transfer_volume_pipeline(&config).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_pipelines_fail_on_unreachable_endpoint() {
+    let config = PipelineConfig::default().with_rpc_url("http://127.0.0.1:1");
+    assert!(
+        transfer_volume_pipeline(&config).await.is_err(),
+        "Pipelines should surface init failures"
+    );
+}
+
+#[tokio::test]
+async fn test_distinct_transactions_drop_overlapping_pages() {
+    let pages = vec![
+        vec![transaction(3), transaction(2), transaction(1)],
+        vec![transaction(4), transaction(3), transaction(2)],
+        vec![transaction(4), transaction(3)],
+    ];
+    let mut source = DistinctTransactions::new(Pages(pages.into()), 10);
+    source.init().await.expect("Initialization failed");
+
+    let mut digests = Vec::new();
+    for _ in 0..3 {
+        if let Some(record) = source.next().await.expect("Polling failed") {
+            digests.extend(record.data.into_iter().map(|tx| tx.transaction_digest));
+        }
+    }
+    assert_eq!(
+        digests,
+        ["digest-3", "digest-2", "digest-1", "digest-4"],
+        "Each transaction should be emitted once"
+    );
+}
@@ -0,0 +1,48 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{EndpointPool, SuiTransactionSource};
+use sui_sdk::SUI_MAINNET_URL;
+
+#[tokio::test]
+async fn test_endpoint_pool_skips_unreachable_endpoints() {
+    // One reachable endpoint and one that can never connect
+    let pool = EndpointPool::new()
+        .with_endpoint(SUI_MAINNET_URL, 2)
+        .with_endpoint("https://invalid.endpoint.example.com", 1);
+    let mut source = SuiTransactionSource::new_with_mainnet(200, 5).with_endpoint_pool(pool);
+
+    source.init().await.expect("Initialization should succeed");
+    for _ in 0..3 {
+        assert!(
+            source.next().await.is_ok(),
+            "Polls should be served by the reachable endpoint"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_endpoint_pool_rate_limited_endpoint() {
+    let pool = EndpointPool::new().with_rate_limited_endpoint(SUI_MAINNET_URL, 1, 5.0, 1);
+    let mut source = SuiTransactionSource::new_with_mainnet(0, 5).with_endpoint_pool(pool);
+    source.init().await.expect("Initialization failed");
+
+    // The endpoint allows one request immediately, then five per second
+    let start = std::time::Instant::now();
+    for _ in 0..3 {
+        let _ = source.next().await;
+    }
+    assert!(
+        start.elapsed() >= std::time::Duration::from_millis(400),
+        "Polls should respect the endpoint rate limit"
+    );
+}
+
+#[tokio::test]
+async fn test_endpoint_pool_without_reachable_endpoints() {
+    let pool = EndpointPool::new().with_endpoint("https://invalid.endpoint.example.com", 1);
+    let mut source = SuiTransactionSource::new_with_mainnet(200, 5).with_endpoint_pool(pool);
+
+    assert!(
+        source.init().await.is_err(),
+        "Initialization should fail without a reachable endpoint"
+    );
+}
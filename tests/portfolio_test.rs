@@ -0,0 +1,65 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiPortfolioSource;
+use sui_sdk::SUI_MAINNET_URL;
+
+const TEST_ADDRESS: &str = "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c";
+
+#[tokio::test]
+async fn test_sui_portfolio_source_initialization() {
+    // Create a new SuiPortfolioSource instance with mainnet configuration
+    let mut source = SuiPortfolioSource::new_with_mainnet(500, vec![TEST_ADDRESS.to_string()]);
+
+    // Test initialization
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized(), "Source should be initialized");
+}
+
+#[tokio::test]
+async fn test_sui_portfolio_source_data_fetching() {
+    // Create SuiPortfolioSource instance
+    let mut source = SuiPortfolioSource::new(
+        SUI_MAINNET_URL.to_string(),
+        500,
+        vec![TEST_ADDRESS.to_string()],
+    )
+    .with_max_objects(50);
+
+    // Initialize
+    source.init().await.expect("Initialization failed");
+
+    // Get first batch of snapshots
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching portfolio data should succeed");
+
+    if let Ok(Some(snapshots)) = result {
+        // One snapshot per watched address
+        assert_eq!(snapshots.data.len(), 1, "Should return one snapshot");
+        assert_eq!(snapshots.data[0].address, TEST_ADDRESS);
+    }
+}
+
+#[tokio::test]
+async fn test_sui_portfolio_source_invalid_address() {
+    // Create SuiPortfolioSource with an invalid watched address
+    let mut source = SuiPortfolioSource::new_with_mainnet(500, vec!["not-an-address".to_string()]);
+    source.init().await.expect("Initialization failed");
+
+    // Fetching should fail on the invalid address
+    let result = source.next().await;
+    assert!(result.is_err(), "Should fail with invalid address");
+}
+
+#[tokio::test]
+async fn test_sui_portfolio_source_error_handling() {
+    // Create SuiPortfolioSource with invalid RPC endpoint
+    let mut source = SuiPortfolioSource::new(
+        "http://invalid-endpoint".to_string(),
+        500,
+        vec![TEST_ADDRESS.to_string()],
+    );
+
+    // Test initialization
+    let init_result = source.init().await;
+    assert!(init_result.is_err(), "Should fail with invalid endpoint");
+}
@@ -0,0 +1,88 @@
+use fluxus_source_sui::{ChainEvent, MoveModulePath, Redact, Redaction, RedactionConfig};
+use serde_json::json;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::event::EventID;
+
+const SENDER: &str = "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c";
+
+fn chain_event() -> ChainEvent {
+    let parsed_json = json!({ "recipient": SENDER });
+    ChainEvent {
+        id: EventID {
+            tx_digest: TransactionDigest::new([0; 32]),
+            event_seq: 0,
+        },
+        module: MoveModulePath::new("0x2", "pool").expect("Module path should parse"),
+        event_type: "0x2::pool::SwapEvent"
+            .parse()
+            .expect("Type tag should parse"),
+        sender: SENDER.to_string(),
+        data: format!("{:?}", parsed_json),
+        parsed_json,
+        timestamp: 0,
+        chain: None,
+        enrichments: Default::default(),
+    }
+}
+
+#[test]
+fn test_redaction_keep_is_identity() {
+    let mut event = chain_event();
+    event.redact(&RedactionConfig::default());
+
+    assert_eq!(event.sender, SENDER);
+    assert_eq!(event.parsed_json, json!({ "recipient": SENDER }));
+}
+
+#[test]
+fn test_redaction_hash_is_stable_and_salted() {
+    let config = RedactionConfig {
+        sender: Redaction::Hash,
+        salt: "pepper".to_string(),
+        ..Default::default()
+    };
+    let mut first = chain_event();
+    let mut second = chain_event();
+    first.redact(&config);
+    second.redact(&config);
+
+    assert_ne!(first.sender, SENDER, "Sender should be hashed");
+    assert_eq!(
+        first.sender, second.sender,
+        "Equal inputs should hash equally"
+    );
+    assert_eq!(first.sender.len(), 64, "Hash should be hex encoded SHA-256");
+
+    let other_salt = RedactionConfig {
+        salt: "salt".to_string(),
+        ..config
+    };
+    assert_ne!(
+        other_salt.redact_value(Redaction::Hash, SENDER),
+        first.sender,
+        "Different salts should produce different hashes"
+    );
+}
+
+#[test]
+fn test_redaction_mask() {
+    let config = RedactionConfig::default();
+    assert_eq!(
+        config.redact_value(Redaction::Mask, SENDER),
+        "0xac5b...c33c"
+    );
+    assert_eq!(config.redact_value(Redaction::Mask, "0x2"), "***");
+}
+
+#[test]
+fn test_redaction_removes_metadata() {
+    let config = RedactionConfig {
+        metadata: Redaction::Mask,
+        ..Default::default()
+    };
+    let mut event = chain_event();
+    event.redact(&config);
+
+    assert_eq!(event.parsed_json, serde_json::Value::Null);
+    assert!(!event.data.contains(SENDER), "Event data should be masked");
+}
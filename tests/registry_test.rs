@@ -0,0 +1,92 @@
+use fluxus_source_sui::{ChainEvent, MoveEventRegistry, MoveModulePath};
+use serde::Deserialize;
+use serde_json::json;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::event::EventID;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct SwapEvent {
+    amount_in: String,
+    amount_out: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum PoolEvent {
+    Swap(SwapEvent),
+}
+
+fn chain_event(event_type: &str, parsed_json: serde_json::Value) -> ChainEvent {
+    ChainEvent {
+        id: EventID {
+            tx_digest: TransactionDigest::new([0; 32]),
+            event_seq: 0,
+        },
+        module: MoveModulePath::new("0x2", "pool").expect("Module path should parse"),
+        event_type: event_type.parse().expect("Type tag should parse"),
+        sender: "0x0".to_string(),
+        data: format!("{:?}", parsed_json),
+        parsed_json,
+        timestamp: 0,
+        chain: None,
+        enrichments: Default::default(),
+    }
+}
+
+#[test]
+fn test_registry_decodes_registered_type() {
+    let registry = MoveEventRegistry::new().register("0x2::pool::SwapEvent", PoolEvent::Swap);
+    let event = chain_event(
+        "0x2::pool::SwapEvent",
+        json!({ "amount_in": "10", "amount_out": "9" }),
+    );
+
+    let decoded = registry.decode(&event).expect("Type should be registered");
+    assert_eq!(
+        decoded.expect("Decoding should succeed"),
+        PoolEvent::Swap(SwapEvent {
+            amount_in: "10".to_string(),
+            amount_out: "9".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_registry_matches_long_address_form() {
+    let registry = MoveEventRegistry::new().register("0x2::pool::SwapEvent", PoolEvent::Swap);
+    let long_form = format!("0x{:0>64}::pool::SwapEvent", "2");
+
+    assert!(
+        registry.is_registered(&long_form),
+        "Long and short address forms should match"
+    );
+}
+
+#[test]
+fn test_registry_matches_generic_instances() {
+    let registry = MoveEventRegistry::new().register("0x2::pool::SwapEvent", PoolEvent::Swap);
+
+    assert!(
+        registry.is_registered("0x2::pool::SwapEvent<0x2::sui::SUI>"),
+        "Generic instances should match the base type"
+    );
+}
+
+#[test]
+fn test_registry_skips_unregistered_type() {
+    let registry = MoveEventRegistry::new().register("0x2::pool::SwapEvent", PoolEvent::Swap);
+    let event = chain_event("0x2::pool::OtherEvent", json!({}));
+
+    assert!(
+        registry.decode(&event).is_none(),
+        "Unregistered types should not decode"
+    );
+}
+
+#[test]
+fn test_registry_reports_decode_errors() {
+    let registry = MoveEventRegistry::new().register("0x2::pool::SwapEvent", PoolEvent::Swap);
+    let event = chain_event("0x2::pool::SwapEvent", json!({ "unexpected": true }));
+
+    let decoded = registry.decode(&event).expect("Type should be registered");
+    assert!(decoded.is_err(), "Malformed payloads should fail to decode");
+}
@@ -0,0 +1,19 @@
+use fluxus_source_sui::next_correlation_id;
+use std::collections::HashSet;
+
+#[test]
+fn test_correlation_id_prefixed_with_source() {
+    let id = next_correlation_id("SuiEventSource");
+    assert!(
+        id.starts_with("SuiEventSource-"),
+        "Correlation ID should start with the source name"
+    );
+}
+
+#[test]
+fn test_correlation_ids_are_unique() {
+    let ids: HashSet<String> = (0..100)
+        .map(|_| next_correlation_id("SuiTransactionSource"))
+        .collect();
+    assert_eq!(ids.len(), 100, "Correlation IDs should never repeat");
+}
@@ -0,0 +1,110 @@
+use fluxus_source_sui::{Priority, SuiScheduler};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_sui_scheduler_allows_burst() {
+    // Create a scheduler with a burst of 5 requests
+    let scheduler = SuiScheduler::new(1.0, 5);
+
+    // High priority requests within the burst should not wait
+    let start = Instant::now();
+    for _ in 0..5 {
+        scheduler.acquire(Priority::High).await;
+    }
+    assert!(
+        start.elapsed() < Duration::from_millis(100),
+        "Burst requests should be granted immediately"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_scheduler_enforces_rate() {
+    // Create a scheduler allowing 20 requests per second with a burst of 1
+    let scheduler = SuiScheduler::new(20.0, 1);
+
+    // Three requests need at least two refills
+    let start = Instant::now();
+    for _ in 0..3 {
+        scheduler.acquire(Priority::High).await;
+    }
+    assert!(
+        start.elapsed() >= Duration::from_millis(90),
+        "Requests beyond the burst should respect the rate"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_scheduler_low_priority_keeps_reserve() {
+    // Create a scheduler with a burst of 4 requests
+    let scheduler = SuiScheduler::new(1.0, 4);
+
+    // Low priority may only spend down to half of the burst
+    scheduler.acquire(Priority::Low).await;
+    scheduler.acquire(Priority::Low).await;
+    let low = tokio::time::timeout(Duration::from_millis(100), scheduler.acquire(Priority::Low));
+    assert!(low.await.is_err(), "Low priority should leave the reserve");
+
+    // High priority can still use the reserve
+    let high = tokio::time::timeout(
+        Duration::from_millis(100),
+        scheduler.acquire(Priority::High),
+    );
+    assert!(high.await.is_ok(), "High priority should use the reserve");
+}
+
+#[tokio::test]
+async fn test_sui_scheduler_clones_share_budget() {
+    let scheduler = SuiScheduler::new(1.0, 2);
+    let other = scheduler.clone();
+
+    scheduler.acquire(Priority::High).await;
+    other.acquire(Priority::High).await;
+    assert!(
+        other.available() < 1.0,
+        "Clones should share the same budget"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_scheduler_serves_class_in_order() {
+    let scheduler = SuiScheduler::new(20.0, 1);
+    scheduler.acquire(Priority::Normal).await;
+
+    // Requests waiting in one class are granted in the order they arrived
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let mut waiters = Vec::new();
+    for n in 0..3 {
+        let scheduler = scheduler.clone();
+        let order = order.clone();
+        waiters.push(tokio::spawn(async move {
+            scheduler.acquire(Priority::High).await;
+            order.lock().expect("Order lock poisoned").push(n);
+        }));
+        sleep(Duration::from_millis(5)).await;
+    }
+    for waiter in waiters {
+        waiter.await.expect("Waiter panicked");
+    }
+    assert_eq!(*order.lock().expect("Order lock poisoned"), vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn test_sui_scheduler_skips_abandoned_requests() {
+    let scheduler = SuiScheduler::new(20.0, 1);
+    scheduler.acquire(Priority::High).await;
+
+    // A request that stops waiting does not hold up the ones behind it
+    let abandoned =
+        tokio::time::timeout(Duration::from_millis(5), scheduler.acquire(Priority::High));
+    assert!(abandoned.await.is_err(), "Budget should be exhausted");
+    let granted = tokio::time::timeout(
+        Duration::from_millis(500),
+        scheduler.acquire(Priority::High),
+    );
+    assert!(
+        granted.await.is_ok(),
+        "Later requests should still be granted"
+    );
+}
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use fluxus::sources::Source;
+use fluxus::utils::models::{Record, StreamError, StreamResult};
+use fluxus_source_sui::{RetryConfig, SharedSuiSource, SuiEventSource};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+#[tokio::test]
+async fn test_shared_source_fans_out_records() {
+    let shared = SharedSuiSource::start(SuiEventSource::new_with_mainnet(500, 10), 16)
+        .await
+        .expect("Shared source should start");
+    let mut first = shared.subscribe();
+    let mut second = shared.subscribe();
+    assert_eq!(shared.subscriber_count(), 2);
+
+    first.init().await.expect("Initialization failed");
+    second.init().await.expect("Initialization failed");
+
+    let Ok(Ok(Some(a))) = timeout(Duration::from_secs(30), first.next()).await else {
+        return;
+    };
+    let b = timeout(Duration::from_secs(30), second.next())
+        .await
+        .expect("Second subscriber should receive the same record")
+        .expect("Record should not be an error")
+        .expect("Record should be present");
+
+    let ids = |events: &[fluxus_source_sui::ChainEvent]| {
+        events.iter().map(|event| event.id).collect::<Vec<_>>()
+    };
+    assert_eq!(
+        ids(&a.data),
+        ids(&b.data),
+        "Subscribers should see the same batch"
+    );
+}
+
+/// Source failing every poll, counting polls and closes
+struct Failing {
+    /// Polls issued so far
+    polls: Arc<AtomicUsize>,
+    /// Set once the source was closed
+    closed: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl Source<u64> for Failing {
+    async fn init(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    async fn next(&mut self) -> StreamResult<Option<Record<u64>>> {
+        self.polls.fetch_add(1, Ordering::SeqCst);
+        Err(StreamError::Runtime("node unavailable".to_string()))
+    }
+
+    async fn close(&mut self) -> StreamResult<()> {
+        self.closed.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_shared_source_backs_off_and_closes() {
+    let polls = Arc::new(AtomicUsize::new(0));
+    let closed = Arc::new(AtomicBool::new(false));
+    let source = Failing {
+        polls: polls.clone(),
+        closed: closed.clone(),
+    };
+    let retry = RetryConfig::new(5, Duration::from_millis(100), Duration::from_millis(100));
+    let shared = SharedSuiSource::start_with_retry(source, 16, retry)
+        .await
+        .expect("Shared source should start");
+    let mut subscription = shared.subscribe();
+
+    let result = timeout(Duration::from_secs(5), subscription.next())
+        .await
+        .expect("Failure should be broadcast");
+    assert!(result.is_err(), "Failed poll should reach the subscriber");
+
+    // Failed polls wait for the retry delay instead of spinning
+    sleep(Duration::from_millis(350)).await;
+    let issued = polls.load(Ordering::SeqCst);
+    assert!(
+        issued <= 5,
+        "Failed polls should back off, issued {}",
+        issued
+    );
+
+    // Dropping every handle closes the wrapped source
+    drop(subscription);
+    drop(shared);
+    sleep(Duration::from_millis(50)).await;
+    assert!(
+        closed.load(Ordering::SeqCst),
+        "Wrapped source should be closed"
+    );
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "submit")]
+
+use async_trait::async_trait;
+use fluxus::utils::models::{StreamError, StreamResult};
+use fluxus_source_sui::{SuiConnection, TransactionSigner, submit};
+use sui_sdk::SUI_TESTNET_URL;
+use sui_sdk::types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+use sui_sdk::types::crypto::Signature;
+use sui_sdk::types::digests::ObjectDigest;
+use sui_sdk::types::transaction::TransactionData;
+
+struct RefusingSigner;
+
+#[async_trait]
+impl TransactionSigner for RefusingSigner {
+    async fn sign(&self, _data: &TransactionData) -> StreamResult<Signature> {
+        Err(StreamError::Runtime("Signing refused".to_string()))
+    }
+}
+
+#[tokio::test]
+async fn test_submit_signer_error() {
+    let connection = SuiConnection::connect(SUI_TESTNET_URL)
+        .await
+        .expect("Connection failed");
+    let data = TransactionData::new_transfer_sui(
+        SuiAddress::random_for_testing_only(),
+        SuiAddress::random_for_testing_only(),
+        Some(1),
+        (
+            ObjectID::random(),
+            SequenceNumber::new(),
+            ObjectDigest::random(),
+        ),
+        10_000_000,
+        1_000,
+    );
+
+    // Nothing is submitted without a signature
+    let result = submit(&connection, data, &RefusingSigner, 100).await;
+    assert!(result.is_err(), "Signer errors should fail the submission");
+}
@@ -0,0 +1,123 @@
+use fluxus_source_sui::{ChainEvent, MoveModulePath, Swap, SwapProtocol};
+use serde_json::json;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::types::event::EventID;
+
+const POOL: &str = "0x0000000000000000000000000000000000000000000000000000000000000b01";
+
+fn chain_event(
+    protocol: SwapProtocol,
+    event_name: &str,
+    parsed_json: serde_json::Value,
+) -> ChainEvent {
+    let (module, _) = event_name
+        .split_once("::")
+        .expect("Event name should have a module");
+    ChainEvent {
+        id: EventID {
+            tx_digest: TransactionDigest::new([0; 32]),
+            event_seq: 0,
+        },
+        module: MoveModulePath::new(protocol.package(), module).expect("Module path should parse"),
+        event_type: format!("{}::{}", protocol.package(), event_name)
+            .parse()
+            .expect("Type tag should parse"),
+        sender: "0xsender".to_string(),
+        data: format!("{:?}", parsed_json),
+        parsed_json,
+        timestamp: 42,
+        chain: None,
+        enrichments: Default::default(),
+    }
+}
+
+#[test]
+fn test_cetus_swap_normalized() {
+    let event = chain_event(
+        SwapProtocol::Cetus,
+        "pool::SwapEvent",
+        json!({ "atob": true, "pool": POOL, "amount_in": "1000", "amount_out": "990" }),
+    );
+
+    let swap = Swap::from_event(&event).expect("Event should be a swap");
+    assert_eq!(swap.protocol, SwapProtocol::Cetus);
+    assert_eq!(swap.pool, POOL);
+    assert_eq!((swap.amount_in, swap.amount_out), (1000, 990));
+    assert_eq!(swap.coin_in, None, "Coin types come from the pool type");
+    assert_eq!(swap.sender, "0xsender");
+    assert_eq!(swap.timestamp, 42);
+}
+
+#[test]
+fn test_turbos_swap_follows_direction() {
+    let event = chain_event(
+        SwapProtocol::Turbos,
+        "pool::SwapEvent",
+        json!({ "a_to_b": false, "pool": POOL, "amount_a": "500", "amount_b": "7" }),
+    );
+
+    let swap = Swap::from_event(&event).expect("Event should be a swap");
+    assert_eq!(
+        (swap.amount_in, swap.amount_out),
+        (7, 500),
+        "B to A swaps sell the B amount"
+    );
+}
+
+#[test]
+fn test_aftermath_swap_normalized() {
+    let event = chain_event(
+        SwapProtocol::Aftermath,
+        "events::SwapEventV2",
+        json!({
+            "pool_id": POOL,
+            "issuer": "0xsender",
+            "types_in": ["0000000000000000000000000000000000000000000000000000000000000002::sui::SUI"],
+            "amounts_in": ["2000000000"],
+            "types_out": ["0x0000000000000000000000000000000000000000000000000000000000000c01::usdc::USDC"],
+            "amounts_out": ["3100000"],
+        }),
+    );
+
+    let swap = Swap::from_event(&event).expect("Event should be a swap");
+    assert_eq!(
+        swap.coin_in.as_deref(),
+        Some("0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI")
+    );
+    assert_eq!(
+        swap.coin_out.as_deref(),
+        Some("0x0000000000000000000000000000000000000000000000000000000000000c01::usdc::USDC")
+    );
+    assert_eq!(
+        (swap.amount_in, swap.amount_out),
+        (2_000_000_000, 3_100_000)
+    );
+}
+
+#[test]
+fn test_other_events_skipped() {
+    let other = chain_event(
+        SwapProtocol::Cetus,
+        "pool::AddLiquidityEvent",
+        json!({ "pool": POOL }),
+    );
+    assert_eq!(Swap::from_event(&other), None);
+
+    let malformed = chain_event(
+        SwapProtocol::Cetus,
+        "pool::SwapEvent",
+        json!({ "pool": POOL }),
+    );
+    assert_eq!(Swap::from_event(&malformed), None);
+}
+
+#[test]
+fn test_swap_protocol_event_types() {
+    assert_eq!(
+        SwapProtocol::Aftermath.event_types(),
+        vec![
+            format!("{}::events::SwapEventV2", SwapProtocol::Aftermath.package()),
+            format!("{}::events::SwapEvent", SwapProtocol::Aftermath.package()),
+        ]
+    );
+}
@@ -0,0 +1,65 @@
+use fluxus_source_sui::{ObjectCategory, ObjectClassifier};
+
+#[test]
+fn test_classifier_builtin_rules() {
+    let classifier = ObjectClassifier::new();
+
+    assert_eq!(
+        classifier.classify_type("0x2::coin::Coin<0x2::sui::SUI>"),
+        ObjectCategory::Coin
+    );
+    assert_eq!(
+        classifier.classify_type(
+            "0x0000000000000000000000000000000000000000000000000000000000000003::staking_pool::StakedSui"
+        ),
+        ObjectCategory::StakedSui,
+        "Long package IDs should match"
+    );
+    assert_eq!(
+        classifier.classify_type("0x2::kiosk::KioskOwnerCap"),
+        ObjectCategory::Kiosk
+    );
+    assert_eq!(
+        classifier.classify_type("0xabc::position::Position"),
+        ObjectCategory::LpPosition,
+        "Wildcard packages should match any package"
+    );
+    assert_eq!(
+        classifier.classify_type("0xabc::hero::Hero"),
+        ObjectCategory::Unknown
+    );
+    assert_eq!(
+        classifier.classify_type("not a type"),
+        ObjectCategory::Unknown
+    );
+}
+
+#[test]
+fn test_classifier_user_rules() {
+    let classifier = ObjectClassifier::new()
+        .with_rule("0xabc::hero::Hero", ObjectCategory::Nft)
+        .expect("Rule should parse")
+        .with_rule("0xabc::position::Position", ObjectCategory::Unknown)
+        .expect("Rule should parse");
+
+    assert_eq!(
+        classifier.classify_type("0xabc::hero::Hero"),
+        ObjectCategory::Nft
+    );
+    assert_eq!(
+        classifier.classify_type("0xabc::position::Position"),
+        ObjectCategory::Unknown,
+        "User rules should override built-in rules"
+    );
+    assert_eq!(
+        classifier.classify_type("0xdef::position::Position"),
+        ObjectCategory::LpPosition
+    );
+
+    assert!(
+        ObjectClassifier::new()
+            .with_rule("hero::Hero", ObjectCategory::Nft)
+            .is_err(),
+        "Patterns need a package"
+    );
+}
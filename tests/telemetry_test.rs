@@ -0,0 +1,36 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::SuiTelemetrySource;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_telemetry_source_initialization() {
+    let mut source = SuiTelemetrySource::new_with_mainnet(500, Duration::from_secs(1));
+
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized());
+}
+
+#[tokio::test]
+async fn test_telemetry_source_window() {
+    let mut source = SuiTelemetrySource::new_with_mainnet(500, Duration::from_secs(2));
+    source.init().await.expect("Initialization failed");
+
+    // Poll until a checkpoint past the first window is read
+    let mut windows = None;
+    for _ in 0..20 {
+        if let Ok(Some(record)) = source.next().await {
+            windows = Some(record.data);
+            break;
+        }
+    }
+
+    let windows = windows.expect("A window should close");
+    for window in windows {
+        assert_eq!(window.window_end - window.window_start, 2_000);
+        assert!(window.checkpoints > 0, "Emitted windows hold checkpoints");
+        assert!(window.first_checkpoint <= window.last_checkpoint);
+        assert!(window.checkpoints_per_second > 0.0);
+        assert!(window.tps >= 0.0 && window.fullness >= 0.0);
+    }
+}
@@ -1,7 +1,11 @@
 use fluxus::sources::Source;
-use fluxus_source_sui::SuiTransactionSource;
+use fluxus_source_sui::{ErrorPolicy, HeuristicsConfig, RetryConfig, SuiTransactionSource};
+use futures::TryStreamExt;
+use std::collections::HashSet;
 use std::time::Duration;
-use sui_sdk::SUI_TESTNET_URL;
+use sui_sdk::types::base_types::SuiAddress;
+use sui_sdk::types::digests::TransactionDigest;
+use sui_sdk::{SUI_MAINNET_URL, SUI_TESTNET_URL};
 use tokio::time::sleep;
 
 #[tokio::test]
@@ -114,3 +118,457 @@ async fn test_sui_transaction_source_error_handling() {
         "Initialization with invalid endpoint should fail"
     );
 }
+
+#[tokio::test]
+async fn test_sui_transaction_source_heuristics() {
+    // Create SuiTransactionSource with heuristics enabled
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 10)
+        .with_heuristics(HeuristicsConfig::default());
+    source.init().await.expect("Initialization failed");
+
+    // Classification must not break fetching
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching with heuristics should succeed");
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_effects_only() {
+    // Create SuiTransactionSource fetching only digests and effects
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).with_effects_only(true);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching effects only should succeed");
+
+    if let Ok(Some(transactions)) = result {
+        for transaction in transactions.data {
+            assert!(transaction.effects.is_some(), "Effects should be present");
+            assert!(
+                transaction.metadata.is_none(),
+                "Input should not be fetched"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_health_check() {
+    // Create SuiTransactionSource checking fullnode lag on every poll
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5)
+        .with_health_check(Duration::ZERO, Duration::from_secs(30));
+    assert!(source.health().is_some(), "Health should be enabled");
+    source.init().await.expect("Initialization failed");
+
+    let _ = source.next().await;
+
+    let health = source.health().expect("Health should be enabled");
+    assert!(
+        health.latest_checkpoint.is_some(),
+        "Health check should record the latest checkpoint"
+    );
+    assert!(health.lag_ms.is_some(), "Health check should estimate lag");
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_health_check_reference() {
+    // Compare the fullnode with a reference endpoint to separate lag from clock skew
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5)
+        .with_health_check(Duration::ZERO, Duration::from_secs(30))
+        .with_health_reference(SUI_MAINNET_URL);
+    source.init().await.expect("Initialization failed");
+
+    let _ = source.next().await;
+
+    let health = source.health().expect("Health should be enabled");
+    if health.latest_checkpoint.is_some() {
+        assert!(
+            health.reference_checkpoint.is_some(),
+            "Health check should record the reference checkpoint"
+        );
+        assert!(health.checkpoints_behind.is_some());
+        assert!(
+            health.clock_skew_ms.is_some(),
+            "Clock skew should be reported"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_chain_metadata() {
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5);
+    source.init().await.expect("Initialization failed");
+
+    let metadata = source
+        .chain_metadata()
+        .cloned()
+        .expect("Chain metadata should be fetched at init");
+    assert_eq!(metadata.network, "mainnet");
+    assert!(
+        !metadata.chain_identifier.is_empty(),
+        "Chain identifier should not be empty"
+    );
+
+    // Every record carries the chain metadata
+    if let Ok(Some(transactions)) = source.next().await {
+        for transaction in transactions.data {
+            assert_eq!(transaction.chain.as_ref(), Some(&metadata));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_raw_bcs() {
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).with_raw_bcs(true);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(transactions)) = source.next().await {
+        for transaction in transactions.data {
+            assert!(
+                transaction.raw_transaction.is_some(),
+                "Raw transaction bytes should be included"
+            );
+            assert!(
+                transaction.raw_effects.is_some(),
+                "Raw effects bytes should be included"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_stats() {
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).with_byte_stats(true);
+    source.init().await.expect("Initialization failed");
+
+    let result = source.next().await;
+    let stats = source.stats();
+    assert_eq!(stats.polls, 1, "One poll should be counted");
+
+    if let Ok(Some(transactions)) = result {
+        assert_eq!(stats.records_emitted, transactions.data.len() as u64);
+        assert!(stats.bytes_fetched > 0, "Fetched bytes should be counted");
+        assert!(stats.cursor.is_some(), "Cursor should be reported");
+    }
+
+    // Stats serialize for health endpoints
+    let json = serde_json::to_value(&stats).expect("Stats should serialize");
+    assert!(json.get("uptime_ms").is_some());
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_checkpoint_range() {
+    let client = sui_sdk::SuiClientBuilder::default()
+        .build(sui_sdk::SUI_MAINNET_URL)
+        .await
+        .expect("Client initialization failed");
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .expect("Fetching latest checkpoint failed");
+    let (start, end) = (latest - 2, latest - 1);
+
+    let mut source =
+        SuiTransactionSource::new_with_mainnet(100, 50).with_checkpoint_range(start, end);
+    source.init().await.expect("Initialization failed");
+
+    // Read the range to completion
+    for _ in 0..20 {
+        if source.is_range_complete() {
+            break;
+        }
+        if let Ok(Some(transactions)) = source.next().await {
+            for transaction in transactions.data {
+                let checkpoint = transaction.checkpoint.expect("Checkpoint should be set");
+                assert!(
+                    (start..=end).contains(&checkpoint),
+                    "Transaction should be within the range"
+                );
+            }
+        }
+    }
+
+    assert!(
+        source.is_range_complete(),
+        "Range should be read completely"
+    );
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "Completed range should yield no more records"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_fetch_range() {
+    let client = sui_sdk::SuiClientBuilder::default()
+        .build(sui_sdk::SUI_MAINNET_URL)
+        .await
+        .expect("Client initialization failed");
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .expect("Fetching latest checkpoint failed");
+
+    let transactions: Vec<_> = SuiTransactionSource::new_with_mainnet(500, 50)
+        .fetch_range(latest - 1, latest - 1)
+        .try_collect()
+        .await
+        .expect("Fetching the range should succeed");
+
+    // Every checkpoint holds at least its consensus commit prologue
+    assert!(!transactions.is_empty(), "Range should not be empty");
+    for transaction in transactions {
+        assert_eq!(transaction.checkpoint, Some(latest - 1));
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_fetch_range_past_head() {
+    let client = sui_sdk::SuiClientBuilder::default()
+        .build(sui_sdk::SUI_MAINNET_URL)
+        .await
+        .expect("Client initialization failed");
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .expect("Fetching latest checkpoint failed");
+
+    // Checkpoints not produced yet are waited for rather than polled back to back
+    let transactions: Vec<_> = tokio::time::timeout(
+        Duration::from_secs(60),
+        SuiTransactionSource::new_with_mainnet(200, 50)
+            .fetch_range(latest + 2, latest + 2)
+            .try_collect(),
+    )
+    .await
+    .expect("Range past the head should be read once produced")
+    .expect("Fetching the range should succeed");
+
+    for transaction in transactions {
+        assert_eq!(transaction.checkpoint, Some(latest + 2));
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_balance_changes() {
+    // Balance changes are decoded by default and dropped when disabled
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 10);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(transactions)) = source.next().await {
+        for change in transactions.data.iter().flat_map(|tx| &tx.balance_changes) {
+            assert!(!change.owner.is_empty(), "Owner should be set");
+            assert!(
+                change.coin_type.contains("::"),
+                "Coin type should be a Move type"
+            );
+        }
+    }
+
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 10).with_balance_changes(false);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(transactions)) = source.next().await {
+        for tx in &transactions.data {
+            assert!(
+                tx.balance_changes.is_empty(),
+                "Balance changes should be disabled"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_partition() {
+    // A partitioned source only reads its own checkpoints
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 50).with_partition(1, 2);
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..4 {
+        if let Ok(Some(transactions)) = source.next().await {
+            for tx in &transactions.data {
+                let checkpoint = tx.checkpoint.expect("Checkpoint should be set");
+                assert_eq!(checkpoint % 2, 1, "Checkpoint of another partition");
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_rpc_error_policy() {
+    // Cursor the node cannot resolve, so every query fails
+    let cursor = TransactionDigest::random();
+
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 5).with_cursor(cursor);
+    source.init().await.expect("Initialization failed");
+    assert!(
+        source.next().await.is_err(),
+        "FailFast should return the error"
+    );
+
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 5)
+        .with_cursor(cursor)
+        .with_rpc_error_policy(ErrorPolicy::SkipAndLog);
+    source.init().await.expect("Initialization failed");
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "SkipAndLog should skip the poll"
+    );
+
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 5)
+        .with_cursor(cursor)
+        .with_rpc_error_policy(ErrorPolicy::RetryThenSkip { retries: 2 });
+    source.init().await.expect("Initialization failed");
+    assert!(
+        matches!(source.next().await, Ok(None)),
+        "RetryThenSkip should skip the poll once retries are exhausted"
+    );
+    assert_eq!(
+        source.stats().errors.get("rpc"),
+        Some(&3),
+        "Every attempt should be counted"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_retry_backoff() {
+    // An unresolvable cursor is not transient, so it fails without backoff
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 5)
+        .with_cursor(TransactionDigest::random())
+        .with_retry(RetryConfig::new(
+            4,
+            Duration::from_secs(5),
+            Duration::from_secs(20),
+        ));
+    source.init().await.expect("Initialization failed");
+    let start = std::time::Instant::now();
+    assert!(source.next().await.is_err(), "Should fail fast");
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "Should not back off"
+    );
+    assert_eq!(source.stats().errors.get("transient"), None);
+
+    // Healthy polls are unaffected
+    let mut source =
+        SuiTransactionSource::new_with_mainnet(100, 5).with_retry(RetryConfig::default());
+    source.init().await.expect("Initialization failed");
+    assert!(source.next().await.is_ok(), "Should poll normally");
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_max_age() {
+    let client = sui_sdk::SuiClientBuilder::default()
+        .build(sui_sdk::SUI_MAINNET_URL)
+        .await
+        .expect("Client initialization failed");
+    let latest = client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await
+        .expect("Fetching latest checkpoint failed");
+
+    // Checkpoints from days ago are older than the maximum age
+    let (start, end) = (latest - 1_000_000, latest - 999_999);
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 50)
+        .with_checkpoint_range(start, end)
+        .with_max_age(Duration::from_secs(60));
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..20 {
+        if source.is_range_complete() {
+            break;
+        }
+        assert!(
+            matches!(source.next().await, Ok(None)),
+            "Stale transactions should be dropped"
+        );
+    }
+    assert!(
+        source.is_range_complete(),
+        "Dropped transactions should still advance the range"
+    );
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_compact() {
+    // Metadata opt-out keeps sender and kind
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).with_include_metadata(false);
+    source.init().await.expect("Initialization failed");
+    if let Ok(Some(transactions)) = source.next().await {
+        for transaction in transactions.data {
+            assert!(transaction.metadata.is_none(), "Metadata should be dropped");
+            assert_ne!(transaction.sender, "unknown", "Sender should be kept");
+        }
+    }
+
+    // Compact records carry only the counting fields
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).compact();
+    source.init().await.expect("Initialization failed");
+    let result = source.next().await;
+    assert!(result.is_ok(), "Fetching compact records should succeed");
+    if let Ok(Some(transactions)) = result {
+        for transaction in transactions.data {
+            assert!(!transaction.transaction_digest.is_empty());
+            assert!(transaction.timestamp > 0, "Timestamp should be set");
+            assert!(
+                transaction.success.is_some(),
+                "Execution status should be set"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_address_activity() {
+    let address: SuiAddress = "0xac5bceec1b789ff840d7d4e6ce4ce61c90d190a7f8c4f4ddf0bff6ee2413c33c"
+        .parse()
+        .expect("Address should parse");
+    let mut source = SuiTransactionSource::new_with_mainnet(100, 10).with_address_activity(address);
+    source.init().await.expect("Initialization failed");
+
+    // Nodes without the combined filter are queried for both directions
+    let result = source.next().await;
+    assert!(
+        result.is_ok(),
+        "Polling should succeed with or without the combined filter"
+    );
+    if let Ok(Some(transactions)) = result {
+        assert!(
+            transactions.data.len() <= 10,
+            "Merged page should respect the batch size"
+        );
+        let mut digests: Vec<&String> = transactions
+            .data
+            .iter()
+            .map(|transaction| &transaction.transaction_digest)
+            .collect();
+        let emitted = digests.len();
+        digests.sort();
+        digests.dedup();
+        assert_eq!(digests.len(), emitted, "Transactions should not repeat");
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_gap_backfill() {
+    // A small page behind a long interval leaves gaps between head polls
+    let mut source = SuiTransactionSource::new_with_mainnet(3000, 5).with_gap_backfill(200);
+    source.init().await.expect("Initialization failed");
+
+    let mut seen = HashSet::new();
+    for _ in 0..2 {
+        let result = source.next().await;
+        assert!(result.is_ok(), "Polling with backfill should succeed");
+        if let Ok(Some(transactions)) = result {
+            for transaction in transactions.data {
+                assert!(
+                    seen.insert(transaction.transaction_digest),
+                    "Backfilled transactions should not repeat across polls"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{ErrorPolicy, SuiEventSource, dead_letter_channel};
+use serde::Deserialize;
+
+/// Event shape no Move event has
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct MissingEvent {
+    field_absent_from_every_event: u64,
+}
+
+#[tokio::test]
+async fn test_typed_event_source_deserializes_json() {
+    let mut source = SuiEventSource::new_with_mainnet(500, 10).typed::<serde_json::Value>();
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(record)) = source.next().await {
+        assert!(!record.data.is_empty(), "Record should carry events");
+    }
+}
+
+#[tokio::test]
+async fn test_typed_event_source_routes_failures() {
+    let (sender, mut receiver) = dead_letter_channel();
+    let mut source = SuiEventSource::new_with_mainnet(500, 10)
+        .typed::<MissingEvent>()
+        .with_dead_letter_channel(sender);
+    source.init().await.expect("Initialization failed");
+
+    assert!(
+        !matches!(source.next().await, Ok(Some(_))),
+        "Events that fail to deserialize should be dropped"
+    );
+    while let Ok(letter) = receiver.try_recv() {
+        assert_eq!(letter.source, "TypedEventSource");
+        assert!(letter.item_id.contains(':'));
+    }
+}
+
+#[tokio::test]
+async fn test_typed_event_source_fail_fast() {
+    let mut source = SuiEventSource::new_with_mainnet(500, 10)
+        .typed::<MissingEvent>()
+        .with_decode_error_policy(ErrorPolicy::FailFast);
+    source.init().await.expect("Initialization failed");
+
+    for _ in 0..3 {
+        match source.next().await {
+            Err(_) => return,
+            Ok(record) => assert!(record.is_none(), "No event should deserialize"),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{
+    MIST_PER_SUI, SuiTransactionSource, format_amount, mist_to_sui, parse_amount, sui_to_mist,
+};
+use proptest::prelude::*;
+
+#[test]
+fn test_mist_sui_conversion() {
+    assert_eq!(mist_to_sui(MIST_PER_SUI as i128), "1");
+    assert_eq!(mist_to_sui(1_500_000_000), "1.5");
+    assert_eq!(mist_to_sui(1), "0.000000001");
+    assert_eq!(mist_to_sui(-250_000_000), "-0.25");
+    assert_eq!(mist_to_sui(0), "0");
+
+    assert_eq!(sui_to_mist("1.5").expect("Should parse"), 1_500_000_000);
+    assert_eq!(sui_to_mist("-0.25").expect("Should parse"), -250_000_000);
+    assert_eq!(sui_to_mist(".5").expect("Should parse"), 500_000_000);
+    assert_eq!(
+        sui_to_mist("3").expect("Should parse"),
+        3 * MIST_PER_SUI as i128
+    );
+}
+
+#[test]
+fn test_parse_amount_rejects_invalid() {
+    assert!(
+        sui_to_mist("0.0000000001").is_err(),
+        "Below one MIST should fail"
+    );
+    assert!(sui_to_mist("").is_err());
+    assert!(sui_to_mist("-").is_err());
+    assert!(sui_to_mist("1e9").is_err());
+    assert!(sui_to_mist("1.2.3").is_err());
+    assert!(parse_amount("1.5", 0).is_err(), "No decimals allowed");
+}
+
+#[test]
+fn test_format_amount_without_decimals() {
+    assert_eq!(format_amount(42, 0), "42");
+    assert_eq!(format_amount(-42, 0), "-42");
+    assert_eq!(
+        format_amount(i128::MIN, 6),
+        "-170141183460469231731687303715884.105728"
+    );
+}
+
+proptest! {
+    #[test]
+    fn format_parse_round_trip(amount in any::<i64>(), decimals in 0u8..20) {
+        let formatted = format_amount(amount as i128, decimals);
+        prop_assert_eq!(parse_amount(&formatted, decimals).unwrap(), amount as i128);
+    }
+}
+
+#[tokio::test]
+async fn test_sui_transaction_source_scaled_amounts() {
+    let mut source = SuiTransactionSource::new_with_mainnet(500, 5).with_scaled_amounts(true);
+    source.init().await.expect("Initialization failed");
+
+    if let Ok(Some(transactions)) = source.next().await {
+        for change in transactions.data.iter().flat_map(|tx| &tx.balance_changes) {
+            if change.coin_type.ends_with("::sui::SUI") {
+                assert_eq!(
+                    change.decimal_amount.as_deref(),
+                    Some(mist_to_sui(change.amount).as_str()),
+                    "SUI amounts should be scaled without a lookup"
+                );
+            }
+        }
+    }
+}
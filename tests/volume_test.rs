@@ -0,0 +1,47 @@
+use fluxus::sources::Source;
+use fluxus_source_sui::{SuiTransactionSource, SuiVolumeSource};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_volume_source_initialization() {
+    let mut source = SuiVolumeSource::new(
+        SuiTransactionSource::new_with_mainnet(500, 10),
+        Duration::from_secs(1),
+    );
+
+    let init_result = source.init().await;
+    assert!(init_result.is_ok(), "Initialization should succeed");
+    assert!(source.is_initialized());
+}
+
+#[tokio::test]
+async fn test_volume_source_window() {
+    let mut source = SuiVolumeSource::new(
+        SuiTransactionSource::new_with_mainnet(200, 20),
+        Duration::from_secs(1),
+    );
+    source.init().await.expect("Initialization failed");
+
+    // Poll until the window closes
+    let mut volumes = None;
+    for _ in 0..10 {
+        if let Ok(Some(record)) = source.next().await {
+            volumes = Some(record.data);
+            break;
+        }
+    }
+
+    if let Some(volumes) = volumes {
+        assert!(
+            volumes
+                .windows(2)
+                .all(|pair| pair[0].volume >= pair[1].volume),
+            "Volumes should be sorted by size"
+        );
+        for volume in volumes {
+            assert!(volume.changes >= volume.transactions);
+            assert!(volume.transactions > 0, "Counted coins should have moved");
+            assert!(volume.window_start <= volume.window_end);
+        }
+    }
+}